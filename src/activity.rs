@@ -0,0 +1,352 @@
+//! Activity, inactivity and Activity2 threshold configuration.
+//!
+//! The ADXL372 compares every enabled axis against an 11-bit threshold (100 mg/LSB). Activity
+//! and inactivity events additionally require the condition to persist for a programmable
+//! number of samples, which [`MotionDetector::duration_ms`] expresses in milliseconds and the
+//! driver converts to register counts for the active [`OutputDataRate`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use adxl372::activity::{AxisMask, MotionDetector, MotionThreshold, ThresholdMode};
+//! use adxl372::config::Config;
+//! use adxl372::params::LinkLoopMode;
+//!
+//! let config = Config::new()
+//!     .activity(MotionDetector::new(
+//!         MotionThreshold::new(3_000, AxisMask::ALL, ThresholdMode::Referenced),
+//!         10,
+//!     ))
+//!     .inactivity(MotionDetector::new(
+//!         MotionThreshold::new(1_000, AxisMask::ALL, ThresholdMode::Referenced),
+//!         500,
+//!     ))
+//!     .linkloop(LinkLoopMode::Loop)
+//!     .build();
+//!
+//! config.validate().unwrap();
+//! ```
+
+use crate::params::OutputDataRate;
+use crate::registers::{REG_THRESH_ACT_X_H, REG_THRESH_ACT2_Z_L, ThresholdLow};
+
+/// Threshold resolution in milli-g per LSB.
+pub const THRESHOLD_MG_PER_LSB: u32 = 100;
+/// Largest threshold code encodable in the 11-bit `THRESH_*` fields.
+pub const THRESHOLD_MAX_CODE: u16 = 0x7FF;
+/// Largest count accepted by the 8-bit `TIME_ACT` register.
+pub const ACTIVITY_TIME_MAX_COUNT: u32 = 0xFF;
+/// Largest count accepted by the 16-bit `TIME_INACT_H`/`TIME_INACT_L` pair.
+pub const INACTIVITY_TIME_MAX_COUNT: u32 = 0xFFFF;
+
+/// Number of bytes spanned by one `THRESH_*_{X,Y,Z}_{H,L}` group.
+pub(crate) const THRESHOLD_BYTES: usize = 6;
+/// Number of bytes in the contiguous `THRESH_ACT_X_H..=THRESH_ACT2_Z_L` block.
+pub(crate) const ACTIVITY_BLOCK_BYTES: usize =
+    (REG_THRESH_ACT2_Z_L - REG_THRESH_ACT_X_H) as usize + 1;
+
+/// Per-axis participation in a threshold detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AxisMask {
+    /// X-axis enabled.
+    pub x: bool,
+    /// Y-axis enabled.
+    pub y: bool,
+    /// Z-axis enabled.
+    pub z: bool,
+}
+
+impl AxisMask {
+    /// No axis participates; the detector is disabled.
+    pub const NONE: Self = Self::new(false, false, false);
+    /// All three axes participate.
+    pub const ALL: Self = Self::new(true, true, true);
+
+    /// Creates a mask from individual axis flags.
+    pub const fn new(x: bool, y: bool, z: bool) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns `true` when at least one axis is enabled.
+    pub const fn any(self) -> bool {
+        self.x || self.y || self.z
+    }
+}
+
+/// Comparison mode of a threshold detector (`ACT_REF`, `INACT_REF`, `ACT2_REF`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ThresholdMode {
+    /// Samples are compared directly against the threshold.
+    #[default]
+    Absolute,
+    /// Samples are compared against the threshold relative to a captured reference.
+    Referenced,
+}
+
+/// Threshold, axis mask and comparison mode shared by every motion detector.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MotionThreshold {
+    /// Threshold magnitude in milli-g, rounded down to 100 mg steps.
+    pub threshold_mg: u32,
+    /// Axes compared against the threshold.
+    pub axes: AxisMask,
+    /// Absolute or referenced comparison.
+    pub mode: ThresholdMode,
+}
+
+impl MotionThreshold {
+    /// Creates a new threshold description.
+    pub const fn new(threshold_mg: u32, axes: AxisMask, mode: ThresholdMode) -> Self {
+        Self {
+            threshold_mg,
+            axes,
+            mode,
+        }
+    }
+
+    /// Returns a detector with no axis enabled and a zero threshold (reset state).
+    pub const fn disabled() -> Self {
+        Self::new(0, AxisMask::NONE, ThresholdMode::Absolute)
+    }
+
+    /// Returns the raw threshold code, which may exceed the 11-bit register range.
+    pub const fn code(&self) -> u32 {
+        self.threshold_mg / THRESHOLD_MG_PER_LSB
+    }
+
+    /// Returns `true` when the threshold fits the 11-bit `THRESH_*` fields.
+    pub const fn is_in_range(&self) -> bool {
+        self.code() <= THRESHOLD_MAX_CODE as u32
+    }
+
+    /// Encodes the `X_H, X_L, Y_H, Y_L, Z_H, Z_L` register images.
+    ///
+    /// Out-of-range thresholds saturate; call [`Config::validate`](crate::config::Config::validate)
+    /// first to reject them instead.
+    pub fn to_registers(&self) -> [u8; THRESHOLD_BYTES] {
+        let code = self.code().min(THRESHOLD_MAX_CODE as u32) as u16;
+        let high = (code >> 3) as u8;
+        let low = ThresholdLow::new().with_threshold_lsb((code & 0x07) as u8);
+        let referenced = matches!(self.mode, ThresholdMode::Referenced);
+
+        [
+            high,
+            u8::from(low.with_enable(self.axes.x).with_reference(referenced)),
+            high,
+            u8::from(low.with_enable(self.axes.y)),
+            high,
+            u8::from(low.with_enable(self.axes.z)),
+        ]
+    }
+
+    /// Decodes the threshold from its six register images.
+    ///
+    /// The threshold and reference bit are taken from the X-axis pair, which the driver always
+    /// programs identically to Y and Z.
+    pub fn from_registers(raw: [u8; THRESHOLD_BYTES]) -> Self {
+        let x_low = ThresholdLow::from(raw[1]);
+        let code = (u32::from(raw[0]) << 3) | u32::from(x_low.threshold_lsb());
+        let mode = if x_low.reference() {
+            ThresholdMode::Referenced
+        } else {
+            ThresholdMode::Absolute
+        };
+        let axes = AxisMask::new(
+            x_low.enable(),
+            ThresholdLow::from(raw[3]).enable(),
+            ThresholdLow::from(raw[5]).enable(),
+        );
+
+        Self::new(code * THRESHOLD_MG_PER_LSB, axes, mode)
+    }
+}
+
+/// Threshold detector with a minimum event duration (activity and inactivity).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MotionDetector {
+    /// Threshold, axes and comparison mode.
+    pub threshold: MotionThreshold,
+    /// Time the condition must persist before the event fires, in milliseconds.
+    pub duration_ms: u32,
+}
+
+impl MotionDetector {
+    /// Creates a new detector description.
+    pub const fn new(threshold: MotionThreshold, duration_ms: u32) -> Self {
+        Self {
+            threshold,
+            duration_ms,
+        }
+    }
+
+    /// Returns a detector in its reset state.
+    pub const fn disabled() -> Self {
+        Self::new(MotionThreshold::disabled(), 0)
+    }
+}
+
+/// Converts an activity duration into `TIME_ACT` counts for the given ODR.
+///
+/// One count lasts 3.3 ms at 6400 Hz and 6.6 ms at every other rate; the result is rounded
+/// down and may exceed [`ACTIVITY_TIME_MAX_COUNT`].
+pub const fn activity_time_counts(duration_ms: u32, odr: OutputDataRate) -> u32 {
+    let tenths_per_count = match odr {
+        OutputDataRate::Od6400Hz => 33,
+        _ => 66,
+    };
+    duration_ms.saturating_mul(10) / tenths_per_count
+}
+
+/// Converts an inactivity duration into `TIME_INACT` counts for the given ODR.
+///
+/// One count lasts 13 ms at 6400 Hz and 26 ms at every other rate; the result is rounded
+/// down and may exceed [`INACTIVITY_TIME_MAX_COUNT`].
+pub const fn inactivity_time_counts(duration_ms: u32, odr: OutputDataRate) -> u32 {
+    let ms_per_count = match odr {
+        OutputDataRate::Od6400Hz => 13,
+        _ => 26,
+    };
+    duration_ms / ms_per_count
+}
+
+/// Converts `TIME_ACT` counts back into a duration in milliseconds, rounded down.
+pub const fn activity_time_ms(counts: u32, odr: OutputDataRate) -> u32 {
+    let tenths_per_count = match odr {
+        OutputDataRate::Od6400Hz => 33,
+        _ => 66,
+    };
+    counts * tenths_per_count / 10
+}
+
+/// Converts `TIME_INACT` counts back into a duration in milliseconds.
+pub const fn inactivity_time_ms(counts: u32, odr: OutputDataRate) -> u32 {
+    let ms_per_count = match odr {
+        OutputDataRate::Od6400Hz => 13,
+        _ => 26,
+    };
+    counts * ms_per_count
+}
+
+/// Encodes the full `THRESH_ACT_X_H..=THRESH_ACT2_Z_L` block for a single burst write.
+pub(crate) fn encode_activity_block(
+    activity: &MotionDetector,
+    inactivity: &MotionDetector,
+    activity2: &MotionThreshold,
+    odr: OutputDataRate,
+) -> [u8; ACTIVITY_BLOCK_BYTES] {
+    let time_act = activity_time_counts(activity.duration_ms, odr).min(ACTIVITY_TIME_MAX_COUNT);
+    let time_inact =
+        inactivity_time_counts(inactivity.duration_ms, odr).min(INACTIVITY_TIME_MAX_COUNT);
+
+    let mut block = [0u8; ACTIVITY_BLOCK_BYTES];
+    block[..6].copy_from_slice(&activity.threshold.to_registers());
+    block[6] = time_act as u8;
+    block[7..13].copy_from_slice(&inactivity.threshold.to_registers());
+    block[13..15].copy_from_slice(&(time_inact as u16).to_be_bytes());
+    block[15..].copy_from_slice(&activity2.to_registers());
+    block
+}
+
+/// Decodes a `THRESH_ACT_X_H..=THRESH_ACT2_Z_L` block into activity, inactivity and Activity2.
+pub(crate) fn decode_activity_block(
+    block: &[u8; ACTIVITY_BLOCK_BYTES],
+    odr: OutputDataRate,
+) -> (MotionDetector, MotionDetector, MotionThreshold) {
+    let threshold = |start: usize| {
+        let mut raw = [0u8; THRESHOLD_BYTES];
+        raw.copy_from_slice(&block[start..start + THRESHOLD_BYTES]);
+        MotionThreshold::from_registers(raw)
+    };
+    let time_inact = u32::from(u16::from_be_bytes([block[13], block[14]]));
+
+    (
+        MotionDetector::new(threshold(0), activity_time_ms(u32::from(block[6]), odr)),
+        MotionDetector::new(threshold(7), inactivity_time_ms(time_inact, odr)),
+        threshold(15),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registers::{
+        REG_THRESH_ACT2_X_H, REG_THRESH_INACT_X_H, REG_TIME_ACT, REG_TIME_INACT_H,
+    };
+
+    /// Ensures the threshold code is split into H[10:3] and L[7:5] with the flags in L.
+    #[test]
+    fn threshold_encodes_split_code_and_flags() {
+        let threshold = MotionThreshold::new(
+            204_700,
+            AxisMask::new(true, false, true),
+            ThresholdMode::Referenced,
+        );
+
+        assert_eq!(
+            threshold.to_registers(),
+            [0xFF, 0xE3, 0xFF, 0xE0, 0xFF, 0xE1]
+        );
+        assert_eq!(
+            MotionThreshold::from_registers(threshold.to_registers()),
+            threshold
+        );
+    }
+
+    /// Verifies the 11-bit range check at the 204.7 g boundary.
+    #[test]
+    fn threshold_range_is_eleven_bits() {
+        assert!(
+            MotionThreshold::new(204_799, AxisMask::ALL, ThresholdMode::Absolute).is_in_range()
+        );
+        assert!(
+            !MotionThreshold::new(204_800, AxisMask::ALL, ThresholdMode::Absolute).is_in_range()
+        );
+    }
+
+    /// Checks duration conversion against the ODR-dependent timer resolution.
+    #[test]
+    fn durations_follow_odr() {
+        assert_eq!(activity_time_counts(33, OutputDataRate::Od6400Hz), 10);
+        assert_eq!(activity_time_counts(33, OutputDataRate::Od400Hz), 5);
+        assert_eq!(inactivity_time_counts(1_300, OutputDataRate::Od6400Hz), 100);
+        assert_eq!(inactivity_time_counts(1_300, OutputDataRate::Od3200Hz), 50);
+    }
+
+    /// Confirms each group lands at its register offset inside the burst block.
+    #[test]
+    fn activity_block_matches_register_layout() {
+        let activity = MotionDetector::new(
+            MotionThreshold::new(800, AxisMask::ALL, ThresholdMode::Absolute),
+            66,
+        );
+        let inactivity = MotionDetector::new(
+            MotionThreshold::new(
+                100,
+                AxisMask::new(false, false, true),
+                ThresholdMode::Absolute,
+            ),
+            26 * 300,
+        );
+        let activity2 = MotionThreshold::new(8_000, AxisMask::ALL, ThresholdMode::Absolute);
+
+        let block =
+            encode_activity_block(&activity, &inactivity, &activity2, OutputDataRate::Od400Hz);
+        let offset = |register: u8| usize::from(register - REG_THRESH_ACT_X_H);
+
+        assert_eq!(block.len(), 21);
+        assert_eq!(block[offset(REG_THRESH_ACT_X_H)], 0x01);
+        assert_eq!(block[offset(REG_TIME_ACT)], 10);
+        assert_eq!(block[offset(REG_THRESH_INACT_X_H) + 5], 0x21);
+        assert_eq!(
+            block[offset(REG_TIME_INACT_H)..offset(REG_TIME_INACT_H) + 2],
+            [0x01, 0x2C]
+        );
+        assert_eq!(block[offset(REG_THRESH_ACT2_X_H)], 0x0A);
+
+        let (decoded_activity, decoded_inactivity, decoded_activity2) =
+            decode_activity_block(&block, OutputDataRate::Od400Hz);
+        assert_eq!(decoded_activity, activity);
+        assert_eq!(decoded_inactivity, inactivity);
+        assert_eq!(decoded_activity2, activity2);
+    }
+}
@@ -0,0 +1,714 @@
+//! Async ADXL372 device driver built on `embedded-hal-async`.
+//!
+//! [`Adxl372Async`] mirrors the blocking [`Adxl372`](crate::device::Adxl372) API for executors
+//! such as Embassy, where the filter settle period and self-test sampling loop must yield
+//! instead of spinning. Both drivers share the register bitfields from
+//! [`registers`](crate::registers) and the [`Config`] encoding helpers, so register programming
+//! stays identical between them.
+
+use embedded_hal_async::delay::DelayNs;
+use embedded_hal_async::i2c::I2c;
+use embedded_hal_async::spi::SpiDevice;
+
+use crate::config::{CONFIG_BLOCK_BYTES, CONFIGURE_SEQUENCE, Config, ConfigDiff};
+use crate::device::{
+    POWER_UP_TO_STANDBY_DELAY_MS, RAW_AXIS_BYTES, StatusSnapshot, VerifiedWrite, WriteVerify,
+    begin_configure, check_id_bytes, decode_xyz,
+};
+use crate::dump::{DUMP_LEN, RegisterDump, dump_segments};
+use crate::error::{Error, Result};
+use crate::fifo::{
+    FIFO_CHUNK_BYTES, FifoDecoder, FifoDecoderStats, Sample, SampleReadPlan, decode_fifo_level,
+    whole_entries,
+};
+use crate::interface::Adxl372InterfaceAsync;
+use crate::interface::i2c::{I2cAddress, I2cInterface};
+use crate::interface::spi::SpiInterface;
+use crate::interrupt::InterruptMaps;
+#[cfg(feature = "defmt")]
+use crate::log::LOG_TAG;
+use crate::params::PowerMode;
+use crate::registers::{
+    PowerControl, REG_DEVID_AD, REG_FIFO_DATA, REG_FIFO_ENTRIES2, REG_INT1_MAP, REG_MAXPEAK_X_H,
+    REG_OFFSET_X, REG_RESET, REG_STATUS, REG_XDATA_H, REG_YDATA_H, REG_ZDATA_H, RESET_COMMAND,
+    ReadableRegister, WritableRegister, unpack_axis,
+};
+use crate::self_test::{SelfTestReport, run_self_test_async};
+use crate::units::Acceleration;
+
+/// High-level async driver for the ADXL372 accelerometer.
+pub struct Adxl372Async<IFACE> {
+    interface: IFACE,
+    config: Config,
+    verify: WriteVerify,
+    fifo: FifoDecoder,
+}
+
+impl<IFACE> Adxl372Async<IFACE> {
+    // ==================================================================
+    // == Driver Construction & Ownership ===============================
+    // ==================================================================
+    /// Creates a new driver instance from the provided bus interface.
+    pub fn new(interface: IFACE, config: Config) -> Self {
+        Self {
+            interface,
+            config,
+            verify: WriteVerify::Disabled,
+            fifo: FifoDecoder::new(config.fifo_format),
+        }
+    }
+
+    /// Consumes the driver and returns the owned interface.
+    pub fn release(self) -> (IFACE, Config) {
+        (self.interface, self.config)
+    }
+
+    /// Provides mutable access to the underlying interface.
+    pub fn interface_mut(&mut self) -> &mut IFACE {
+        &mut self.interface
+    }
+
+    /// Returns a shared reference to the active configuration.
+    pub fn config(&self) -> &Config {
+        &self.config
+    }
+
+    /// Selects whether register writes are read back and checked.
+    ///
+    /// See [`Adxl372::set_write_verify`](crate::device::Adxl372::set_write_verify).
+    pub fn set_write_verify(&mut self, policy: WriteVerify) {
+        self.verify = policy;
+    }
+
+    /// Returns the active write verification policy.
+    pub fn write_verify(&self) -> WriteVerify {
+        self.verify
+    }
+}
+
+impl<SPI> Adxl372Async<SpiInterface<SPI>>
+where
+    SPI: SpiDevice,
+{
+    // ==================================================================
+    // == SPI Convenience Constructors ==================================
+    // ==================================================================
+    /// Convenience constructor for async SPI transports.
+    pub fn new_spi(spi: SPI, config: Config) -> Self {
+        Self::new(SpiInterface::new(spi), config)
+    }
+
+    /// Releases the driver, returning the SPI device and configuration.
+    pub fn release_spi(self) -> (SPI, Config) {
+        let (iface, config) = self.release();
+        (iface.release(), config)
+    }
+}
+
+impl<I2C> Adxl372Async<I2cInterface<I2C>>
+where
+    I2C: I2c,
+{
+    // ==================================================================
+    // == I2C Convenience Constructors ==================================
+    // ==================================================================
+    /// Convenience constructor for async I2C transports.
+    pub fn new_i2c(i2c: I2C, address: I2cAddress, config: Config) -> Self {
+        Self::new(I2cInterface::new(i2c, address), config)
+    }
+
+    /// Releases the driver, returning the I2C bus and configuration.
+    pub fn release_i2c(self) -> (I2C, Config) {
+        let (iface, config) = self.release();
+        (iface.release(), config)
+    }
+}
+
+impl<IFACE, CommE> Adxl372Async<IFACE>
+where
+    IFACE: Adxl372InterfaceAsync<Error = CommE>,
+{
+    // ==================================================================
+    // == Initialization & Global Configuration ==========================
+    // ==================================================================
+    /// Initializes the sensor using the current configuration.
+    ///
+    /// Follows the same sequence as [`Adxl372::init`](crate::device::Adxl372::init): power-up
+    /// delay, ER001 self-test, soft reset and finally [`configure`](Self::configure).
+    pub async fn init(&mut self, delay: &mut impl DelayNs) -> Result<(), CommE> {
+        delay.delay_ms(POWER_UP_TO_STANDBY_DELAY_MS).await;
+
+        self.config.validate().map_err(Error::InvalidConfig)?;
+
+        if !self.run_self_test(delay).await?.passed {
+            return Err(Error::SelfTestFailed);
+        }
+
+        self.modify_reg(|power: &mut PowerControl| power.set_mode(PowerMode::Standby))
+            .await?;
+        self.reset().await?;
+        self.configure(self.config, delay).await?;
+        Ok(())
+    }
+
+    /// Applies a new configuration to the device.
+    ///
+    /// Automatically waits for the filter settle period when entering measurement mode.
+    pub async fn configure(
+        &mut self,
+        config: Config,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), CommE> {
+        let settle = begin_configure(&self.config, &config)?;
+
+        for step in CONFIGURE_SEQUENCE {
+            let register = step.register();
+            if let Some(block) = step.block(&config) {
+                self.write_block(register, block.as_slice()).await?;
+            } else {
+                let current = self
+                    .interface
+                    .read_register(register)
+                    .await
+                    .map_err(Error::from)?;
+                if let Some(updated) = step.update(&config, current) {
+                    self.write_block(register, &[updated]).await?;
+                }
+            }
+        }
+
+        self.finish_configure(config, settle, delay).await;
+        Ok(())
+    }
+
+    /// Applies a new configuration with a single burst write.
+    ///
+    /// Unlike [`configure`](Self::configure), no register is read first: the whole
+    /// `OFFSET_X..=POWER_CTL` block is assembled from `config` and written in one transaction.
+    /// `POWER_CTL` is the last address in the block, so the power mode changes only after the
+    /// other control registers hold their new values. Reserved bits are written as zero.
+    ///
+    /// Automatically waits for the filter settle period when entering measurement mode.
+    pub async fn configure_burst(
+        &mut self,
+        config: Config,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), CommE> {
+        let settle = begin_configure(&self.config, &config)?;
+
+        self.write_block(REG_OFFSET_X, &config.register_block())
+            .await?;
+
+        self.finish_configure(config, settle, delay).await;
+        Ok(())
+    }
+
+    // Records an applied configuration, waiting for the filters when `settle` is set.
+    async fn finish_configure(&mut self, config: Config, settle: bool, delay: &mut impl DelayNs) {
+        self.config = config;
+        self.fifo = FifoDecoder::new(config.fifo_format);
+
+        if settle {
+            self.wait_filter_settle(delay).await;
+        }
+    }
+
+    /// Waits for the configured filter settle time.
+    pub async fn wait_filter_settle(&self, delay: &mut impl DelayNs) {
+        #[cfg(feature = "defmt")]
+        defmt::info!(
+            "{} Waiting for filter settle: {} ms",
+            LOG_TAG,
+            self.config.filter_settle.millis()
+        );
+        delay
+            .delay_ms(self.config.filter_settle.millis() as u32)
+            .await;
+    }
+
+    /// Issues a soft reset sequence.
+    pub async fn reset(&mut self) -> Result<(), CommE> {
+        self.interface
+            .write_register(REG_RESET, RESET_COMMAND)
+            .await
+            .map_err(Error::from)?;
+        self.fifo.reset();
+        Ok(())
+    }
+
+    /// Reads the live register contents back into a [`Config`].
+    ///
+    /// The whole `OFFSET_X..=POWER_CTL` block is fetched in one burst, so the result is a
+    /// consistent snapshot. Thresholds and durations come back at register resolution.
+    ///
+    /// Behind a [`CachedInterface`](crate::interface::cached::CachedInterface) the block is
+    /// answered from the shadow copy; invalidate it first to read the device itself.
+    pub async fn read_config(&mut self) -> Result<Config, CommE> {
+        let mut block = [0u8; CONFIG_BLOCK_BYTES];
+        self.interface
+            .read_many(REG_OFFSET_X, &mut block)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(Config::from_register_block(&block))
+    }
+
+    /// Compares the live register contents against the cached configuration.
+    ///
+    /// The cached side is quantised to register resolution first, so only genuine
+    /// divergence (brown-out, reset, another bus master) is reported.
+    pub async fn verify_config(&mut self) -> Result<ConfigDiff, CommE> {
+        let actual = self.read_config().await?;
+        Ok(self.config.quantized().diff(&actual))
+    }
+
+    // ==================================================================
+    // == Identification & Status =======================================
+    // ==================================================================
+    /// Verifies identification registers against the expected ADXL372 constants.
+    pub async fn check_ids(&mut self) -> Result<u8, CommE> {
+        let mut ids = [0u8; 4];
+        self.interface
+            .read_many(REG_DEVID_AD, &mut ids)
+            .await
+            .map_err(Error::from)?;
+
+        check_id_bytes(&ids)
+    }
+
+    /// Returns a snapshot of the `STATUS` and `STATUS2` registers.
+    pub async fn read_status(&mut self) -> Result<StatusSnapshot, CommE> {
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(REG_STATUS, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(StatusSnapshot::from_bytes(raw))
+    }
+
+    /// Reads the INT1 and INT2 pin mapping back from the device.
+    pub async fn read_interrupt_maps(&mut self) -> Result<InterruptMaps, CommE> {
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(REG_INT1_MAP, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(InterruptMaps::from_registers(raw))
+    }
+
+    /// Captures every readable register except `FIFO_DATA` for diagnostics.
+    ///
+    /// Reading clears the `MAXPEAK` latches and the `STATUS2` event flags; see
+    /// [`RegisterDump`] for the stable encoding.
+    pub async fn dump_registers(&mut self) -> Result<RegisterDump, CommE> {
+        let mut values = [0u8; DUMP_LEN];
+        for (start, segment) in dump_segments(&mut values) {
+            self.interface
+                .read_many(start, segment)
+                .await
+                .map_err(Error::from)?;
+        }
+
+        Ok(RegisterDump::from_values(values))
+    }
+
+    // ==================================================================
+    // == Typed Register Access =========================================
+    // ==================================================================
+    /// Reads register `R` and decodes it into its bitfield type.
+    pub async fn read_reg<R: ReadableRegister>(&mut self) -> Result<R, CommE> {
+        let raw = self
+            .interface
+            .read_register(R::ADDRESS)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(R::from(raw))
+    }
+
+    /// Writes `value` to register `R`.
+    ///
+    /// Typed writes bypass the cached [`Config`]; prefer [`configure`](Self::configure) for
+    /// fields it tracks.
+    pub async fn write_reg<R: WritableRegister>(&mut self, value: R) -> Result<(), CommE> {
+        self.write_block(R::ADDRESS, &[value.into()]).await
+    }
+
+    /// Reads register `R`, applies `mutate` and writes it back if anything changed.
+    ///
+    /// Returns the register value now held by the device.
+    pub async fn modify_reg<R, F>(&mut self, mutate: F) -> Result<R, CommE>
+    where
+        R: ReadableRegister + WritableRegister,
+        F: FnOnce(&mut R),
+    {
+        let current = self
+            .interface
+            .read_register(R::ADDRESS)
+            .await
+            .map_err(Error::from)?;
+
+        let mut reg = R::from(current);
+        mutate(&mut reg);
+
+        let updated: u8 = reg.into();
+        if updated != current {
+            self.write_block(R::ADDRESS, &[updated]).await?;
+        }
+
+        Ok(reg)
+    }
+
+    // Writes `data` from `register` onwards, then applies the write verification policy.
+    async fn write_block(&mut self, register: u8, data: &[u8]) -> Result<(), CommE> {
+        self.send(register, data).await?;
+
+        let Some(mut verify) = VerifiedWrite::new(self.verify, register, data) else {
+            return Ok(());
+        };
+        while let Some((address, read)) = verify.next_read() {
+            self.interface
+                .read_many(address, read)
+                .await
+                .map_err(Error::from)?;
+            if verify.check()? {
+                self.send(register, data).await?;
+            }
+        }
+        Ok(())
+    }
+
+    // Single registers go through `write_register`, longer runs through `write_many`.
+    async fn send(&mut self, register: u8, data: &[u8]) -> Result<(), CommE> {
+        let result = match data {
+            [value] => self.interface.write_register(register, *value).await,
+            _ => self.interface.write_many(register, data).await,
+        };
+        result.map_err(Error::from)
+    }
+
+    // ==================================================================
+    // == Data Acquisition ==============================================
+    // ==================================================================
+    /// Reads a raw acceleration triplet.
+    pub async fn read_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
+        let mut raw = [0u8; RAW_AXIS_BYTES];
+        self.interface
+            .read_many(REG_XDATA_H, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(decode_xyz(&raw))
+    }
+
+    /// Returns acceleration scaled in milli-g.
+    pub async fn read_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.read_xyz_raw().await.map(Acceleration::from_raw)
+    }
+
+    /// Reads the raw X-axis acceleration sample.
+    pub async fn read_x_raw(&mut self) -> Result<i16, CommE> {
+        self.read_axis_raw(REG_XDATA_H).await
+    }
+
+    /// Reads the raw Y-axis acceleration sample.
+    pub async fn read_y_raw(&mut self) -> Result<i16, CommE> {
+        self.read_axis_raw(REG_YDATA_H).await
+    }
+
+    /// Reads the raw Z-axis acceleration sample.
+    pub async fn read_z_raw(&mut self) -> Result<i16, CommE> {
+        self.read_axis_raw(REG_ZDATA_H).await
+    }
+
+    // ==================================================================
+    // == Peak Acceleration =============================================
+    // ==================================================================
+    /// Reads the per-axis peak acceleration latched in `MAXPEAK_X/Y/Z`, clearing the latch.
+    ///
+    /// See [`Adxl372::read_peak_xyz_raw`](crate::device::Adxl372::read_peak_xyz_raw) for the
+    /// interaction with instant-on mode and the peak FIFO format.
+    pub async fn read_peak_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
+        let mut raw = [0u8; RAW_AXIS_BYTES];
+        self.interface
+            .read_many(REG_MAXPEAK_X_H, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(decode_xyz(&raw))
+    }
+
+    /// Reads the latched peak acceleration scaled in milli-g, clearing the latch.
+    pub async fn read_peak_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.read_peak_xyz_raw().await.map(Acceleration::from_raw)
+    }
+
+    /// Discards the latched peak so tracking restarts from the next sample.
+    pub async fn clear_peak(&mut self) -> Result<(), CommE> {
+        self.read_peak_xyz_raw().await.map(|_| ())
+    }
+
+    // ==================================================================
+    // == FIFO Streaming ================================================
+    // ==================================================================
+    /// Returns the number of FIFO entries currently buffered.
+    pub async fn read_fifo_level(&mut self) -> Result<u16, CommE> {
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(REG_FIFO_ENTRIES2, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(decode_fifo_level(raw))
+    }
+
+    /// Burst-reads raw `FIFO_DATA` bytes into the provided buffer.
+    ///
+    /// Only whole two-byte FIFO entries are transferred; the returned count is the number of
+    /// bytes written into `buf`.
+    pub async fn read_fifo_raw(&mut self, buf: &mut [u8]) -> Result<usize, CommE> {
+        let len = whole_entries(buf.len());
+        if len == 0 {
+            return Ok(0);
+        }
+
+        self.interface
+            .read_many(REG_FIFO_DATA, &mut buf[..len])
+            .await
+            .map_err(Error::from)?;
+        Ok(len)
+    }
+
+    /// Decodes complete FIFO samples into the caller-provided slice.
+    ///
+    /// Uses the configured [`FifoFormat`](crate::params::FifoFormat) and keeps the decoder
+    /// between calls exactly like
+    /// [`Adxl372::read_fifo_samples`](crate::device::Adxl372::read_fifo_samples).
+    pub async fn read_fifo_samples(&mut self, samples: &mut [Sample]) -> Result<usize, CommE> {
+        let level = self.read_fifo_level().await?;
+        let mut plan = SampleReadPlan::new(&self.fifo, level, samples.len());
+        let mut raw = [0u8; FIFO_CHUNK_BYTES];
+        let mut produced = 0;
+
+        while let Some(len) = plan.next_chunk() {
+            let chunk = &mut raw[..len];
+            self.read_fifo_raw(chunk).await?;
+
+            produced += self.fifo.decode(chunk, &mut samples[produced..]).produced;
+        }
+
+        Ok(produced)
+    }
+
+    /// Returns the discard counters of the decoder used by
+    /// [`read_fifo_samples`](Self::read_fifo_samples).
+    pub fn fifo_stats(&self) -> FifoDecoderStats {
+        self.fifo.stats()
+    }
+
+    /// Creates a streaming [`FifoDecoder`] for the configured FIFO format.
+    pub fn fifo_decoder(&self) -> FifoDecoder {
+        FifoDecoder::new(self.config.fifo_format)
+    }
+
+    // ==================================================================
+    // == Self-Test ======================================================
+    // ==================================================================
+    /// Executes the ER001 self-test routine using the sensor's default settings.
+    ///
+    /// See [`Adxl372::run_self_test`](crate::device::Adxl372::run_self_test) for the reset
+    /// caveats: always run it before applying your own configuration.
+    pub async fn run_self_test(
+        &mut self,
+        delay: &mut impl DelayNs,
+    ) -> Result<SelfTestReport, CommE> {
+        let report = run_self_test_async(self, delay).await?;
+        #[cfg(feature = "defmt")]
+        defmt::info!(
+            "{} Self Test Report: passed={}, delta_z_lsb={}, timed_out={}",
+            LOG_TAG,
+            report.passed,
+            report.delta_z_lsb,
+            report.timed_out
+        );
+        Ok(report)
+    }
+
+    // ==================================================================
+    // == Internal Helpers ===============================================
+    // ==================================================================
+    async fn read_axis_raw(&mut self, register: u8) -> Result<i16, CommE> {
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(register, &mut raw)
+            .await
+            .map_err(Error::from)?;
+
+        Ok(unpack_axis(raw[0], raw[1]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::Adxl372Async;
+    use crate::config::{Config, ConfigField};
+    use crate::device::WriteVerify;
+    use crate::error::Error;
+    use crate::params::PowerMode;
+    use crate::registers::{PowerControl, SelfTest};
+    use core::future::Future;
+    use core::pin::pin;
+    use core::task::{Context, Poll, Waker};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let mut future = pin!(future);
+        let mut cx = Context::from_waker(Waker::noop());
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    fn read(command: u8, response: std::vec::Vec<u8>) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![command]),
+            Transaction::read_vec(response),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Ensures the async burst read decodes left-justified 12-bit samples.
+    #[test]
+    fn read_xyz_raw_decodes_axes() {
+        let expectations = read(0x11, vec![0x00, 0x10, 0xFF, 0xF0, 0x7F, 0xF0]);
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+
+        let xyz = block_on(device.read_xyz_raw()).unwrap();
+        assert_eq!(xyz, [1, -1, 2047]);
+
+        device.release_spi().0.done();
+    }
+
+    /// Ensures the peak burst starts at MAXPEAK_X_H and scales to milli-g.
+    #[test]
+    fn read_peak_xyz_mg_scales_latched_peak() {
+        let expectations = read(0x2B, vec![0x7F, 0xF0, 0x00, 0x00, 0xFF, 0xF0]);
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+
+        let peak = block_on(device.read_peak_xyz_mg()).unwrap();
+        assert_eq!(peak.as_mg(), [204_700, 0, -100]);
+
+        device.release_spi().0.done();
+    }
+
+    /// Verifies the FIFO level combines FIFO_ENTRIES2 and FIFO_ENTRIES.
+    #[test]
+    fn read_fifo_level_combines_entry_registers() {
+        let expectations = read(0x0D, vec![0x01, 0x2C]);
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+
+        assert_eq!(block_on(device.read_fifo_level()).unwrap(), 300);
+
+        device.release_spi().0.done();
+    }
+
+    /// Checks a device found in standby is reported against a cached measuring config.
+    #[test]
+    fn verify_config_reports_power_mode_drift() {
+        let expectations = read(0x41, vec![0x00; 32]);
+        let config = Config::new().power_mode(PowerMode::Measure).build();
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), config);
+
+        let diff = block_on(device.verify_config()).unwrap();
+        assert!(diff.contains(ConfigField::PowerMode));
+        assert_eq!(diff.actual.power_mode, PowerMode::Standby);
+
+        device.release_spi().0.done();
+    }
+
+    /// Ensures the burst path writes the whole control block in one transaction.
+    #[test]
+    fn configure_burst_writes_single_block() {
+        let config = Config::new().power_mode(PowerMode::Measure).build();
+        let expectations = [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x40]),
+            Transaction::write_vec(config.register_block().to_vec()),
+            Transaction::transaction_end(),
+        ];
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+
+        block_on(device.configure_burst(config, &mut NoopDelay::new())).unwrap();
+        assert_eq!(device.config(), &config);
+
+        device.release_spi().0.done();
+    }
+
+    fn write(command: u8, data: std::vec::Vec<u8>) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![command]),
+            Transaction::write_vec(data),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Ensures a mismatching read-back re-issues the write before succeeding.
+    #[test]
+    fn write_verify_retries_mismatch() {
+        let mut expectations = std::vec::Vec::new();
+        expectations.extend(write(0x7E, vec![0x03]));
+        expectations.extend(read(0x7F, vec![0x00]));
+        expectations.extend(write(0x7E, vec![0x03]));
+        expectations.extend(read(0x7F, vec![0x03]));
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+        device.set_write_verify(WriteVerify::ReadBack { retries: 1 });
+
+        block_on(device.write_reg(PowerControl::from(0x03))).unwrap();
+
+        device.release_spi().0.done();
+    }
+
+    /// Checks exhausted retries surface VerifyFailed and SELF_TEST writes are not read back.
+    #[test]
+    fn write_verify_reports_failure_and_skips_self_test() {
+        let mut expectations = std::vec::Vec::new();
+        expectations.extend(write(0x7E, vec![0x03]));
+        expectations.extend(read(0x7F, vec![0x02]));
+        expectations.extend(write(0x80, vec![0x05]));
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+        device.set_write_verify(WriteVerify::ReadBack { retries: 0 });
+
+        assert_eq!(
+            block_on(device.write_reg(PowerControl::from(0x03))),
+            Err(Error::VerifyFailed {
+                register: 0x3F,
+                written: 0x03,
+                read: 0x02,
+            })
+        );
+        block_on(device.write_reg(SelfTest::from(0x05))).unwrap();
+
+        device.release_spi().0.done();
+    }
+
+    /// Confirms identification mismatches surface as DeviceIdMismatch.
+    #[test]
+    fn check_ids_rejects_unknown_part() {
+        let expectations = read(0x01, vec![0xAD, 0x1D, 0x00, 0x02]);
+        let mut device = Adxl372Async::new_spi(Mock::new(&expectations), Config::default());
+
+        assert_eq!(block_on(device.check_ids()), Err(Error::DeviceIdMismatch));
+
+        device.release_spi().0.done();
+    }
+}
@@ -0,0 +1,258 @@
+//! User offset trim (`OFFSET_X/Y/Z`) and the stationary offset calibration routine.
+//!
+//! Each axis has a four-bit two's complement trim added to the output. [`OffsetTrim`] holds
+//! the codes applied by [`Config`](crate::config::Config), and [`calibrate_offsets`] derives
+//! them from averaged stationary readings against a known gravity vector.
+
+use embedded_hal::delay::DelayNs;
+
+use crate::device::Adxl372;
+use crate::error::{Error, Result};
+use crate::interface::Adxl372Interface;
+use crate::params::PowerMode;
+use crate::registers::Offset;
+use crate::units::{Acceleration, raw_to_mg};
+
+/// Output shift produced by one trim code, in milli-g.
+pub const OFFSET_MG_PER_CODE: i32 = 100;
+/// Smallest trim code accepted by the four-bit `USER_OFF_*` fields.
+pub const OFFSET_MIN_CODE: i8 = -8;
+/// Largest trim code accepted by the four-bit `USER_OFF_*` fields.
+pub const OFFSET_MAX_CODE: i8 = 7;
+
+/// Per-axis user offset trim codes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OffsetTrim {
+    /// X-axis trim code.
+    pub x: i8,
+    /// Y-axis trim code.
+    pub y: i8,
+    /// Z-axis trim code.
+    pub z: i8,
+}
+
+impl OffsetTrim {
+    /// No trim applied (reset state).
+    pub const ZERO: Self = Self::new(0, 0, 0);
+
+    /// Creates a trim from per-axis codes.
+    pub const fn new(x: i8, y: i8, z: i8) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Returns `true` when every code fits the four-bit register field.
+    pub const fn is_in_range(&self) -> bool {
+        code_in_range(self.x) && code_in_range(self.y) && code_in_range(self.z)
+    }
+
+    /// Encodes the `OFFSET_X`, `OFFSET_Y` and `OFFSET_Z` register images.
+    pub fn to_registers(&self) -> [u8; 3] {
+        [self.x, self.y, self.z].map(|code| u8::from(Offset::from_code(code)))
+    }
+
+    /// Decodes the trim from the three offset register values.
+    pub fn from_registers(raw: [u8; 3]) -> Self {
+        let [x, y, z] = raw.map(|value| Offset::from(value).code());
+        Self::new(x, y, z)
+    }
+}
+
+/// Outcome of [`calibrate_offsets`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct OffsetCalibrationReport {
+    /// Number of samples averaged per axis.
+    pub samples: u16,
+    /// Averaged reading with the previous trim applied.
+    pub measured: Acceleration,
+    /// Gravity vector the sensor was expected to read.
+    pub expected: Acceleration,
+    /// Trim active before calibration.
+    pub previous: OffsetTrim,
+    /// Trim written by the calibration.
+    pub trim: OffsetTrim,
+    /// Indicates at least one axis needed more correction than the trim range allows.
+    pub saturated: bool,
+}
+
+/// Averages stationary readings and programs the trim that moves them onto `expected`.
+///
+/// The device must already be in [`PowerMode::Measure`] and held still in a known orientation,
+/// e.g. `Acceleration::new(0, 0, 1_000)` when lying flat. One sample is taken per output data
+/// period. The current trim is taken into account, so the routine can be repeated to refine
+/// the result.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidSampleCount`] when `samples` is zero and [`Error::NotMeasuring`]
+/// when the device is not measuring.
+pub fn calibrate_offsets<IFACE, CommE>(
+    device: &mut Adxl372<IFACE>,
+    delay: &mut impl DelayNs,
+    samples: u16,
+    expected: Acceleration,
+) -> Result<OffsetCalibrationReport, CommE>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+{
+    let config = *device.config();
+    if samples == 0 {
+        return Err(Error::InvalidSampleCount);
+    }
+
+    if !matches!(config.power_mode, PowerMode::Measure) {
+        return Err(Error::NotMeasuring);
+    }
+
+    let sample_period_us = 1_000_000 / config.odr.hz();
+    // Up to u16::MAX samples of ±204 800 mg overflow an i32 sum.
+    let mut sums = [0i64; 3];
+    for _ in 0..samples {
+        delay.delay_us(sample_period_us);
+        let raw = device.read_xyz_raw()?;
+        for (sum, axis) in sums.iter_mut().zip(raw) {
+            *sum += i64::from(raw_to_mg(axis));
+        }
+    }
+
+    let [x, y, z] = sums.map(|sum| average_mg(sum, samples));
+    let measured = Acceleration::new(x, y, z);
+    let previous = config.offsets;
+
+    let mut saturated = false;
+    let mut correct = |current: i8, measured_mg: i32, expected_mg: i32| {
+        let error_mg = i64::from(expected_mg - measured_mg);
+        let code = i32::from(current) + div_round(error_mg, i64::from(OFFSET_MG_PER_CODE)) as i32;
+        let clamped = code.clamp(i32::from(OFFSET_MIN_CODE), i32::from(OFFSET_MAX_CODE));
+        saturated |= clamped != code;
+        clamped as i8
+    };
+    let trim = OffsetTrim::new(
+        correct(previous.x, measured.x_mg, expected.x_mg),
+        correct(previous.y, measured.y_mg, expected.y_mg),
+        correct(previous.z, measured.z_mg, expected.z_mg),
+    );
+
+    device.configure_offsets(trim)?;
+
+    Ok(OffsetCalibrationReport {
+        samples,
+        measured,
+        expected,
+        previous,
+        trim,
+        saturated,
+    })
+}
+
+const fn code_in_range(code: i8) -> bool {
+    code >= OFFSET_MIN_CODE && code <= OFFSET_MAX_CODE
+}
+
+// Rounded mean of `samples` readings; the mean of i32 values always fits an i32.
+fn average_mg(sum: i64, samples: u16) -> i32 {
+    div_round(sum, i64::from(samples)) as i32
+}
+
+// Integer division rounding half away from zero.
+fn div_round(numerator: i64, denominator: i64) -> i64 {
+    let half = denominator / 2;
+    if numerator >= 0 {
+        (numerator + half) / denominator
+    } else {
+        (numerator - half) / denominator
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{OffsetTrim, average_mg, calibrate_offsets};
+    use crate::config::Config;
+    use crate::device::Adxl372;
+    use crate::error::Error;
+    use crate::params::PowerMode;
+    use crate::units::Acceleration;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+    use std::vec::Vec;
+
+    fn read(command: u8, response: Vec<u8>) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![command]),
+            Transaction::read_vec(response),
+            Transaction::transaction_end(),
+        ]
+    }
+
+    /// Ensures trims round-trip through the four-bit register encoding.
+    #[test]
+    fn trim_roundtrips_through_registers() {
+        let trim = OffsetTrim::new(-8, 0, 7);
+
+        assert_eq!(trim.to_registers(), [0x08, 0x00, 0x07]);
+        assert_eq!(OffsetTrim::from_registers(trim.to_registers()), trim);
+        assert!(!OffsetTrim::new(8, 0, 0).is_in_range());
+    }
+
+    /// Ensures the longest accepted run at full scale averages without overflowing.
+    #[test]
+    fn average_handles_full_scale_sums() {
+        let full_scale = -2048 * 100;
+        let sum = i64::from(full_scale) * i64::from(u16::MAX);
+
+        assert_eq!(average_mg(sum, u16::MAX), full_scale);
+        assert_eq!(average_mg(-sum, u16::MAX), -full_scale);
+        assert_eq!(average_mg(-3, 2), -2);
+    }
+
+    /// Averages two samples and writes the trim that cancels the error against +1 g on Z.
+    #[test]
+    fn calibration_writes_correcting_trim() {
+        let config = Config::new().power_mode(PowerMode::Measure).build();
+        let mut expectations = Vec::new();
+        expectations.extend(read(0x11, vec![0x00, 0x20, 0xFF, 0xF0, 0x00, 0x80]));
+        expectations.extend(read(0x11, vec![0x00, 0x20, 0x00, 0x00, 0x00, 0x80]));
+        expectations.extend([
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![0x40]),
+            Transaction::write_vec(vec![0x0E, 0x01, 0x02]),
+            Transaction::transaction_end(),
+        ]);
+        let mut device = Adxl372::new_spi(Mock::new(&expectations), config);
+
+        let report = calibrate_offsets(
+            &mut device,
+            &mut NoopDelay::new(),
+            2,
+            Acceleration::new(0, 0, 1_000),
+        )
+        .unwrap();
+
+        assert_eq!(report.measured, Acceleration::new(200, -50, 800));
+        assert_eq!(report.trim, OffsetTrim::new(-2, 1, 2));
+        assert!(!report.saturated);
+        assert_eq!(device.config().offsets, report.trim);
+
+        device.release_spi().0.done();
+    }
+
+    /// Confirms calibration refuses to sample while the device is in standby.
+    #[test]
+    fn calibration_requires_measurement_mode() {
+        let expectations: [Transaction<u8>; 0] = [];
+        let mut device = Adxl372::new_spi(Mock::new(&expectations), Config::default());
+
+        let result = calibrate_offsets(
+            &mut device,
+            &mut NoopDelay::new(),
+            8,
+            Acceleration::new(0, 0, 1_000),
+        );
+        assert_eq!(result, Err(Error::NotMeasuring));
+
+        device.release_spi().0.done();
+    }
+}
@@ -18,11 +18,27 @@
 //! config.validate().unwrap();
 //! ```
 
+use crate::activity::{
+    ACTIVITY_BLOCK_BYTES, ACTIVITY_TIME_MAX_COUNT, INACTIVITY_TIME_MAX_COUNT, MotionDetector,
+    MotionThreshold, activity_time_counts, decode_activity_block, encode_activity_block,
+    inactivity_time_counts,
+};
+use crate::calibration::OffsetTrim;
+use crate::fifo::{FIFO_MAX_WATERMARK, FifoSettings};
+use crate::interrupt::{InterruptMap, InterruptMaps};
 use crate::params::{
-    AutoSleep, Bandwidth, ExtClk, ExtSync, HpfDisable, I2cHsmEn, InstantOnThreshold, LinkLoopMode,
-    LowNoise, LpfDisable, OutputDataRate, PowerMode, SettleFilter, UserOrDisable, WakeUpRate,
+    AutoSleep, Bandwidth, ExtClk, ExtSync, FifoFormat, FifoMode, HighPassCorner, HpfDisable,
+    I2cHsmEn, InstantOnThreshold, LinkLoopMode, LowNoise, LpfDisable, OutputDataRate, PowerMode,
+    SettleFilter, UserOrDisable, WakeUpRate,
+};
+use crate::registers::{
+    FifoControl, Hpf, Measure, PowerControl, REG_FIFO_SAMPLES, REG_HPF, REG_INT1_MAP, REG_MEASURE,
+    REG_OFFSET_X, REG_POWER_CTL, REG_THRESH_ACT_X_H, REG_TIMING, Timing,
 };
 
+/// Number of bytes in the contiguous `OFFSET_X..=POWER_CTL` configuration block.
+pub(crate) const CONFIG_BLOCK_BYTES: usize = (REG_POWER_CTL - REG_OFFSET_X) as usize + 1;
+
 /// User-facing configuration for the ADXL372 sensor.
 ///
 /// Prefer using the builder [`Config::new`] to construct instances and validate
@@ -57,29 +73,458 @@ pub struct Config {
     pub lpf_disable: LpfDisable,
     /// High-pass filter disable selection.
     pub hpf_disable: HpfDisable,
+    /// High-pass filter corner, used while the filter is enabled.
+    pub hpf_corner: HighPassCorner,
     /// Operating power mode selection.
     pub power_mode: PowerMode,
+    /// FIFO operating mode.
+    pub fifo_mode: FifoMode,
+    /// FIFO packing format.
+    pub fifo_format: FifoFormat,
+    /// FIFO watermark expressed in entries (nine bits).
+    pub fifo_watermark: u16,
+    /// Activity detector threshold and duration.
+    pub activity: MotionDetector,
+    /// Inactivity detector threshold and duration.
+    pub inactivity: MotionDetector,
+    /// Activity2 (motion warning) detector threshold.
+    pub activity2: MotionThreshold,
+    /// Events routed to the INT1 pin and its polarity.
+    pub int1_map: InterruptMap,
+    /// Events routed to the INT2 pin and its polarity.
+    pub int2_map: InterruptMap,
+    /// Per-axis user offset trim.
+    pub offsets: OffsetTrim,
 }
 
 impl Config {
     /// Begins building a [`Config`] using the builder pattern.
+    #[allow(clippy::new_ret_no_self)]
     pub fn new() -> ConfigBuilder {
         ConfigBuilder::new()
     }
 
     /// Checks whether this configuration is valid according to datasheet rules.
     ///
+    /// Every cross-field constraint is checked, in order: sampling (Nyquist), autosleep and
+    /// link/loop, wake-up mode, instant-on, external sync, motion detector ranges, high-pass
+    /// corner and FIFO watermark.
+    ///
     /// # Errors
     ///
-    /// Returns [`ConfigError::NyquistViolation`] when the selected bandwidth exceeds
-    /// the $\frac{ODR}{2}$ Nyquist limit.
+    /// Returns the first violated rule as a [`ConfigError`]; each variant documents the
+    /// constraint it enforces.
     pub fn validate(&self) -> core::result::Result<(), ConfigError> {
+        let wake_up = matches!(self.power_mode, PowerMode::WakeUp);
+        let autosleep = matches!(self.autosleep, AutoSleep::Enabled);
+
         if self.bandwidth.max_hz() * 2 > self.odr.hz() {
             return Err(ConfigError::NyquistViolation);
         }
 
+        if autosleep && matches!(self.linkloop, LinkLoopMode::Default) {
+            return Err(ConfigError::AutosleepRequiresLinkLoop);
+        }
+
+        if wake_up {
+            if !self.activity.threshold.axes.any() {
+                return Err(ConfigError::WakeUpRequiresActivity);
+            }
+
+            if !matches!(self.odr, OutputDataRate::Od400Hz) {
+                return Err(ConfigError::WakeUpOdrUnsupported);
+            }
+
+            if matches!(self.lpf_disable, LpfDisable::Enabled)
+                || matches!(self.hpf_disable, HpfDisable::Enabled)
+            {
+                return Err(ConfigError::WakeUpFiltersEnabled);
+            }
+        }
+
+        let instant_on = matches!(self.power_mode, PowerMode::InstantOn);
+        if instant_on && autosleep {
+            return Err(ConfigError::InstantOnAutosleepConflict);
+        }
+
+        if matches!(self.fifo_format, FifoFormat::Peak)
+            && !matches!(self.fifo_mode, FifoMode::Bypass)
+            && !instant_on
+        {
+            return Err(ConfigError::PeakFormatRequiresInstantOn);
+        }
+
+        if matches!(self.ext_sync, ExtSync::Enabled) && matches!(self.ext_clk, ExtClk::Disabled) {
+            return Err(ConfigError::ExtSyncRequiresExtClk);
+        }
+
+        if !self.activity.threshold.is_in_range()
+            || !self.inactivity.threshold.is_in_range()
+            || !self.activity2.is_in_range()
+        {
+            return Err(ConfigError::ThresholdOutOfRange);
+        }
+
+        if activity_time_counts(self.activity.duration_ms, self.odr) > ACTIVITY_TIME_MAX_COUNT
+            || inactivity_time_counts(self.inactivity.duration_ms, self.odr)
+                > INACTIVITY_TIME_MAX_COUNT
+        {
+            return Err(ConfigError::DurationOutOfRange);
+        }
+
+        if !self.offsets.is_in_range() {
+            return Err(ConfigError::OffsetOutOfRange);
+        }
+
+        let detectors_enabled = self.activity.threshold.axes.any()
+            || self.inactivity.threshold.axes.any()
+            || self.activity2.axes.any();
+        if matches!(self.hpf_disable, HpfDisable::Enabled)
+            && detectors_enabled
+            && !self.hpf_corner.is_activity_lp_compatible(self.odr)
+        {
+            return Err(ConfigError::HpfCornerIncompatible);
+        }
+
+        if self.fifo_watermark > FIFO_MAX_WATERMARK {
+            return Err(ConfigError::FifoWatermarkOutOfRange);
+        }
+
+        if !matches!(self.fifo_mode, FifoMode::Bypass)
+            && !self
+                .fifo_watermark
+                .is_multiple_of(u16::from(self.fifo_format.axis_count()))
+        {
+            return Err(ConfigError::FifoWatermarkMisaligned);
+        }
+
         Ok(())
     }
+
+    /// Returns the FIFO portion of this configuration.
+    pub fn fifo_settings(&self) -> FifoSettings {
+        FifoSettings::new(self.fifo_watermark, self.fifo_mode, self.fifo_format)
+    }
+
+    /// Returns the interrupt pin portion of this configuration.
+    pub fn interrupt_maps(&self) -> InterruptMaps {
+        InterruptMaps {
+            int1: self.int1_map,
+            int2: self.int2_map,
+        }
+    }
+
+    /// Compares this configuration against `actual`, field by field.
+    pub fn diff(&self, actual: &Config) -> ConfigDiff {
+        let mismatched = ConfigField::ALL
+            .iter()
+            .filter(|field| !field.matches(self, actual))
+            .fold(0, |mask, field| mask | field.bit());
+
+        ConfigDiff {
+            expected: *self,
+            actual: *actual,
+            mismatched,
+        }
+    }
+
+    /// Decodes a full configuration from the `OFFSET_X..=POWER_CTL` register block.
+    pub(crate) fn from_register_block(block: &[u8; CONFIG_BLOCK_BYTES]) -> Self {
+        let at = |register: u8| block[usize::from(register - REG_OFFSET_X)];
+        let mut config = Config::default();
+
+        // Timing comes first: the activity timers are decoded against the ODR.
+        config.update_from_timing(Timing::from(at(REG_TIMING)));
+        config.update_from_measure(Measure::from(at(REG_MEASURE)));
+        config.update_from_power_control(PowerControl::from(at(REG_POWER_CTL)));
+        config.update_from_hpf(Hpf::from(at(REG_HPF)));
+        config.update_from_fifo(FifoSettings::from_registers(
+            at(REG_FIFO_SAMPLES),
+            FifoControl::from(at(REG_FIFO_SAMPLES + 1)),
+        ));
+        config.update_from_interrupts(InterruptMaps::from_registers([
+            at(REG_INT1_MAP),
+            at(REG_INT1_MAP + 1),
+        ]));
+        config.offsets = OffsetTrim::from_registers([
+            at(REG_OFFSET_X),
+            at(REG_OFFSET_X + 1),
+            at(REG_OFFSET_X + 2),
+        ]);
+
+        let start = usize::from(REG_THRESH_ACT_X_H - REG_OFFSET_X);
+        let mut activity = [0u8; ACTIVITY_BLOCK_BYTES];
+        activity.copy_from_slice(&block[start..start + ACTIVITY_BLOCK_BYTES]);
+        config.update_from_activity_block(&activity);
+        config
+    }
+
+    /// Encodes the full `OFFSET_X..=POWER_CTL` register block for a single burst write.
+    ///
+    /// Reserved bits are written as zero, matching their reset state. `POWER_CTL` is the last
+    /// byte, so a burst write changes the power mode only after everything else is in place.
+    pub(crate) fn register_block(&self) -> [u8; CONFIG_BLOCK_BYTES] {
+        let mut block = [0u8; CONFIG_BLOCK_BYTES];
+        let mut put = |register: u8, values: &[u8]| {
+            let start = usize::from(register - REG_OFFSET_X);
+            block[start..start + values.len()].copy_from_slice(values);
+        };
+
+        let mut hpf = Hpf::from(0);
+        let mut timing = Timing::from(0);
+        let mut measure = Measure::from(0);
+        let mut power = PowerControl::from(0);
+        self.apply_to_hpf(&mut hpf);
+        self.apply_to_timing(&mut timing);
+        self.apply_to_measure(&mut measure);
+        self.apply_to_power_control(&mut power);
+        let (samples, control) = self.fifo_settings().to_registers();
+
+        put(REG_OFFSET_X, &self.offsets.to_registers());
+        put(REG_THRESH_ACT_X_H, &self.activity_block());
+        put(REG_HPF, &[u8::from(hpf)]);
+        put(REG_FIFO_SAMPLES, &[samples, u8::from(control)]);
+        put(REG_INT1_MAP, &self.interrupt_maps().to_registers());
+        put(
+            REG_TIMING,
+            &[u8::from(timing), u8::from(measure), u8::from(power)],
+        );
+        block
+    }
+
+    /// Returns this configuration as the device would report it back.
+    ///
+    /// Thresholds are truncated to 100 mg steps and durations to whole timer counts, so a
+    /// read-back only matches after the same quantisation.
+    pub(crate) fn quantized(&self) -> Self {
+        let mut config = *self;
+        config.update_from_activity_block(&self.activity_block());
+        config
+    }
+
+    /// Refreshes the detector fields from a `THRESH_ACT_X_H..=THRESH_ACT2_Z_L` block.
+    pub(crate) fn update_from_activity_block(&mut self, block: &[u8; ACTIVITY_BLOCK_BYTES]) {
+        let (activity, inactivity, activity2) = decode_activity_block(block, self.odr);
+        self.activity = activity;
+        self.inactivity = inactivity;
+        self.activity2 = activity2;
+    }
+
+    /// Refreshes the interrupt pin fields from a decoded read-back.
+    pub(crate) fn update_from_interrupts(&mut self, maps: InterruptMaps) {
+        self.int1_map = maps.int1;
+        self.int2_map = maps.int2;
+    }
+
+    /// Encodes the activity, inactivity and Activity2 registers starting at `THRESH_ACT_X_H`.
+    pub(crate) fn activity_block(&self) -> [u8; ACTIVITY_BLOCK_BYTES] {
+        encode_activity_block(&self.activity, &self.inactivity, &self.activity2, self.odr)
+    }
+
+    /// Copies the timing fields into a `TIMING` register image.
+    pub(crate) fn apply_to_timing(&self, timing: &mut Timing) {
+        timing.set_odr(self.odr);
+        timing.set_wake_up_rate(self.wakeup_rate);
+        timing.set_ext_clk(self.ext_clk);
+        timing.set_ext_sync(self.ext_sync);
+    }
+
+    /// Refreshes the timing fields from a `TIMING` register image.
+    pub(crate) fn update_from_timing(&mut self, timing: Timing) {
+        self.odr = timing.odr();
+        self.wakeup_rate = timing.wake_up_rate();
+        self.ext_clk = timing.ext_clk();
+        self.ext_sync = timing.ext_sync();
+    }
+
+    /// Copies the measurement fields into a `MEASURE` register image.
+    pub(crate) fn apply_to_measure(&self, measure: &mut Measure) {
+        measure.set_bandwidth(self.bandwidth);
+        measure.set_low_noise(self.low_noise);
+        measure.set_link_loop_mode(self.linkloop);
+        measure.set_autosleep(matches!(self.autosleep, AutoSleep::Enabled));
+        measure.set_user_or_disable(matches!(self.user_or_disable, UserOrDisable::Disabled));
+    }
+
+    /// Refreshes the measurement fields from a `MEASURE` register image.
+    pub(crate) fn update_from_measure(&mut self, measure: Measure) {
+        self.bandwidth = measure.bandwidth();
+        self.low_noise = measure.low_noise();
+        self.linkloop = measure.link_loop_mode();
+        self.autosleep = if measure.autosleep() {
+            AutoSleep::Enabled
+        } else {
+            AutoSleep::Disabled
+        };
+        self.user_or_disable = if measure.user_or_disable() {
+            UserOrDisable::Disabled
+        } else {
+            UserOrDisable::Enabled
+        };
+    }
+
+    /// Copies the high-pass corner into an `HPF` register image.
+    pub(crate) fn apply_to_hpf(&self, hpf: &mut Hpf) {
+        hpf.set_corner(self.hpf_corner);
+    }
+
+    /// Refreshes the high-pass corner from an `HPF` register image.
+    pub(crate) fn update_from_hpf(&mut self, hpf: Hpf) {
+        self.hpf_corner = hpf.corner();
+    }
+
+    /// Copies the power control fields into a `POWER_CTL` register image.
+    pub(crate) fn apply_to_power_control(&self, power: &mut PowerControl) {
+        power.set_mode(self.power_mode);
+        power.set_hpf_disable(matches!(self.hpf_disable, HpfDisable::Disabled));
+        power.set_lpf_disable(matches!(self.lpf_disable, LpfDisable::Disabled));
+        power.set_instant_on_threshold(self.instant_on_threshold);
+        power.set_filter_settle(self.filter_settle);
+        power.set_i2c_high_speed_enable(matches!(self.i2c_hsm_en, I2cHsmEn::Enabled));
+    }
+
+    /// Refreshes the FIFO fields from a decoded settings snapshot.
+    pub(crate) fn update_from_fifo(&mut self, settings: FifoSettings) {
+        self.fifo_watermark = settings.watermark;
+        self.fifo_mode = settings.mode;
+        self.fifo_format = settings.format;
+    }
+
+    /// Refreshes the power control fields from a `POWER_CTL` register image.
+    pub(crate) fn update_from_power_control(&mut self, power: PowerControl) {
+        self.power_mode = power.mode();
+        self.hpf_disable = if power.hpf_disable() {
+            HpfDisable::Disabled
+        } else {
+            HpfDisable::Enabled
+        };
+        self.lpf_disable = if power.lpf_disable() {
+            LpfDisable::Disabled
+        } else {
+            LpfDisable::Enabled
+        };
+        self.filter_settle = power.filter_settle();
+        self.instant_on_threshold = power.instant_on_threshold();
+        self.i2c_hsm_en = if power.i2c_high_speed_enable() {
+            I2cHsmEn::Enabled
+        } else {
+            I2cHsmEn::Disabled
+        };
+    }
+}
+
+/// Register update issued by `configure`, in the order given by [`CONFIGURE_SEQUENCE`].
+///
+/// Shared by the blocking and async drivers so both program the device identically; each
+/// driver only performs the bus accesses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum ConfigStep {
+    /// Read-modify-write of `TIMING`.
+    Timing,
+    /// Read-modify-write of `MEASURE`.
+    Measure,
+    /// Read-modify-write of `HPF`.
+    Hpf,
+    /// Burst write of `OFFSET_X..=OFFSET_Z`.
+    Offsets,
+    /// Burst write of the activity, inactivity and Activity2 thresholds and timers.
+    Activity,
+    /// Burst write of `FIFO_SAMPLES` and `FIFO_CTL`.
+    Fifo,
+    /// Burst write of `INT1_MAP` and `INT2_MAP`.
+    Interrupts,
+    /// Read-modify-write of `POWER_CTL`.
+    PowerControl,
+}
+
+/// Programming order used by `configure`.
+///
+/// `POWER_CTL` comes last so the FIFO, detectors and interrupt pins are reprogrammed before
+/// measurement resumes.
+pub(crate) const CONFIGURE_SEQUENCE: [ConfigStep; 8] = [
+    ConfigStep::Timing,
+    ConfigStep::Measure,
+    ConfigStep::Hpf,
+    ConfigStep::Offsets,
+    ConfigStep::Activity,
+    ConfigStep::Fifo,
+    ConfigStep::Interrupts,
+    ConfigStep::PowerControl,
+];
+
+impl ConfigStep {
+    /// First register written by the step.
+    pub(crate) const fn register(self) -> u8 {
+        match self {
+            Self::Timing => REG_TIMING,
+            Self::Measure => REG_MEASURE,
+            Self::Hpf => REG_HPF,
+            Self::Offsets => REG_OFFSET_X,
+            Self::Activity => REG_THRESH_ACT_X_H,
+            Self::Fifo => REG_FIFO_SAMPLES,
+            Self::Interrupts => REG_INT1_MAP,
+            Self::PowerControl => REG_POWER_CTL,
+        }
+    }
+
+    /// Encodes a burst-write step.
+    ///
+    /// Returns `None` for read-modify-write steps, which go through [`update`](Self::update).
+    pub(crate) fn block(self, config: &Config) -> Option<StepBlock> {
+        let put = |values: &[u8]| {
+            let mut bytes = [0u8; CONFIG_BLOCK_BYTES];
+            bytes[..values.len()].copy_from_slice(values);
+            Some(StepBlock {
+                bytes,
+                len: values.len(),
+            })
+        };
+        match self {
+            Self::Offsets => put(&config.offsets.to_registers()),
+            Self::Activity => put(&config.activity_block()),
+            Self::Fifo => {
+                let (samples, control) = config.fifo_settings().to_registers();
+                put(&[samples, u8::from(control)])
+            }
+            Self::Interrupts => put(&config.interrupt_maps().to_registers()),
+            Self::Timing | Self::Measure | Self::Hpf | Self::PowerControl => None,
+        }
+    }
+
+    /// Returns the value a read-modify-write step writes over `current`, or `None` when the
+    /// register already holds it and the write is skipped.
+    ///
+    /// Burst-write steps always return `None`.
+    pub(crate) fn update(self, config: &Config, current: u8) -> Option<u8> {
+        fn apply<R: From<u8> + Into<u8>>(current: u8, mutate: impl FnOnce(&mut R)) -> u8 {
+            let mut reg = R::from(current);
+            mutate(&mut reg);
+            reg.into()
+        }
+
+        let updated = match self {
+            Self::Timing => apply(current, |timing| config.apply_to_timing(timing)),
+            Self::Measure => apply(current, |measure| config.apply_to_measure(measure)),
+            Self::Hpf => apply(current, |hpf| config.apply_to_hpf(hpf)),
+            Self::PowerControl => apply(current, |power| config.apply_to_power_control(power)),
+            Self::Offsets | Self::Activity | Self::Fifo | Self::Interrupts => current,
+        };
+        (updated != current).then_some(updated)
+    }
+}
+
+/// Bytes of a burst-write [`ConfigStep`], starting at [`ConfigStep::register`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct StepBlock {
+    bytes: [u8; CONFIG_BLOCK_BYTES],
+    len: usize,
+}
+
+impl StepBlock {
+    /// Returns the encoded registers.
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
 }
 
 /// Builder for [`Config`] allowing piecemeal construction.
@@ -158,6 +603,14 @@ impl ConfigBuilder {
         self
     }
 
+    /// Sets the high-pass filter corner.
+    ///
+    /// The corner frequency scales with the ODR; see [`HighPassCorner::hz`].
+    pub fn hpf_corner(mut self, corner: HighPassCorner) -> Self {
+        self.config.hpf_corner = corner;
+        self
+    }
+
     /// Enables or disables I2C high-speed mode.
     pub fn i2c_hsm_en(mut self, setting: I2cHsmEn) -> Self {
         self.config.i2c_hsm_en = setting;
@@ -188,10 +641,83 @@ impl ConfigBuilder {
         self
     }
 
+    /// Sets the FIFO operating mode.
+    ///
+    /// Outside bypass the watermark is rounded down to a whole number of sample sets; set
+    /// [`fifo_watermark`](Self::fifo_watermark) afterwards to pick another value.
+    pub fn fifo_mode(mut self, mode: FifoMode) -> Self {
+        self.config.fifo_mode = mode;
+        self.align_fifo_watermark();
+        self
+    }
+
+    /// Sets the FIFO packing format, realigning the watermark as [`fifo_mode`](Self::fifo_mode)
+    /// does.
+    pub fn fifo_format(mut self, format: FifoFormat) -> Self {
+        self.config.fifo_format = format;
+        self.align_fifo_watermark();
+        self
+    }
+
+    /// Sets the FIFO watermark in entries.
+    pub fn fifo_watermark(mut self, watermark: u16) -> Self {
+        self.config.fifo_watermark = watermark;
+        self
+    }
+
+    /// Sets the activity detector.
+    pub fn activity(mut self, detector: MotionDetector) -> Self {
+        self.config.activity = detector;
+        self
+    }
+
+    /// Sets the inactivity detector.
+    pub fn inactivity(mut self, detector: MotionDetector) -> Self {
+        self.config.inactivity = detector;
+        self
+    }
+
+    /// Sets the Activity2 detector threshold.
+    pub fn activity2(mut self, threshold: MotionThreshold) -> Self {
+        self.config.activity2 = threshold;
+        self
+    }
+
+    /// Sets the events routed to the INT1 pin.
+    pub fn int1_map(mut self, map: InterruptMap) -> Self {
+        self.config.int1_map = map;
+        self
+    }
+
+    /// Sets the events routed to the INT2 pin.
+    pub fn int2_map(mut self, map: InterruptMap) -> Self {
+        self.config.int2_map = map;
+        self
+    }
+
+    /// Sets the per-axis user offset trim.
+    pub fn offsets(mut self, offsets: OffsetTrim) -> Self {
+        self.config.offsets = offsets;
+        self
+    }
+
     /// Finalizes the builder and returns the [`Config`].
     pub fn build(self) -> Config {
         self.config
     }
+
+    fn align_fifo_watermark(&mut self) {
+        if !matches!(self.config.fifo_mode, FifoMode::Bypass) {
+            let axes = u16::from(self.config.fifo_format.axis_count());
+            self.config.fifo_watermark -= self.config.fifo_watermark % axes;
+        }
+    }
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
 }
 
 impl Default for Config {
@@ -211,7 +737,17 @@ impl Default for Config {
             filter_settle: SettleFilter::Ms370,
             lpf_disable: LpfDisable::Enabled,
             hpf_disable: HpfDisable::Enabled,
+            hpf_corner: HighPassCorner::Corner0,
             power_mode: PowerMode::Standby,
+            fifo_mode: FifoMode::Bypass,
+            fifo_format: FifoFormat::XYZ,
+            fifo_watermark: 0x80,
+            activity: MotionDetector::disabled(),
+            inactivity: MotionDetector::disabled(),
+            activity2: MotionThreshold::disabled(),
+            int1_map: InterruptMap::NONE,
+            int2_map: InterruptMap::NONE,
+            offsets: OffsetTrim::ZERO,
         }
     }
 }
@@ -221,4 +757,352 @@ impl Default for Config {
 pub enum ConfigError {
     /// Requested bandwidth violates Nyquist sampling limits for the chosen ODR.
     NyquistViolation,
+    /// Autosleep only operates in linked or loop mode.
+    AutosleepRequiresLinkLoop,
+    /// Wake-up mode needs an enabled activity detector to ever leave the low-power state.
+    WakeUpRequiresActivity,
+    /// Wake-up mode samples at the 400 Hz base rate; other ODR selections are unsupported.
+    WakeUpOdrUnsupported,
+    /// Wake-up mode samples too sparsely for the filters to settle; both must be disabled.
+    WakeUpFiltersEnabled,
+    /// Instant-on mode manages its own wake/sleep cycle and cannot be combined with autosleep.
+    InstantOnAutosleepConflict,
+    /// The peak FIFO format is only populated by instant-on events.
+    PeakFormatRequiresInstantOn,
+    /// External sync requires the external clock to be enabled.
+    ExtSyncRequiresExtClk,
+    /// A motion threshold exceeds the 11-bit `THRESH_*` range.
+    ThresholdOutOfRange,
+    /// An activity or inactivity duration does not fit its timer at the selected ODR.
+    DurationOutOfRange,
+    /// A user offset trim code exceeds the four-bit `USER_OFF_*` range.
+    OffsetOutOfRange,
+    /// The enabled high-pass corner exceeds 10 Hz while a motion detector is active.
+    HpfCornerIncompatible,
+    /// FIFO watermark exceeds the nine-bit `FIFO_SAMPLES` range.
+    FifoWatermarkOutOfRange,
+    /// FIFO watermark is not a whole number of sample sets for the selected format.
+    FifoWatermarkMisaligned,
+}
+
+/// Individual [`Config`] fields, as reported by [`ConfigDiff`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigField {
+    /// [`Config::odr`].
+    Odr,
+    /// [`Config::wakeup_rate`].
+    WakeUpRate,
+    /// [`Config::ext_clk`].
+    ExtClk,
+    /// [`Config::ext_sync`].
+    ExtSync,
+    /// [`Config::user_or_disable`].
+    UserOrDisable,
+    /// [`Config::autosleep`].
+    AutoSleep,
+    /// [`Config::linkloop`].
+    LinkLoop,
+    /// [`Config::low_noise`].
+    LowNoise,
+    /// [`Config::bandwidth`].
+    Bandwidth,
+    /// [`Config::i2c_hsm_en`].
+    I2cHsmEn,
+    /// [`Config::instant_on_threshold`].
+    InstantOnThreshold,
+    /// [`Config::filter_settle`].
+    FilterSettle,
+    /// [`Config::lpf_disable`].
+    LpfDisable,
+    /// [`Config::hpf_disable`].
+    HpfDisable,
+    /// [`Config::hpf_corner`].
+    HpfCorner,
+    /// [`Config::power_mode`].
+    PowerMode,
+    /// [`Config::fifo_mode`].
+    FifoMode,
+    /// [`Config::fifo_format`].
+    FifoFormat,
+    /// [`Config::fifo_watermark`].
+    FifoWatermark,
+    /// [`Config::activity`].
+    Activity,
+    /// [`Config::inactivity`].
+    Inactivity,
+    /// [`Config::activity2`].
+    Activity2,
+    /// [`Config::int1_map`].
+    Int1Map,
+    /// [`Config::int2_map`].
+    Int2Map,
+    /// [`Config::offsets`].
+    Offsets,
+}
+
+impl ConfigField {
+    /// Every field, in [`Config`] declaration order.
+    pub const ALL: [ConfigField; 25] = [
+        Self::Odr,
+        Self::WakeUpRate,
+        Self::ExtClk,
+        Self::ExtSync,
+        Self::UserOrDisable,
+        Self::AutoSleep,
+        Self::LinkLoop,
+        Self::LowNoise,
+        Self::Bandwidth,
+        Self::I2cHsmEn,
+        Self::InstantOnThreshold,
+        Self::FilterSettle,
+        Self::LpfDisable,
+        Self::HpfDisable,
+        Self::HpfCorner,
+        Self::PowerMode,
+        Self::FifoMode,
+        Self::FifoFormat,
+        Self::FifoWatermark,
+        Self::Activity,
+        Self::Inactivity,
+        Self::Activity2,
+        Self::Int1Map,
+        Self::Int2Map,
+        Self::Offsets,
+    ];
+
+    const fn bit(self) -> u32 {
+        1 << self as u32
+    }
+
+    fn matches(self, a: &Config, b: &Config) -> bool {
+        match self {
+            Self::Odr => a.odr == b.odr,
+            Self::WakeUpRate => a.wakeup_rate == b.wakeup_rate,
+            Self::ExtClk => a.ext_clk == b.ext_clk,
+            Self::ExtSync => a.ext_sync == b.ext_sync,
+            Self::UserOrDisable => a.user_or_disable == b.user_or_disable,
+            Self::AutoSleep => a.autosleep == b.autosleep,
+            Self::LinkLoop => a.linkloop == b.linkloop,
+            Self::LowNoise => a.low_noise == b.low_noise,
+            Self::Bandwidth => a.bandwidth == b.bandwidth,
+            Self::I2cHsmEn => a.i2c_hsm_en == b.i2c_hsm_en,
+            Self::InstantOnThreshold => a.instant_on_threshold == b.instant_on_threshold,
+            Self::FilterSettle => a.filter_settle == b.filter_settle,
+            Self::LpfDisable => a.lpf_disable == b.lpf_disable,
+            Self::HpfDisable => a.hpf_disable == b.hpf_disable,
+            Self::HpfCorner => a.hpf_corner == b.hpf_corner,
+            Self::PowerMode => a.power_mode == b.power_mode,
+            Self::FifoMode => a.fifo_mode == b.fifo_mode,
+            Self::FifoFormat => a.fifo_format == b.fifo_format,
+            Self::FifoWatermark => a.fifo_watermark == b.fifo_watermark,
+            Self::Activity => a.activity == b.activity,
+            Self::Inactivity => a.inactivity == b.inactivity,
+            Self::Activity2 => a.activity2 == b.activity2,
+            Self::Int1Map => a.int1_map == b.int1_map,
+            Self::Int2Map => a.int2_map == b.int2_map,
+            Self::Offsets => a.offsets == b.offsets,
+        }
+    }
+}
+
+/// Per-field comparison between an expected and an actual [`Config`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ConfigDiff {
+    /// Configuration the driver expected to find.
+    pub expected: Config,
+    /// Configuration reported by the device.
+    pub actual: Config,
+    mismatched: u32,
+}
+
+impl ConfigDiff {
+    /// Returns `true` when every field matches.
+    pub const fn is_match(&self) -> bool {
+        self.mismatched == 0
+    }
+
+    /// Returns `true` when `field` differs between the two configurations.
+    pub const fn contains(&self, field: ConfigField) -> bool {
+        self.mismatched & field.bit() != 0
+    }
+
+    /// Returns the number of mismatched fields.
+    pub const fn len(&self) -> usize {
+        self.mismatched.count_ones() as usize
+    }
+
+    /// Returns `true` when no field differs; equivalent to [`is_match`](Self::is_match).
+    pub const fn is_empty(&self) -> bool {
+        self.is_match()
+    }
+
+    /// Iterates over the mismatched fields in [`Config`] declaration order.
+    pub fn fields(&self) -> impl Iterator<Item = ConfigField> + '_ {
+        ConfigField::ALL
+            .into_iter()
+            .filter(|field| self.contains(*field))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CONFIG_BLOCK_BYTES, Config, ConfigError, ConfigField};
+    use crate::activity::{AxisMask, MotionDetector, MotionThreshold, ThresholdMode};
+    use crate::params::{
+        AutoSleep, Bandwidth, ExtSync, FifoFormat, FifoMode, HpfDisable, LinkLoopMode, LpfDisable,
+        OutputDataRate, PowerMode,
+    };
+    use crate::registers::PowerControl;
+
+    /// Confirms the reset-state configuration passes every rule.
+    #[test]
+    fn default_config_is_valid() {
+        assert_eq!(Config::default().validate(), Ok(()));
+    }
+
+    /// Checks that each mode-level restriction reports its own variant.
+    #[test]
+    fn mode_rules_report_distinct_errors() {
+        let cases = [
+            (
+                Config::new()
+                    .odr(OutputDataRate::Od400Hz)
+                    .bandwidth(Bandwidth::Bw400Hz)
+                    .build(),
+                ConfigError::NyquistViolation,
+            ),
+            (
+                Config::new().autosleep(AutoSleep::Enabled).build(),
+                ConfigError::AutosleepRequiresLinkLoop,
+            ),
+            (
+                Config::new().power_mode(PowerMode::WakeUp).build(),
+                ConfigError::WakeUpRequiresActivity,
+            ),
+            (
+                Config::new()
+                    .power_mode(PowerMode::InstantOn)
+                    .autosleep(AutoSleep::Enabled)
+                    .linkloop(LinkLoopMode::Loop)
+                    .build(),
+                ConfigError::InstantOnAutosleepConflict,
+            ),
+            (
+                Config::new()
+                    .fifo_mode(FifoMode::Stream)
+                    .fifo_format(FifoFormat::Peak)
+                    .build(),
+                ConfigError::PeakFormatRequiresInstantOn,
+            ),
+            (
+                Config::new().ext_sync(ExtSync::Enabled).build(),
+                ConfigError::ExtSyncRequiresExtClk,
+            ),
+        ];
+
+        for (config, expected) in cases {
+            assert_eq!(config.validate(), Err(expected));
+        }
+    }
+
+    /// Exercises the wake-up ODR and filter rules once an activity detector is enabled.
+    #[test]
+    fn wake_up_requires_base_rate_and_no_filters() {
+        let activity = MotionDetector::new(
+            MotionThreshold::new(1_000, AxisMask::ALL, ThresholdMode::Referenced),
+            0,
+        );
+        let wake_up = Config::new()
+            .power_mode(PowerMode::WakeUp)
+            .activity(activity)
+            .odr(OutputDataRate::Od400Hz)
+            .bandwidth(Bandwidth::Bw200Hz);
+
+        assert_eq!(
+            wake_up.odr(OutputDataRate::Od800Hz).build().validate(),
+            Err(ConfigError::WakeUpOdrUnsupported)
+        );
+        assert_eq!(
+            wake_up.build().validate(),
+            Err(ConfigError::WakeUpFiltersEnabled)
+        );
+        assert_eq!(
+            wake_up
+                .lpf_disable(LpfDisable::Disabled)
+                .hpf_disable(HpfDisable::Disabled)
+                .build()
+                .validate(),
+            Ok(())
+        );
+    }
+
+    /// Round-trips a configuration through its register block and reports a changed field.
+    #[test]
+    fn register_block_roundtrip_and_diff() {
+        let config = Config::new()
+            .odr(OutputDataRate::Od3200Hz)
+            .bandwidth(Bandwidth::Bw1600Hz)
+            .activity(MotionDetector::new(
+                MotionThreshold::new(1_250, AxisMask::ALL, ThresholdMode::Referenced),
+                70,
+            ))
+            .linkloop(LinkLoopMode::Loop)
+            .fifo_mode(FifoMode::Stream)
+            .fifo_watermark(300)
+            .power_mode(PowerMode::Measure)
+            .build();
+
+        let block = config.register_block();
+        assert_eq!(
+            PowerControl::from(block[CONFIG_BLOCK_BYTES - 1]).mode(),
+            PowerMode::Measure
+        );
+
+        let decoded = Config::from_register_block(&block);
+        assert!(config.quantized().diff(&decoded).is_match());
+
+        let stale = Config::new().odr(OutputDataRate::Od6400Hz).build();
+        let diff = stale.diff(&Config::default());
+        assert!(diff.contains(ConfigField::Odr));
+        assert_eq!(diff.len(), 1);
+        assert_eq!(diff.fields().next(), Some(ConfigField::Odr));
+    }
+
+    /// Verifies FIFO watermarks must fit nine bits and hold whole sample sets.
+    #[test]
+    fn fifo_watermark_limits() {
+        let stream = Config::new().fifo_mode(FifoMode::Stream);
+
+        assert_eq!(
+            stream.fifo_watermark(0x200).build().validate(),
+            Err(ConfigError::FifoWatermarkOutOfRange)
+        );
+        assert_eq!(
+            stream.fifo_watermark(95).build().validate(),
+            Err(ConfigError::FifoWatermarkMisaligned)
+        );
+        assert_eq!(stream.fifo_watermark(96).build().validate(), Ok(()));
+    }
+
+    /// Ensures enabling any FIFO mode on top of the defaults yields a valid configuration.
+    #[test]
+    fn default_fifo_settings_validate_in_every_mode() {
+        for mode in [
+            FifoMode::Bypass,
+            FifoMode::Stream,
+            FifoMode::Trigger,
+            FifoMode::OldestSaved,
+        ] {
+            assert_eq!(Config::new().fifo_mode(mode).build().validate(), Ok(()));
+        }
+
+        let stream = Config::new().fifo_mode(FifoMode::Stream).build();
+        assert_eq!(stream.fifo_watermark, 0x7E);
+        let pairs = Config::new()
+            .fifo_mode(FifoMode::Stream)
+            .fifo_format(FifoFormat::XY)
+            .build();
+        assert_eq!(pairs.fifo_watermark, 0x7E);
+        assert_eq!(Config::default().fifo_watermark, 0x80);
+    }
 }
@@ -1,35 +1,61 @@
 //! High-level ADXL372 device driver implementation.
 
-use crate::config::Config;
+use crate::activity::{MotionDetector, MotionThreshold};
+use crate::calibration::{OffsetCalibrationReport, OffsetTrim, calibrate_offsets};
+use crate::config::{CONFIG_BLOCK_BYTES, CONFIGURE_SEQUENCE, Config, ConfigDiff};
+use crate::dump::{DUMP_LEN, RegisterDump, dump_segments};
 use crate::error::{Error, Result};
-use crate::fifo::{FifoSettings, Sample};
+use crate::fifo::{self, FifoDecoder, FifoDecoderStats, FifoSettings, Sample};
 use crate::interface::Adxl372Interface;
+use crate::interface::i2c::{I2cAddress, I2cInterface};
 use crate::interface::spi::SpiInterface;
+use crate::interrupt::{InterruptMap, InterruptMaps};
 #[cfg(feature = "defmt")]
 use crate::log::LOG_TAG;
 use crate::params::{
-    AutoSleep, Bandwidth, ExtClk, ExtSync, FifoFormat, FifoMode, HpfDisable, I2cHsmEn,
-    InstantOnThreshold, LinkLoopMode, LowNoise, LpfDisable, OutputDataRate, PowerMode,
+    AutoSleep, Bandwidth, ExtClk, ExtSync, FifoFormat, FifoMode, HighPassCorner, HpfDisable,
+    I2cHsmEn, InstantOnThreshold, LinkLoopMode, LowNoise, LpfDisable, OutputDataRate, PowerMode,
     SettleFilter, UserOrDisable, WakeUpRate,
 };
 use crate::registers::{
-    EXPECTED_DEVID_AD, EXPECTED_DEVID_MST, EXPECTED_PART_ID, Measure, PowerControl, REG_DEVID_AD,
-    REG_MEASURE, REG_POWER_CTL, REG_RESET, REG_STATUS, REG_TIMING, REG_XDATA_H, REG_YDATA_H,
-    REG_ZDATA_H, RESET_COMMAND, Status, Status2, Timing,
+    EXPECTED_DEVID_AD, EXPECTED_DEVID_MST, EXPECTED_PART_ID, FifoControl, Hpf, Measure,
+    PowerControl, REG_DEVID_AD, REG_FIFO_SAMPLES, REG_INT1_MAP, REG_MAXPEAK_X_H, REG_OFFSET_X,
+    REG_RESET, REG_STATUS, REG_THRESH_ACT_X_H, REG_XDATA_H, REG_YDATA_H, REG_ZDATA_H,
+    RESET_COMMAND, ReadableRegister, Status, Status2, Timing, WritableRegister, unpack_axis,
+    verify_mask,
 };
 use crate::self_test::{SelfTestReport, run_self_test};
+use crate::units::Acceleration;
 use embedded_hal::delay::DelayNs;
+use embedded_hal::i2c::I2c;
 use embedded_hal::spi::SpiDevice;
 
 // ADXL372 datasheet power-up to standby delay (milliseconds).
-const POWER_UP_TO_STANDBY_DELAY_MS: u32 = 5;
+pub(crate) const POWER_UP_TO_STANDBY_DELAY_MS: u32 = 5;
 // Number of consecutive bytes spanning X, Y, Z axis samples.
-const RAW_AXIS_BYTES: usize = 6;
+pub(crate) const RAW_AXIS_BYTES: usize = 6;
 
 /// High-level synchronous driver for the ADXL372 accelerometer.
 pub struct Adxl372<IFACE> {
     interface: IFACE,
     config: Config,
+    verify: WriteVerify,
+    fifo: FifoDecoder,
+}
+
+/// Read-back policy applied to every register the driver writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum WriteVerify {
+    /// Writes are trusted without reading them back.
+    #[default]
+    Disabled,
+    /// Each write is read back and compared under the register's
+    /// [`VERIFY_MASK`](crate::registers::WritableRegister::VERIFY_MASK).
+    ReadBack {
+        /// Number of times a mismatching write is re-issued before giving up.
+        retries: u8,
+    },
 }
 
 /// Combined view of the `STATUS` and `STATUS2` registers with explicit flags.
@@ -58,6 +84,11 @@ pub struct StatusSnapshot {
 }
 
 impl StatusSnapshot {
+    /// Builds a snapshot from a `STATUS`, `STATUS2` burst.
+    pub(crate) fn from_bytes(raw: [u8; 2]) -> Self {
+        Self::from_registers(Status::from(raw[0]), Status2::from(raw[1]))
+    }
+
     /// Builds a snapshot from the raw STATUS and STATUS2 bitfields.
     pub fn from_registers(status: Status, status2: Status2) -> Self {
         Self {
@@ -101,7 +132,12 @@ impl<IFACE> Adxl372<IFACE> {
     // ==================================================================
     /// Creates a new driver instance from the provided bus interface.
     pub fn new(interface: IFACE, config: Config) -> Self {
-        Self { interface, config }
+        Self {
+            interface,
+            config,
+            verify: WriteVerify::Disabled,
+            fifo: FifoDecoder::new(config.fifo_format),
+        }
     }
 
     /// Consumes the driver and returns the owned interface.
@@ -113,6 +149,147 @@ impl<IFACE> Adxl372<IFACE> {
     pub fn interface_mut(&mut self) -> &mut IFACE {
         &mut self.interface
     }
+
+    /// Selects whether register writes are read back and checked.
+    ///
+    /// Behind a [`CachedInterface`](crate::interface::cached::CachedInterface) the read-back is
+    /// answered from the shadow copy, so verification only covers the bus below the cache when
+    /// the cache is not in use.
+    pub fn set_write_verify(&mut self, policy: WriteVerify) {
+        self.verify = policy;
+    }
+
+    /// Returns the active write verification policy.
+    pub fn write_verify(&self) -> WriteVerify {
+        self.verify
+    }
+}
+
+// Returns whether any register in the `len` bytes starting at `register` can be read back.
+fn verifiable(register: u8, len: usize) -> bool {
+    (register..)
+        .take(len)
+        .any(|address| verify_mask(address) != 0)
+}
+
+/// Read-back and retry bookkeeping for one verified write.
+///
+/// Shared by the blocking and async drivers, which only perform the bus accesses: read into
+/// [`next_read`](Self::next_read) until it returns `None`, re-sending the whole write whenever
+/// [`check`](Self::check) asks for it. Writes longer than [`READBACK_CHUNK_BYTES`] are read
+/// back in several bursts.
+pub(crate) struct VerifiedWrite<'a> {
+    register: u8,
+    written: &'a [u8],
+    read: [u8; READBACK_CHUNK_BYTES],
+    // Bytes of `written` already read back and matched.
+    offset: usize,
+    retries_left: u8,
+}
+
+// Largest single read-back burst; every write the driver issues fits in one.
+const READBACK_CHUNK_BYTES: usize = CONFIG_BLOCK_BYTES;
+
+impl<'a> VerifiedWrite<'a> {
+    // Starts verifying `written` at `register`, or returns `None` when `policy` does not read
+    // the write back.
+    pub(crate) fn new(policy: WriteVerify, register: u8, written: &'a [u8]) -> Option<Self> {
+        debug_assert!(
+            usize::from(register) + written.len() <= 0x100,
+            "write runs past the register map"
+        );
+        match policy {
+            WriteVerify::ReadBack { retries } if verifiable(register, written.len()) => {
+                Some(Self {
+                    register,
+                    written,
+                    read: [0; READBACK_CHUNK_BYTES],
+                    offset: 0,
+                    retries_left: retries,
+                })
+            }
+            _ => None,
+        }
+    }
+
+    // Returns the start address and buffer of the next read-back, or `None` once verified.
+    pub(crate) fn next_read(&mut self) -> Option<(u8, &mut [u8])> {
+        let len = self.chunk_len();
+        if len == 0 {
+            return None;
+        }
+        Some((self.chunk_register(), &mut self.read[..len]))
+    }
+
+    // Checks the last read-back and returns whether the write must be re-sent.
+    pub(crate) fn check<E>(&mut self) -> Result<bool, E> {
+        let len = self.chunk_len();
+        let written = &self.written[self.offset..self.offset + len];
+        match check_readback(self.chunk_register(), written, &self.read[..len]) {
+            Err(Error::VerifyFailed { .. }) if self.retries_left > 0 => {
+                self.retries_left -= 1;
+                self.offset = 0;
+                Ok(true)
+            }
+            result => {
+                result?;
+                self.offset += len;
+                Ok(false)
+            }
+        }
+    }
+
+    fn chunk_len(&self) -> usize {
+        (self.written.len() - self.offset).min(READBACK_CHUNK_BYTES)
+    }
+
+    fn chunk_register(&self) -> u8 {
+        // Bounded by the register map, see `new`.
+        self.register.wrapping_add(self.offset as u8)
+    }
+}
+
+// Compares a read-back burst with the bytes written, ignoring bits outside each verify mask.
+fn check_readback<E>(register: u8, written: &[u8], read: &[u8]) -> Result<(), E> {
+    for ((address, &expected), &actual) in (register..).zip(written).zip(read) {
+        if (expected ^ actual) & verify_mask(address) != 0 {
+            return Err(Error::VerifyFailed {
+                register: address,
+                written: expected,
+                read: actual,
+            });
+        }
+    }
+    Ok(())
+}
+
+// Decodes an `X/Y/Z` burst (data or peak registers) into signed 12-bit samples.
+pub(crate) fn decode_xyz(raw: &[u8; RAW_AXIS_BYTES]) -> [i16; 3] {
+    [
+        unpack_axis(raw[0], raw[1]),
+        unpack_axis(raw[2], raw[3]),
+        unpack_axis(raw[4], raw[5]),
+    ]
+}
+
+// Checks the `DEVID_AD..=REVID` burst and returns the silicon revision.
+pub(crate) fn check_id_bytes<E>(ids: &[u8; 4]) -> Result<u8, E> {
+    if ids[0] != EXPECTED_DEVID_AD || ids[1] != EXPECTED_DEVID_MST || ids[2] != EXPECTED_PART_ID {
+        return Err(Error::DeviceIdMismatch);
+    }
+    Ok(ids[3])
+}
+
+// Returns whether switching from `previous` to `next` requires waiting for the filters.
+fn needs_filter_settle(previous: PowerMode, next: PowerMode) -> bool {
+    !matches!(previous, PowerMode::Standby) && matches!(next, PowerMode::Measure)
+}
+
+// Validates `config` for a full reconfiguration from `current` and returns whether the
+// filters must settle once it is applied.
+pub(crate) fn begin_configure<E>(current: &Config, config: &Config) -> Result<bool, E> {
+    config.validate().map_err(Error::InvalidConfig)?;
+    Ok(needs_filter_settle(current.power_mode, config.power_mode))
 }
 
 impl<SPI> Adxl372<SpiInterface<SPI>>
@@ -134,6 +311,25 @@ where
     }
 }
 
+impl<I2C> Adxl372<I2cInterface<I2C>>
+where
+    I2C: I2c,
+{
+    // ==================================================================
+    // == I2C Convenience Constructors ==================================
+    // ==================================================================
+    /// Convenience constructor for I2C transports.
+    pub fn new_i2c(i2c: I2C, address: I2cAddress, config: Config) -> Self {
+        Self::new(I2cInterface::new(i2c, address), config)
+    }
+
+    /// Releases the driver, returning the I2C bus and configuration.
+    pub fn release_i2c(self) -> (I2C, Config) {
+        let (iface, config) = self.release();
+        (iface.release(), config)
+    }
+}
+
 impl<IFACE, CommE> Adxl372<IFACE>
 where
     IFACE: Adxl372Interface<Error = CommE>,
@@ -150,7 +346,7 @@ where
     pub fn init(&mut self, delay: &mut impl DelayNs) -> Result<(), CommE> {
         delay.delay_ms(POWER_UP_TO_STANDBY_DELAY_MS);
 
-        self.config.validate().map_err(|_| Error::InvalidConfig)?;
+        self.config.validate().map_err(Error::InvalidConfig)?;
 
         if !self.run_self_test(delay)?.passed {
             return Err(Error::SelfTestFailed);
@@ -166,31 +362,72 @@ where
     ///
     /// Automatically waits for the filter settle period when entering measurement mode.
     ///
-    /// Planned helper pipeline for the forthcoming register programming:
-    /// 1. `apply_timing_config()` – programs `TIMING` (ODR, wake-up rate, ext sync/clk)
-    /// 2. `apply_measurement_config()` – programs `MEASURE` (bandwidth, noise, link/loop)
-    /// 3. `apply_power_control_config()` – programs `POWER_CTL` fields unrelated to mode
-    /// 4. `apply_fifo_config()` – programs `FIFO_CTL` and watermark registers
-    /// 5. `apply_activity_config()` – programs activity/inactivity threshold windows
-    /// 6. `apply_interrupt_config()` – programs interrupt/fault signaling behaviour
+    /// Register programming pipeline:
+    /// 1. `TIMING` (ODR, wake-up rate, ext sync/clk), read-modify-write
+    /// 2. `MEASURE` (bandwidth, noise, link/loop), read-modify-write
+    /// 3. `HPF` corner, read-modify-write
+    /// 4. `OFFSET_X/Y/Z` user trims
+    /// 5. activity, inactivity and Activity2 thresholds and timers
+    /// 6. `FIFO_SAMPLES` and `FIFO_CTL`
+    /// 7. `INT1_MAP` and `INT2_MAP`
+    /// 8. `POWER_CTL`, including the power mode, read-modify-write
+    ///
+    /// `POWER_CTL` is written last so the FIFO, detectors and interrupt pins are reprogrammed
+    /// before measurement resumes.
     ///
-    /// Each helper will be wired up once its corresponding register logic is implemented.
+    /// [`configure_burst`](Self::configure_burst) programs the same registers in a single
+    /// write transaction.
     pub fn configure(&mut self, config: Config, delay: &mut impl DelayNs) -> Result<(), CommE> {
-        config.validate().map_err(|_| Error::InvalidConfig)?;
+        let settle = begin_configure(&self.config, &config)?;
+
+        for step in CONFIGURE_SEQUENCE {
+            let register = step.register();
+            if let Some(block) = step.block(&config) {
+                self.write_block(register, block.as_slice())?;
+            } else {
+                let current = self
+                    .interface
+                    .read_register(register)
+                    .map_err(Error::from)?;
+                if let Some(updated) = step.update(&config, current) {
+                    self.write_block(register, &[updated])?;
+                }
+            }
+        }
 
-        let previous_mode = self.config.power_mode;
-        let next_mode = config.power_mode;
+        self.finish_configure(config, settle, delay);
+        Ok(())
+    }
+
+    /// Applies a new configuration with a single burst write.
+    ///
+    /// Unlike [`configure`](Self::configure), no register is read first: the whole
+    /// `OFFSET_X..=POWER_CTL` block is assembled from `config` and written in one transaction.
+    /// `POWER_CTL` is the last address in the block, so the power mode changes only after the
+    /// other control registers hold their new values. Reserved bits are written as zero.
+    ///
+    /// Automatically waits for the filter settle period when entering measurement mode.
+    pub fn configure_burst(
+        &mut self,
+        config: Config,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), CommE> {
+        let settle = begin_configure(&self.config, &config)?;
 
-        self.apply_timing_config(&config)?;
-        self.apply_measurement_config(&config)?;
-        self.apply_power_control_config(&config)?;
+        self.write_block(REG_OFFSET_X, &config.register_block())?;
 
+        self.finish_configure(config, settle, delay);
+        Ok(())
+    }
+
+    // Records an applied configuration, waiting for the filters when `settle` is set.
+    fn finish_configure(&mut self, config: Config, settle: bool, delay: &mut impl DelayNs) {
         self.config = config;
+        self.fifo = FifoDecoder::new(config.fifo_format);
 
-        if !matches!(previous_mode, PowerMode::Standby) && matches!(next_mode, PowerMode::Measure) {
+        if settle {
             self.wait_filter_settle(delay);
         }
-        Ok(())
     }
 
     /// Waits for the configured filter settle time.
@@ -213,7 +450,34 @@ where
     pub fn reset(&mut self) -> Result<(), CommE> {
         self.interface
             .write_register(REG_RESET, RESET_COMMAND)
-            .map_err(Error::from)
+            .map_err(Error::from)?;
+        self.fifo.reset();
+        Ok(())
+    }
+
+    /// Reads the live register contents back into a [`Config`].
+    ///
+    /// The whole `OFFSET_X..=POWER_CTL` block is fetched in one burst, so the result is a
+    /// consistent snapshot. Thresholds and durations come back at register resolution.
+    ///
+    /// Behind a [`CachedInterface`](crate::interface::cached::CachedInterface) the block is
+    /// answered from the shadow copy; invalidate it first to read the device itself.
+    pub fn read_config(&mut self) -> Result<Config, CommE> {
+        let mut block = [0u8; CONFIG_BLOCK_BYTES];
+        self.interface
+            .read_many(REG_OFFSET_X, &mut block)
+            .map_err(Error::from)?;
+
+        Ok(Config::from_register_block(&block))
+    }
+
+    /// Compares the live register contents against the cached configuration.
+    ///
+    /// The cached side is quantised to register resolution first, so only genuine
+    /// divergence (brown-out, reset, another bus master) is reported.
+    pub fn verify_config(&mut self) -> Result<ConfigDiff, CommE> {
+        let actual = self.read_config()?;
+        Ok(self.config.quantized().diff(&actual))
     }
 
     // ==================================================================
@@ -231,12 +495,7 @@ where
             .read_many(REG_DEVID_AD, &mut ids)
             .map_err(Error::from)?;
 
-        if ids[0] != EXPECTED_DEVID_AD || ids[1] != EXPECTED_DEVID_MST || ids[2] != EXPECTED_PART_ID
-        {
-            return Err(Error::DeviceIdMismatch);
-        }
-
-        Ok(ids[3])
+        check_id_bytes(&ids)
     }
 
     /// Returns a snapshot of the `STATUS` and `STATUS2` registers.
@@ -246,15 +505,81 @@ where
             .read_many(REG_STATUS, &mut raw)
             .map_err(Error::from)?;
 
-        let status = Status::from(raw[0]);
-        let status2 = Status2::from(raw[1]);
-
-        Ok(StatusSnapshot::from_registers(status, status2))
+        Ok(StatusSnapshot::from_bytes(raw))
     }
 
     /// Snapshot of FIFO configuration registers.
     pub fn fifo_settings(&mut self) -> Result<FifoSettings, CommE> {
-        Err(Error::NotReady)
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(REG_FIFO_SAMPLES, &mut raw)
+            .map_err(Error::from)?;
+
+        Ok(FifoSettings::from_registers(
+            raw[0],
+            FifoControl::from(raw[1]),
+        ))
+    }
+
+    /// Captures every readable register except `FIFO_DATA` for diagnostics.
+    ///
+    /// Reading clears the `MAXPEAK` latches and the `STATUS2` event flags; see
+    /// [`RegisterDump`] for the stable encoding.
+    pub fn dump_registers(&mut self) -> Result<RegisterDump, CommE> {
+        let mut values = [0u8; DUMP_LEN];
+        for (start, segment) in dump_segments(&mut values) {
+            self.interface
+                .read_many(start, segment)
+                .map_err(Error::from)?;
+        }
+
+        Ok(RegisterDump::from_values(values))
+    }
+
+    // ==================================================================
+    // == Typed Register Access =========================================
+    // ==================================================================
+    /// Reads register `R` and decodes it into its bitfield type.
+    pub fn read_reg<R: ReadableRegister>(&mut self) -> Result<R, CommE> {
+        let raw = self
+            .interface
+            .read_register(R::ADDRESS)
+            .map_err(Error::from)?;
+
+        Ok(R::from(raw))
+    }
+
+    /// Writes `value` to register `R`.
+    ///
+    /// Typed writes bypass the cached [`Config`]; prefer the `configure_*` methods for fields
+    /// it tracks.
+    ///
+    /// Read-only registers are rejected at compile time:
+    ///
+    /// ```rust,compile_fail
+    /// # use adxl372::device::Adxl372;
+    /// # use adxl372::interface::Adxl372Interface;
+    /// # use adxl372::registers::Status;
+    /// # fn example<I: Adxl372Interface>(device: &mut Adxl372<I>, status: Status) {
+    /// device.write_reg(status);
+    /// # }
+    /// ```
+    pub fn write_reg<R: WritableRegister>(&mut self, value: R) -> Result<(), CommE> {
+        self.write_block(R::ADDRESS, &[value.into()])
+    }
+
+    /// Reads register `R`, applies `mutate` and writes it back if anything changed.
+    ///
+    /// Returns the register value now held by the device.
+    pub fn modify_reg<R, F>(&mut self, mutate: F) -> Result<R, CommE>
+    where
+        R: ReadableRegister + WritableRegister,
+        F: FnOnce(&mut R),
+    {
+        self.try_modify_reg(|reg| {
+            mutate(reg);
+            Ok(())
+        })
     }
 
     // ==================================================================
@@ -360,15 +685,21 @@ where
         })
     }
 
+    /// Selects the high-pass filter corner.
+    ///
+    /// Rejected with [`Error::InvalidConfig`] when the corner would break an active motion
+    /// detector; see [`Config::validate`].
+    pub fn configure_hpf_corner(&mut self, corner: HighPassCorner) -> Result<(), CommE> {
+        let mut config = self.config;
+        config.hpf_corner = corner;
+        config.validate().map_err(Error::InvalidConfig)?;
+
+        self.apply_hpf_config(&config)
+    }
+
     // ==================================================================
     // == Data Acquisition ==============================================
     // ==================================================================
-    #[inline]
-    fn unpack_axis(msb: u8, lsb: u8) -> i16 {
-        // Sensor outputs 12-bit left-justified two's complement data.
-        i16::from_be_bytes([msb, lsb]) >> 4
-    }
-
     /// Reads a raw acceleration triplet.
     pub fn read_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
         let mut raw = [0u8; RAW_AXIS_BYTES];
@@ -376,11 +707,7 @@ where
             .read_many(REG_XDATA_H, &mut raw)
             .map_err(Error::from)?;
 
-        let x = Self::unpack_axis(raw[0], raw[1]);
-        let y = Self::unpack_axis(raw[2], raw[3]);
-        let z = Self::unpack_axis(raw[4], raw[5]);
-
-        Ok([x, y, z])
+        Ok(decode_xyz(&raw))
     }
 
     /// Reads the raw X-axis acceleration sample.
@@ -390,7 +717,7 @@ where
             .read_many(REG_XDATA_H, &mut raw)
             .map_err(Error::from)?;
 
-        Ok(Self::unpack_axis(raw[0], raw[1]))
+        Ok(unpack_axis(raw[0], raw[1]))
     }
 
     /// Reads the raw Y-axis acceleration sample.
@@ -400,7 +727,7 @@ where
             .read_many(REG_YDATA_H, &mut raw)
             .map_err(Error::from)?;
 
-        Ok(Self::unpack_axis(raw[0], raw[1]))
+        Ok(unpack_axis(raw[0], raw[1]))
     }
 
     /// Reads the raw Z-axis acceleration sample.
@@ -410,50 +737,218 @@ where
             .read_many(REG_ZDATA_H, &mut raw)
             .map_err(Error::from)?;
 
-        Ok(Self::unpack_axis(raw[0], raw[1]))
+        Ok(unpack_axis(raw[0], raw[1]))
     }
 
     /// Returns acceleration scaled in milli-g.
-    pub fn read_xyz_mg(&mut self) -> Result<[i32; 3], CommE> {
-        Err(Error::NotReady)
+    pub fn read_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.read_xyz_raw().map(Acceleration::from_raw)
+    }
+
+    // ==================================================================
+    // == Peak Acceleration =============================================
+    // ==================================================================
+    /// Reads the per-axis peak acceleration latched in `MAXPEAK_X/Y/Z`.
+    ///
+    /// The sensor tracks the largest sample seen on each axis since the registers were last
+    /// read; reading them clears the latch, so every call re-arms peak tracking. In instant-on
+    /// mode the latch only updates while the device is awake after an over-threshold event.
+    /// [`FifoFormat::Peak`] stores a per-event peak in the FIFO instead, which is the better
+    /// fit when several events must be kept apart.
+    pub fn read_peak_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
+        let mut raw = [0u8; RAW_AXIS_BYTES];
+        self.interface
+            .read_many(REG_MAXPEAK_X_H, &mut raw)
+            .map_err(Error::from)?;
+
+        Ok(decode_xyz(&raw))
+    }
+
+    /// Reads the latched peak acceleration scaled in milli-g, clearing the latch.
+    pub fn read_peak_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.read_peak_xyz_raw().map(Acceleration::from_raw)
+    }
+
+    /// Discards the latched peak so tracking restarts from the next sample.
+    pub fn clear_peak(&mut self) -> Result<(), CommE> {
+        self.read_peak_xyz_raw().map(|_| ())
+    }
+
+    // ==================================================================
+    // == Offset Trim & Calibration =====================================
+    // ==================================================================
+    /// Programs the per-axis user offset trim.
+    pub fn configure_offsets(&mut self, offsets: OffsetTrim) -> Result<(), CommE> {
+        let mut candidate = self.config;
+        candidate.offsets = offsets;
+        candidate.validate().map_err(Error::InvalidConfig)?;
+
+        self.write_offsets(offsets)
+    }
+
+    /// Reads the per-axis user offset trim back from the device.
+    pub fn read_offsets(&mut self) -> Result<OffsetTrim, CommE> {
+        let mut raw = [0u8; 3];
+        self.interface
+            .read_many(REG_OFFSET_X, &mut raw)
+            .map_err(Error::from)?;
+
+        Ok(OffsetTrim::from_registers(raw))
+    }
+
+    /// Averages `samples` stationary readings and programs the trim matching `expected`.
+    ///
+    /// See [`calibrate_offsets`] for the measurement requirements.
+    pub fn calibrate_offsets(
+        &mut self,
+        delay: &mut impl DelayNs,
+        samples: u16,
+        expected: Acceleration,
+    ) -> Result<OffsetCalibrationReport, CommE> {
+        calibrate_offsets(self, delay, samples, expected)
     }
 
     // ==================================================================
     // == FIFO Configuration & Streaming ================================
     // ==================================================================
     /// Updates FIFO format, mode, or watermark.
+    ///
+    /// The watermark is expressed in FIFO entries, so a watermark of 96 with
+    /// [`FifoFormat::XYZ`] triggers `FIFO_RDY` after 32 complete samples.
     pub fn configure_fifo(
         &mut self,
         format: Option<FifoFormat>,
         mode: Option<FifoMode>,
         watermark: Option<u16>,
     ) -> Result<(), CommE> {
-        let _ = format;
-        let _ = mode;
-        let _ = watermark;
-        Err(Error::NotReady)
+        let mut settings = self.fifo_settings()?;
+
+        if let Some(format) = format {
+            settings.format = format;
+        }
+
+        if let Some(mode) = mode {
+            settings.mode = mode;
+        }
+
+        if let Some(watermark) = watermark {
+            settings.watermark = watermark;
+        }
+
+        let mut candidate = self.config;
+        candidate.update_from_fifo(settings);
+        candidate.validate().map_err(Error::InvalidConfig)?;
+
+        self.write_fifo_settings(settings)
     }
 
-    /// Returns the number of FIFO samples currently buffered.
+    /// Returns the number of FIFO entries currently buffered.
     pub fn read_fifo_level(&mut self) -> Result<u16, CommE> {
-        Err(Error::NotReady)
+        fifo::read_fifo_level(&mut self.interface)
     }
 
     /// Reads raw FIFO bytes into the provided buffer.
+    ///
+    /// Only whole two-byte entries are transferred; the returned count is the number of bytes
+    /// written into `buf`.
     pub fn read_fifo_raw(&mut self, buf: &mut [u8]) -> Result<usize, CommE> {
-        let _ = buf;
-        Err(Error::NotReady)
+        fifo::read_fifo_raw(&mut self.interface, buf)
     }
 
     /// Decodes FIFO samples into the caller-provided slice.
+    ///
+    /// Samples are decoded with the configured [`FifoFormat`], so axes that are not stored by
+    /// the format are reported as `None`. The driver keeps its decoder between calls, so a set
+    /// split by FIFO misalignment completes on the next call instead of being dropped. The
+    /// decoder restarts on [`configure`](Self::configure), [`reset`](Self::reset),
+    /// [`flush_fifo`](Self::flush_fifo) and FIFO reconfiguration.
     pub fn read_fifo_samples(&mut self, samples: &mut [Sample]) -> Result<usize, CommE> {
-        let _ = samples;
-        Err(Error::NotReady)
+        fifo::read_fifo_samples_with(&mut self.interface, &mut self.fifo, samples)
+    }
+
+    /// Returns the discard counters of the decoder used by
+    /// [`read_fifo_samples`](Self::read_fifo_samples).
+    pub fn fifo_stats(&self) -> FifoDecoderStats {
+        self.fifo.stats()
+    }
+
+    /// Creates a streaming [`FifoDecoder`] for the configured FIFO format.
+    ///
+    /// Use it with [`read_fifo_raw`](Self::read_fifo_raw) when reading the FIFO in chunks that
+    /// do not line up with sample boundaries, e.g. DMA-sized transfers.
+    pub fn fifo_decoder(&self) -> FifoDecoder {
+        FifoDecoder::new(self.config.fifo_format)
     }
 
-    /// Drains the FIFO without returning its contents.
+    /// Discards the FIFO contents by briefly switching it into bypass mode.
     pub fn flush_fifo(&mut self) -> Result<(), CommE> {
-        Err(Error::NotReady)
+        let current: FifoControl = self.read_reg()?;
+        self.write_reg(current.with_mode(FifoMode::Bypass))?;
+        self.write_reg(current)?;
+        self.fifo.reset();
+        Ok(())
+    }
+
+    // ==================================================================
+    // == Activity & Inactivity Detection ===============================
+    // ==================================================================
+    /// Programs the activity detector.
+    ///
+    /// The duration is converted to `TIME_ACT` counts using the active ODR, so reprogram the
+    /// detector after changing the output data rate.
+    pub fn configure_activity(&mut self, detector: MotionDetector) -> Result<(), CommE> {
+        let mut config = self.config;
+        config.activity = detector;
+        self.update_activity_config(&config)
+    }
+
+    /// Programs the inactivity detector.
+    ///
+    /// The duration is converted to `TIME_INACT` counts using the active ODR, so reprogram the
+    /// detector after changing the output data rate.
+    pub fn configure_inactivity(&mut self, detector: MotionDetector) -> Result<(), CommE> {
+        let mut config = self.config;
+        config.inactivity = detector;
+        self.update_activity_config(&config)
+    }
+
+    /// Programs the Activity2 (motion warning) detector.
+    pub fn configure_activity2(&mut self, threshold: MotionThreshold) -> Result<(), CommE> {
+        let mut config = self.config;
+        config.activity2 = threshold;
+        self.update_activity_config(&config)
+    }
+
+    // ==================================================================
+    // == Interrupt Pin Mapping =========================================
+    // ==================================================================
+    /// Updates the INT1 and/or INT2 pin mapping.
+    pub fn configure_interrupts(
+        &mut self,
+        int1: Option<InterruptMap>,
+        int2: Option<InterruptMap>,
+    ) -> Result<(), CommE> {
+        let mut maps = self.config.interrupt_maps();
+
+        if let Some(map) = int1 {
+            maps.int1 = map;
+        }
+
+        if let Some(map) = int2 {
+            maps.int2 = map;
+        }
+
+        self.write_interrupt_maps(maps)
+    }
+
+    /// Reads the INT1 and INT2 pin mapping back from the device.
+    pub fn read_interrupt_maps(&mut self) -> Result<InterruptMaps, CommE> {
+        let mut raw = [0u8; 2];
+        self.interface
+            .read_many(REG_INT1_MAP, &mut raw)
+            .map_err(Error::from)?;
+
+        Ok(InterruptMaps::from_registers(raw))
     }
 
     // ==================================================================
@@ -488,182 +983,202 @@ where
     // == Internal Configuration Helpers =================================
     // ==================================================================
 
-    #[allow(dead_code)]
-    fn apply_timing_config(&mut self, config: &Config) -> Result<(), CommE> {
-        self.update_timing_config(|timing| {
-            timing.set_odr(config.odr);
-            timing.set_wake_up_rate(config.wakeup_rate);
-            timing.set_ext_clk(config.ext_clk);
-            timing.set_ext_sync(config.ext_sync);
-        })
+    fn update_timing_config<F>(&mut self, mutate: F) -> Result<(), CommE>
+    where
+        F: FnOnce(&mut Timing),
+    {
+        // Validate the merged configuration so cross-field rules see the new timing fields.
+        let mut candidate = self.config;
+        self.try_modify_reg(|timing: &mut Timing| {
+            mutate(timing);
+            candidate.update_from_timing(*timing);
+            candidate.validate().map_err(Error::InvalidConfig)
+        })?;
+
+        self.config = candidate;
+        Ok(())
     }
 
-    fn update_timing_config<F>(&mut self, mut mutate: F) -> Result<(), CommE>
+    fn update_measure_config<F>(&mut self, mutate: F) -> Result<(), CommE>
     where
-        F: FnMut(&mut Timing),
+        F: FnOnce(&mut Measure),
     {
-        let current = self
-            .interface
-            .read_register(REG_TIMING)
-            .map_err(Error::from)?;
-
-        let mut timing = Timing::from(current);
-        mutate(&mut timing);
-
-        let new_odr = timing.odr();
-        if self.config.bandwidth.max_hz() * 2 > new_odr.hz() {
-            return Err(Error::InvalidConfig);
-        }
-
-        let updated = u8::from(timing);
-        if updated != current {
-            self.interface
-                .write_register(REG_TIMING, updated)
-                .map_err(Error::from)?;
-        }
-
-        self.config.odr = new_odr;
-        self.config.wakeup_rate = timing.wake_up_rate();
-        self.config.ext_clk = timing.ext_clk();
-        self.config.ext_sync = timing.ext_sync();
+        let mut candidate = self.config;
+        self.try_modify_reg(|measure: &mut Measure| {
+            mutate(measure);
+            candidate.update_from_measure(*measure);
+            candidate.validate().map_err(Error::InvalidConfig)
+        })?;
+
+        self.config = candidate;
+        Ok(())
+    }
 
+    fn force_power_mode(&mut self, mode: PowerMode) -> Result<(), CommE> {
+        self.modify_reg(|power: &mut PowerControl| power.set_mode(mode))?;
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn apply_measurement_config(&mut self, config: &Config) -> Result<(), CommE> {
-        self.update_measure_config(|measure| {
-            measure.set_bandwidth(config.bandwidth);
-            measure.set_low_noise(config.low_noise);
-            measure.set_link_loop_mode(config.linkloop);
-            measure.set_autosleep(matches!(config.autosleep, AutoSleep::Enabled));
-            measure.set_user_or_disable(matches!(config.user_or_disable, UserOrDisable::Disabled));
-        })
+    fn update_power_control<F>(&mut self, mutate: F) -> Result<(), CommE>
+    where
+        F: FnOnce(&mut PowerControl),
+    {
+        let mut candidate = self.config;
+        self.try_modify_reg(|power: &mut PowerControl| {
+            mutate(power);
+            candidate.update_from_power_control(*power);
+            candidate.validate().map_err(Error::InvalidConfig)
+        })?;
+
+        self.config = candidate;
+        Ok(())
     }
 
-    fn update_measure_config<F>(&mut self, mut mutate: F) -> Result<(), CommE>
+    /// Reads `R`, lets `mutate` update it and writes it back if the raw value changed.
+    fn try_modify_reg<R, F>(&mut self, mutate: F) -> Result<R, CommE>
     where
-        F: FnMut(&mut Measure),
+        R: ReadableRegister + WritableRegister,
+        F: FnOnce(&mut R) -> Result<(), CommE>,
     {
         let current = self
             .interface
-            .read_register(REG_MEASURE)
+            .read_register(R::ADDRESS)
             .map_err(Error::from)?;
 
-        let mut measure = Measure::from(current);
-        mutate(&mut measure);
+        let mut reg = R::from(current);
+        mutate(&mut reg)?;
 
-        let new_bandwidth = measure.bandwidth();
-        if new_bandwidth.max_hz() * 2 > self.config.odr.hz() {
-            return Err(Error::InvalidConfig);
+        let updated: u8 = reg.into();
+        if updated != current {
+            self.write_block(R::ADDRESS, &[updated])?;
         }
 
-        let updated = u8::from(measure);
-        if updated != current {
+        Ok(reg)
+    }
+
+    // Writes `data` from `register` onwards, then applies the write verification policy.
+    fn write_block(&mut self, register: u8, data: &[u8]) -> Result<(), CommE> {
+        self.send(register, data)?;
+
+        let Some(mut verify) = VerifiedWrite::new(self.verify, register, data) else {
+            return Ok(());
+        };
+        while let Some((address, read)) = verify.next_read() {
             self.interface
-                .write_register(REG_MEASURE, updated)
+                .read_many(address, read)
                 .map_err(Error::from)?;
+            if verify.check()? {
+                self.send(register, data)?;
+            }
         }
+        Ok(())
+    }
 
-        self.config.bandwidth = new_bandwidth;
-        self.config.low_noise = measure.low_noise();
-        self.config.linkloop = measure.link_loop_mode();
-        self.config.autosleep = if measure.autosleep() {
-            AutoSleep::Enabled
-        } else {
-            AutoSleep::Disabled
-        };
-        self.config.user_or_disable = if measure.user_or_disable() {
-            UserOrDisable::Disabled
-        } else {
-            UserOrDisable::Enabled
+    // Single registers go through `write_register`, longer runs through `write_many`.
+    fn send(&mut self, register: u8, data: &[u8]) -> Result<(), CommE> {
+        let result = match data {
+            [value] => self.interface.write_register(register, *value),
+            _ => self.interface.write_many(register, data),
         };
+        result.map_err(Error::from)
+    }
 
+    fn write_fifo_settings(&mut self, settings: FifoSettings) -> Result<(), CommE> {
+        // FIFO_SAMPLES and FIFO_CTL are adjacent, so both land in a single burst.
+        let (samples, control) = settings.to_registers();
+        self.write_block(REG_FIFO_SAMPLES, &[samples, u8::from(control)])?;
+
+        self.config.update_from_fifo(settings);
+        self.fifo = FifoDecoder::new(settings.format);
         Ok(())
     }
 
-    #[allow(dead_code)]
-    fn apply_power_control_config(&mut self, config: &Config) -> Result<(), CommE> {
-        self.update_power_control(|power| {
-            power.set_mode(config.power_mode);
-            power.set_hpf_disable(matches!(config.hpf_disable, HpfDisable::Disabled));
-            power.set_lpf_disable(matches!(config.lpf_disable, LpfDisable::Disabled));
-            power.set_instant_on_threshold(config.instant_on_threshold);
-            power.set_filter_settle(config.filter_settle);
-            power.set_i2c_high_speed_enable(matches!(config.i2c_hsm_en, I2cHsmEn::Enabled));
-        })
+    fn apply_hpf_config(&mut self, config: &Config) -> Result<(), CommE> {
+        let hpf = self.modify_reg(|hpf: &mut Hpf| config.apply_to_hpf(hpf))?;
+        self.config.update_from_hpf(hpf);
+        Ok(())
     }
 
-    fn force_power_mode(&mut self, mode: PowerMode) -> Result<(), CommE> {
-        self.mutate_power_control(|power| power.set_mode(mode))?;
+    fn write_offsets(&mut self, offsets: OffsetTrim) -> Result<(), CommE> {
+        self.write_block(REG_OFFSET_X, &offsets.to_registers())?;
+
+        self.config.offsets = offsets;
         Ok(())
     }
 
-    fn mutate_power_control<F>(&mut self, mut mutate: F) -> Result<PowerControl, CommE>
-    where
-        F: FnMut(&mut PowerControl),
-    {
-        let current = self
-            .interface
-            .read_register(REG_POWER_CTL)
-            .map_err(Error::from)?;
-
-        let mut power = PowerControl::from(current);
-        mutate(&mut power);
+    fn update_activity_config(&mut self, config: &Config) -> Result<(), CommE> {
+        config.validate().map_err(Error::InvalidConfig)?;
+        self.apply_activity_config(config)
+    }
 
-        let updated = u8::from(power);
-        if updated != current {
-            self.interface
-                .write_register(REG_POWER_CTL, updated)
-                .map_err(Error::from)?;
-        }
+    fn apply_activity_config(&mut self, config: &Config) -> Result<(), CommE> {
+        // THRESH_ACT_X_H through THRESH_ACT2_Z_L are contiguous, timers included.
+        self.write_block(REG_THRESH_ACT_X_H, &config.activity_block())?;
 
-        Ok(power)
+        self.config.activity = config.activity;
+        self.config.inactivity = config.inactivity;
+        self.config.activity2 = config.activity2;
+        Ok(())
     }
 
-    fn update_power_control<F>(&mut self, mut mutate: F) -> Result<(), CommE>
-    where
-        F: FnMut(&mut PowerControl),
-    {
-        let power = self.mutate_power_control(|ctrl| mutate(ctrl))?;
-
-        self.config.power_mode = power.mode();
-        self.config.hpf_disable = if power.hpf_disable() {
-            HpfDisable::Disabled
-        } else {
-            HpfDisable::Enabled
-        };
-        self.config.lpf_disable = if power.lpf_disable() {
-            LpfDisable::Disabled
-        } else {
-            LpfDisable::Enabled
-        };
-        self.config.filter_settle = power.filter_settle();
-        self.config.instant_on_threshold = power.instant_on_threshold();
-        self.config.i2c_hsm_en = if power.i2c_high_speed_enable() {
-            I2cHsmEn::Enabled
-        } else {
-            I2cHsmEn::Disabled
-        };
+    fn write_interrupt_maps(&mut self, maps: InterruptMaps) -> Result<(), CommE> {
+        // INT1_MAP and INT2_MAP are adjacent, so both pins are updated in one burst.
+        self.write_block(REG_INT1_MAP, &maps.to_registers())?;
 
+        self.config.update_from_interrupts(maps);
         Ok(())
     }
+}
 
-    #[allow(dead_code)]
-    fn apply_fifo_config(&mut self, config: &Config) -> Result<(), CommE> {
-        let _ = config;
-        Err(Error::NotReady)
-    }
+#[cfg(test)]
+mod tests {
+    use super::{READBACK_CHUNK_BYTES, VerifiedWrite, WriteVerify};
+    use crate::error::Error;
+    use crate::registers::REG_OFFSET_X;
+
+    /// Checks that long writes are read back in bounded bursts and restart after a re-send.
+    #[test]
+    fn verified_write_reads_back_in_chunks() {
+        let written = [0x05u8; READBACK_CHUNK_BYTES + 8];
+        let policy = WriteVerify::ReadBack { retries: 1 };
+        let mut verify = VerifiedWrite::new(policy, REG_OFFSET_X, &written).unwrap();
+
+        let mut reads = [(0u8, 0usize); 3];
+        let mut count = 0;
+        while let Some((address, read)) = verify.next_read() {
+            reads[count] = (address, read.len());
+            read.copy_from_slice(&written[..read.len()]);
+            // Corrupt the first burst once to force a re-send.
+            if count == 0 {
+                read[0] ^= 0x01;
+            }
+            count += 1;
+            let resend = verify.check::<()>().unwrap();
+            assert_eq!(resend, count == 1);
+        }
 
-    #[allow(dead_code)]
-    fn apply_activity_config(&mut self, config: &Config) -> Result<(), CommE> {
-        let _ = config;
-        Err(Error::NotReady)
-    }
+        let tail = REG_OFFSET_X + READBACK_CHUNK_BYTES as u8;
+        assert_eq!(
+            reads,
+            [
+                (REG_OFFSET_X, READBACK_CHUNK_BYTES),
+                (REG_OFFSET_X, READBACK_CHUNK_BYTES),
+                (tail, 8),
+            ]
+        );
 
-    #[allow(dead_code)]
-    fn apply_interrupt_config(&mut self, config: &Config) -> Result<(), CommE> {
-        let _ = config;
-        Err(Error::NotReady)
+        let mut verify =
+            VerifiedWrite::new(WriteVerify::ReadBack { retries: 0 }, REG_OFFSET_X, &written)
+                .unwrap();
+        let (_, read) = verify.next_read().unwrap();
+        read.fill(0);
+        assert_eq!(
+            verify.check::<()>(),
+            Err(Error::VerifyFailed {
+                register: REG_OFFSET_X,
+                written: 0x05,
+                read: 0x00,
+            })
+        );
     }
 }
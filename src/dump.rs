@@ -0,0 +1,321 @@
+//! Register dump diagnostics.
+//!
+//! [`RegisterDump`] captures every readable register apart from `FIFO_DATA` in three bursts,
+//! so the full sensor state can be logged, compared against the datasheet reset values or
+//! shipped in a crash report. The byte encoding returned by [`RegisterDump::to_bytes`] is
+//! stable across releases with the same [`RegisterDump::FORMAT_VERSION`].
+//!
+//! Reading the dump has the same side effects as any other read: the `MAXPEAK` latches and
+//! the `STATUS2` event flags are cleared.
+
+use crate::registers::{
+    DevIdAd, DevIdMst, FifoControl, FifoEntries, FifoEntriesUpper, FifoSamples, Hpf, Int1Map,
+    Int2Map, MaxPeakXH, MaxPeakXL, MaxPeakYH, MaxPeakYL, MaxPeakZH, MaxPeakZL, Measure, OffsetX,
+    OffsetY, OffsetZ, PartId, PowerControl, REG_DEVID_AD, REG_MAXPEAK_X_H, REG_MAXPEAK_Z_L,
+    REG_OFFSET_X, REG_SELF_TEST, REG_ZDATA_L, Register, RevId, SelfTest, Status, Status2,
+    ThreshAct2XH, ThreshAct2XL, ThreshAct2YH, ThreshAct2YL, ThreshAct2ZH, ThreshAct2ZL,
+    ThreshActXH, ThreshActXL, ThreshActYH, ThreshActYL, ThreshActZH, ThreshActZL, ThreshInactXH,
+    ThreshInactXL, ThreshInactYH, ThreshInactYL, ThreshInactZH, ThreshInactZL, TimeAct, TimeInactH,
+    TimeInactL, Timing, XDataH, XDataL, YDataH, YDataL, ZDataH, ZDataL,
+};
+
+/// Number of registers captured by a [`RegisterDump`].
+pub const DUMP_LEN: usize = 53;
+
+/// Contiguous `(first register, length)` bursts covering the dump, in address order.
+pub(crate) const DUMP_BLOCKS: [(u8, usize); 3] = [
+    (REG_DEVID_AD, (REG_ZDATA_L - REG_DEVID_AD) as usize + 1),
+    (
+        REG_MAXPEAK_X_H,
+        (REG_MAXPEAK_Z_L - REG_MAXPEAK_X_H) as usize + 1,
+    ),
+    (REG_OFFSET_X, (REG_SELF_TEST - REG_OFFSET_X) as usize + 1),
+];
+
+/// Splits a dump buffer into the `(first register, destination)` pairs of [`DUMP_BLOCKS`].
+pub(crate) fn dump_segments(values: &mut [u8; DUMP_LEN]) -> impl Iterator<Item = (u8, &mut [u8])> {
+    let mut rest: &mut [u8] = values;
+    DUMP_BLOCKS.into_iter().map(move |(start, len)| {
+        let (segment, tail) = core::mem::take(&mut rest).split_at_mut(len);
+        rest = tail;
+        (start, segment)
+    })
+}
+
+#[derive(Clone, Copy)]
+struct DumpEntry {
+    address: u8,
+    name: &'static str,
+    reset: Option<u8>,
+}
+
+impl DumpEntry {
+    const fn of<R: Register<Raw = u8>>(name: &'static str) -> Self {
+        Self {
+            address: R::ADDRESS,
+            name,
+            reset: R::RESET_VALUE,
+        }
+    }
+}
+
+// Builds the dump table and the per-register decoder from one list, in address order.
+macro_rules! dump_map {
+    ($($name:literal => $reg:ty,)+) => {
+        const DUMP_MAP: [DumpEntry; DUMP_LEN] = [$(DumpEntry::of::<$reg>($name),)+];
+
+        #[cfg(feature = "defmt")]
+        fn format_decoded(f: defmt::Formatter<'_>, address: u8, value: u8) {
+            $(
+                if address == <$reg as Register>::ADDRESS {
+                    defmt::write!(f, "{}", defmt::Debug2Format(&<$reg>::from(value)));
+                    return;
+                }
+            )+
+        }
+    };
+}
+
+dump_map! {
+    "DEVID_AD" => DevIdAd,
+    "DEVID_MST" => DevIdMst,
+    "PARTID" => PartId,
+    "REVID" => RevId,
+    "STATUS" => Status,
+    "STATUS2" => Status2,
+    "FIFO_ENTRIES2" => FifoEntriesUpper,
+    "FIFO_ENTRIES" => FifoEntries,
+    "XDATA_H" => XDataH,
+    "XDATA_L" => XDataL,
+    "YDATA_H" => YDataH,
+    "YDATA_L" => YDataL,
+    "ZDATA_H" => ZDataH,
+    "ZDATA_L" => ZDataL,
+    "MAXPEAK_X_H" => MaxPeakXH,
+    "MAXPEAK_X_L" => MaxPeakXL,
+    "MAXPEAK_Y_H" => MaxPeakYH,
+    "MAXPEAK_Y_L" => MaxPeakYL,
+    "MAXPEAK_Z_H" => MaxPeakZH,
+    "MAXPEAK_Z_L" => MaxPeakZL,
+    "OFFSET_X" => OffsetX,
+    "OFFSET_Y" => OffsetY,
+    "OFFSET_Z" => OffsetZ,
+    "THRESH_ACT_X_H" => ThreshActXH,
+    "THRESH_ACT_X_L" => ThreshActXL,
+    "THRESH_ACT_Y_H" => ThreshActYH,
+    "THRESH_ACT_Y_L" => ThreshActYL,
+    "THRESH_ACT_Z_H" => ThreshActZH,
+    "THRESH_ACT_Z_L" => ThreshActZL,
+    "TIME_ACT" => TimeAct,
+    "THRESH_INACT_X_H" => ThreshInactXH,
+    "THRESH_INACT_X_L" => ThreshInactXL,
+    "THRESH_INACT_Y_H" => ThreshInactYH,
+    "THRESH_INACT_Y_L" => ThreshInactYL,
+    "THRESH_INACT_Z_H" => ThreshInactZH,
+    "THRESH_INACT_Z_L" => ThreshInactZL,
+    "TIME_INACT_H" => TimeInactH,
+    "TIME_INACT_L" => TimeInactL,
+    "THRESH_ACT2_X_H" => ThreshAct2XH,
+    "THRESH_ACT2_X_L" => ThreshAct2XL,
+    "THRESH_ACT2_Y_H" => ThreshAct2YH,
+    "THRESH_ACT2_Y_L" => ThreshAct2YL,
+    "THRESH_ACT2_Z_H" => ThreshAct2ZH,
+    "THRESH_ACT2_Z_L" => ThreshAct2ZL,
+    "HPF" => Hpf,
+    "FIFO_SAMPLES" => FifoSamples,
+    "FIFO_CTL" => FifoControl,
+    "INT1_MAP" => Int1Map,
+    "INT2_MAP" => Int2Map,
+    "TIMING" => Timing,
+    "MEASURE" => Measure,
+    "POWER_CTL" => PowerControl,
+    "SELF_TEST" => SelfTest,
+}
+
+/// Iterates over `(address, reset value)` for every dumped register with a datasheet default.
+#[cfg(any(test, feature = "sim", feature = "testing"))]
+pub(crate) fn reset_values() -> impl Iterator<Item = (u8, u8)> {
+    DUMP_MAP
+        .iter()
+        .filter_map(|entry| entry.reset.map(|reset| (entry.address, reset)))
+}
+
+/// One register captured in a [`RegisterDump`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DumpedRegister {
+    /// Register address.
+    pub address: u8,
+    /// Datasheet register name.
+    pub name: &'static str,
+    /// Value read from the device.
+    pub value: u8,
+    /// Datasheet reset value, if defined.
+    pub reset: Option<u8>,
+}
+
+/// Snapshot of every readable register except `FIFO_DATA`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisterDump {
+    values: [u8; DUMP_LEN],
+}
+
+impl RegisterDump {
+    /// Version byte leading the encoding returned by [`to_bytes`](Self::to_bytes).
+    pub const FORMAT_VERSION: u8 = 1;
+    /// Length of the encoding: version, register count, then one value per register.
+    pub const ENCODED_LEN: usize = DUMP_LEN + 2;
+
+    /// Creates a dump from register values in address order.
+    pub(crate) const fn from_values(values: [u8; DUMP_LEN]) -> Self {
+        Self { values }
+    }
+
+    /// Returns the captured value of `address`, or `None` if it is not part of the dump.
+    pub fn get(&self, address: u8) -> Option<u8> {
+        DUMP_MAP
+            .iter()
+            .position(|entry| entry.address == address)
+            .map(|index| self.values[index])
+    }
+
+    /// Iterates over every captured register in address order.
+    pub fn iter(&self) -> impl Iterator<Item = DumpedRegister> + '_ {
+        DUMP_MAP
+            .iter()
+            .zip(self.values)
+            .map(|(entry, value)| DumpedRegister {
+                address: entry.address,
+                name: entry.name,
+                value,
+                reset: entry.reset,
+            })
+    }
+
+    /// Iterates over the registers whose value differs from the datasheet reset value.
+    pub fn diff_against_reset(&self) -> impl Iterator<Item = DumpedRegister> + '_ {
+        self.iter()
+            .filter(|register| register.reset.is_some_and(|reset| reset != register.value))
+    }
+
+    /// Encodes the dump as `[FORMAT_VERSION, DUMP_LEN, values...]` in address order.
+    pub fn to_bytes(&self) -> [u8; Self::ENCODED_LEN] {
+        let mut bytes = [0u8; Self::ENCODED_LEN];
+        bytes[0] = Self::FORMAT_VERSION;
+        bytes[1] = DUMP_LEN as u8;
+        bytes[2..].copy_from_slice(&self.values);
+        bytes
+    }
+
+    /// Decodes a dump produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Returns `None` when the version, register count or length does not match.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            [Self::FORMAT_VERSION, count, values @ ..]
+                if usize::from(*count) == DUMP_LEN && values.len() == DUMP_LEN =>
+            {
+                let mut dump = [0u8; DUMP_LEN];
+                dump.copy_from_slice(values);
+                Some(Self::from_values(dump))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "defmt")]
+impl defmt::Format for RegisterDump {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        defmt::write!(f, "RegisterDump v{=u8}", Self::FORMAT_VERSION);
+        for register in self.iter() {
+            defmt::write!(
+                f,
+                "\n  {=u8:#04x} {=str} = {=u8:#04x} ",
+                register.address,
+                register.name,
+                register.value
+            );
+            format_decoded(f, register.address, register.value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{DUMP_BLOCKS, DUMP_LEN, DUMP_MAP, RegisterDump};
+    use crate::config::Config;
+    use crate::device::Adxl372;
+    use crate::registers::{REG_FIFO_SAMPLES, REG_STATUS};
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+    use std::vec::Vec;
+
+    fn reset_dump() -> [u8; DUMP_LEN] {
+        DUMP_MAP.map(|entry| entry.reset.unwrap_or(0))
+    }
+
+    /// Ensures the burst blocks cover exactly the dump table, in order.
+    #[test]
+    fn blocks_cover_dump_map() {
+        let addresses = DUMP_BLOCKS
+            .iter()
+            .flat_map(|&(start, len)| (0..len).map(move |offset| start + offset as u8));
+
+        assert!(addresses.eq(DUMP_MAP.iter().map(|entry| entry.address)));
+    }
+
+    /// Round-trips the stable encoding and rejects foreign versions.
+    #[test]
+    fn encoding_roundtrips() {
+        let mut values = reset_dump();
+        values[7] = 0x2A;
+        let dump = RegisterDump::from_values(values);
+        let bytes = dump.to_bytes();
+
+        assert_eq!(bytes[..2], [RegisterDump::FORMAT_VERSION, DUMP_LEN as u8]);
+        assert_eq!(RegisterDump::from_bytes(&bytes), Some(dump));
+
+        let mut foreign = bytes;
+        foreign[0] = 0xFF;
+        assert_eq!(RegisterDump::from_bytes(&foreign), None);
+        assert_eq!(RegisterDump::from_bytes(&bytes[..10]), None);
+    }
+
+    /// Reads the three bursts and reports registers that left their reset state.
+    #[test]
+    fn device_dump_diffs_against_reset() {
+        let mut values = reset_dump();
+        let status = DUMP_MAP
+            .iter()
+            .position(|e| e.address == REG_STATUS)
+            .unwrap();
+        let samples = DUMP_MAP
+            .iter()
+            .position(|e| e.address == REG_FIFO_SAMPLES)
+            .unwrap();
+        values[status] = 0xA1;
+        values[samples] = 0x60;
+
+        let mut expectations = Vec::new();
+        let mut index = 0;
+        for (start, len) in DUMP_BLOCKS {
+            expectations.extend([
+                Transaction::transaction_start(),
+                Transaction::write_vec(vec![(start << 1) | 1]),
+                Transaction::read_vec(values[index..index + len].to_vec()),
+                Transaction::transaction_end(),
+            ]);
+            index += len;
+        }
+        let mut device = Adxl372::new_spi(Mock::new(&expectations), Config::default());
+
+        let dump = device.dump_registers().unwrap();
+        let changed: Vec<_> = dump.diff_against_reset().map(|r| r.name).collect();
+        assert_eq!(changed, ["STATUS", "FIFO_SAMPLES"]);
+        assert_eq!(dump.get(REG_STATUS), Some(0xA1));
+
+        device.release_spi().0.done();
+    }
+}
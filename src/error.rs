@@ -1,5 +1,7 @@
 //! Error handling primitives for the ADXL372 driver.
 
+use crate::config::ConfigError;
+
 /// Crate-wide result type alias.
 pub type Result<T, E> = core::result::Result<T, Error<E>>;
 
@@ -9,13 +11,33 @@ pub enum Error<E> {
     /// Any error reported by the underlying bus interface.
     Interface(E),
     /// The provided configuration parameters are invalid.
-    InvalidConfig,
+    InvalidConfig(ConfigError),
     /// The self-test did not pass.
     SelfTestFailed,
     /// The requested operation is not available yet.
     NotReady,
     /// The peripheral did not report the expected identification values.
     DeviceIdMismatch,
+    /// A digital interrupt pin reported an error.
+    Pin,
+    /// The awaited interrupt event is not routed to any interrupt pin.
+    EventNotMapped,
+    /// Every pin carrying the awaited event also carries a level-held source that can keep it
+    /// asserted without the event.
+    SharedInterruptPin,
+    /// The operation samples acceleration but the device is not in measurement mode.
+    NotMeasuring,
+    /// The operation requires at least one sample.
+    InvalidSampleCount,
+    /// A written register did not read back the expected value.
+    VerifyFailed {
+        /// Address of the first mismatching register.
+        register: u8,
+        /// Value written to the register.
+        written: u8,
+        /// Value read back from the register.
+        read: u8,
+    },
 }
 
 impl<E> From<E> for Error<E> {
@@ -3,6 +3,22 @@
 use crate::error::Result;
 use crate::interface::Adxl372Interface;
 use crate::params::{FifoFormat, FifoMode};
+use crate::registers::{
+    FifoControl, FifoEntriesUpper, REG_FIFO_DATA, REG_FIFO_ENTRIES2, fifo_entry_count, unpack_axis,
+};
+
+/// Number of 16-bit entries the FIFO can hold.
+pub const FIFO_CAPACITY: u16 = 512;
+/// Largest watermark encodable in `FIFO_SAMPLES` plus `FIFO_CTL.FIFO_SAMPLES[8]`.
+pub const FIFO_MAX_WATERMARK: u16 = 0x1FF;
+/// Number of bytes occupied by a single FIFO entry.
+pub const FIFO_ENTRY_BYTES: usize = 2;
+/// Flag bit in the low byte of a FIFO entry marking the first axis of a set.
+pub const FIFO_SERIES_START_FLAG: u8 = 0x01;
+/// Flag bit in the low byte of a FIFO entry marking peak acceleration data.
+pub const FIFO_PEAK_FLAG: u8 = 0x02;
+// Scratch buffer used while streaming FIFO sets; divisible by every set size (2, 4, 6 bytes).
+pub(crate) const FIFO_CHUNK_BYTES: usize = 96;
 
 /// A decoded FIFO sample.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,8 +53,26 @@ impl FifoSettings {
             format,
         }
     }
+
+    /// Decodes the settings from the `FIFO_SAMPLES` and `FIFO_CTL` register values.
+    pub fn from_registers(samples: u8, control: FifoControl) -> Self {
+        let msb = u16::from(control.samples_msb()) << 8;
+        Self::new(msb | u16::from(samples), control.mode(), control.format())
+    }
+
+    /// Encodes the settings into the `FIFO_SAMPLES` and `FIFO_CTL` register values.
+    ///
+    /// Watermarks above [`FIFO_MAX_WATERMARK`] are truncated to nine bits.
+    pub fn to_registers(self) -> (u8, FifoControl) {
+        let control = FifoControl::new()
+            .with_samples_msb(self.watermark & 0x100 != 0)
+            .with_mode(self.mode)
+            .with_format(self.format);
+        ((self.watermark & 0xFF) as u8, control)
+    }
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for Sample {
     fn default() -> Self {
         Self {
@@ -50,42 +84,574 @@ impl Default for Sample {
     }
 }
 
+/// Decodes one FIFO set (`axis_count` big-endian entries) according to `format`.
+///
+/// Axes are laid out in X, Y, Z order, skipping the axes not selected by the format.
+/// `raw` must hold at least `format.axis_count() * FIFO_ENTRY_BYTES` bytes.
+pub fn decode_sample(format: FifoFormat, raw: &[u8]) -> Sample {
+    let (has_x, has_y, has_z) = axis_layout(format);
+    let mut entries = raw
+        .chunks_exact(FIFO_ENTRY_BYTES)
+        .map(|entry| unpack_axis(entry[0], entry[1]));
+
+    let x = if has_x { entries.next() } else { None };
+    let y = if has_y { entries.next() } else { None };
+    let z = if has_z { entries.next() } else { None };
+
+    Sample {
+        x,
+        y,
+        z,
+        is_peak: matches!(format, FifoFormat::Peak),
+    }
+}
+
+/// Returns which of the X, Y and Z axes a FIFO format stores.
+pub(crate) const fn axis_layout(format: FifoFormat) -> (bool, bool, bool) {
+    match format {
+        FifoFormat::XYZ | FifoFormat::Peak => (true, true, true),
+        FifoFormat::X => (true, false, false),
+        FifoFormat::Y => (false, true, false),
+        FifoFormat::XY => (true, true, false),
+        FifoFormat::Z => (false, false, true),
+        FifoFormat::XZ => (true, false, true),
+        FifoFormat::YZ => (false, true, true),
+    }
+}
+
+/// Counters describing the entries a [`FifoDecoder`] could not place in a sample.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct FifoDecoderStats {
+    /// Entries dropped because they could not be assigned to an axis.
+    pub discarded_entries: u32,
+    /// Sets abandoned because a series-start flag arrived early or was missing.
+    pub misaligned_sets: u32,
+}
+
+/// Outcome of a single [`FifoDecoder::decode`] call.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DecodeProgress {
+    /// Number of input bytes consumed.
+    pub consumed: usize,
+    /// Number of samples written into the output slice.
+    pub produced: usize,
+}
+
+/// Stateful FIFO stream decoder that resynchronises on the series-start flag.
+///
+/// The decoder accepts raw `FIFO_DATA` bytes in chunks of any size, including chunks that split
+/// an entry or a set, and keeps the partial state between calls. Every entry carries a
+/// series-start flag ([`FIFO_SERIES_START_FLAG`]) on the first axis of a set; the decoder uses
+/// it to re-align the axis order after dropped entries instead of shifting axes permanently.
+/// Entries that cannot be placed are counted in [`FifoDecoderStats`].
+#[derive(Debug, Clone, Copy)]
+pub struct FifoDecoder {
+    format: FifoFormat,
+    pending_byte: Option<u8>,
+    axis_index: u8,
+    aligned: bool,
+    partial: [Option<i16>; 3],
+    partial_peak: bool,
+    stats: FifoDecoderStats,
+}
+
+impl FifoDecoder {
+    /// Creates a decoder for the given FIFO packing format.
+    pub const fn new(format: FifoFormat) -> Self {
+        Self {
+            format,
+            pending_byte: None,
+            axis_index: 0,
+            aligned: false,
+            partial: [None; 3],
+            partial_peak: false,
+            stats: FifoDecoderStats {
+                discarded_entries: 0,
+                misaligned_sets: 0,
+            },
+        }
+    }
+
+    /// Returns the FIFO format this decoder expects.
+    pub const fn format(&self) -> FifoFormat {
+        self.format
+    }
+
+    /// Returns the discard counters accumulated since creation or the last [`reset`](Self::reset).
+    pub const fn stats(&self) -> FifoDecoderStats {
+        self.stats
+    }
+
+    /// Drops any partial entry or set and clears the counters.
+    ///
+    /// Call this after flushing the FIFO or changing its format.
+    pub fn reset(&mut self) {
+        *self = Self::new(self.format);
+    }
+
+    /// Decodes `chunk` into `out`, stopping early once `out` is full.
+    ///
+    /// Returns how many input bytes were consumed and how many samples were produced; any
+    /// unconsumed bytes must be passed again on the next call.
+    pub fn decode(&mut self, chunk: &[u8], out: &mut [Sample]) -> DecodeProgress {
+        let mut progress = DecodeProgress::default();
+
+        while progress.consumed < chunk.len() && progress.produced < out.len() {
+            let byte = chunk[progress.consumed];
+            progress.consumed += 1;
+
+            let Some(msb) = self.pending_byte.take() else {
+                self.pending_byte = Some(byte);
+                continue;
+            };
+
+            if let Some(sample) = self.push_entry(msb, byte) {
+                out[progress.produced] = sample;
+                progress.produced += 1;
+            }
+        }
+
+        progress
+    }
+
+    /// Number of whole entries held in an incomplete set.
+    pub(crate) const fn carried_entries(&self) -> usize {
+        self.axis_index as usize
+    }
+
+    fn push_entry(&mut self, msb: u8, lsb: u8) -> Option<Sample> {
+        let series_start = lsb & FIFO_SERIES_START_FLAG != 0;
+
+        if series_start {
+            if self.axis_index != 0 {
+                self.abandon_partial_set();
+            }
+            self.aligned = true;
+        } else if !self.aligned {
+            self.stats.discarded_entries += 1;
+            return None;
+        } else if self.axis_index == 0 {
+            // A set must open with the series-start flag; wait for the next one.
+            self.aligned = false;
+            self.stats.discarded_entries += 1;
+            self.stats.misaligned_sets += 1;
+            return None;
+        }
+
+        let slot = self.slot_for(self.axis_index);
+        self.partial[slot] = Some(unpack_axis(msb, lsb));
+        self.partial_peak |= lsb & FIFO_PEAK_FLAG != 0;
+        self.axis_index += 1;
+
+        if self.axis_index < self.format.axis_count() {
+            return None;
+        }
+
+        let sample = Sample {
+            x: self.partial[0],
+            y: self.partial[1],
+            z: self.partial[2],
+            is_peak: self.partial_peak || matches!(self.format, FifoFormat::Peak),
+        };
+        self.clear_partial();
+        Some(sample)
+    }
+
+    fn abandon_partial_set(&mut self) {
+        self.stats.discarded_entries += u32::from(self.axis_index);
+        self.stats.misaligned_sets += 1;
+        self.clear_partial();
+    }
+
+    fn clear_partial(&mut self) {
+        self.axis_index = 0;
+        self.partial = [None; 3];
+        self.partial_peak = false;
+    }
+
+    /// Maps the position within a set to the X/Y/Z slot stored by the format.
+    fn slot_for(&self, position: u8) -> usize {
+        let (has_x, has_y, has_z) = axis_layout(self.format);
+        let mut remaining = position;
+        for (slot, present) in [has_x, has_y, has_z].into_iter().enumerate() {
+            if present {
+                if remaining == 0 {
+                    return slot;
+                }
+                remaining -= 1;
+            }
+        }
+        2
+    }
+}
+
+// ======================================================================
+// == Shared Read Planning ===============================================
+// ======================================================================
+
+/// Decodes the `FIFO_ENTRIES2`, `FIFO_ENTRIES` burst into an entry count.
+pub(crate) fn decode_fifo_level(raw: [u8; 2]) -> u16 {
+    fifo_entry_count(FifoEntriesUpper::from_bytes([raw[0]]), raw[1])
+}
+
+/// Returns how many bytes of a `len`-byte buffer hold whole FIFO entries.
+pub(crate) const fn whole_entries(len: usize) -> usize {
+    len - len % FIFO_ENTRY_BYTES
+}
+
+/// Chunking of a sample read, shared by the blocking and async drivers.
+///
+/// Only complete sets counted by the FIFO level are drained, in chunks that fit
+/// [`FIFO_CHUNK_BYTES`]. The read is capped so the entries carried by `decoder` plus the
+/// drained ones complete at most `capacity` samples; every drained entry is therefore
+/// consumed by the decoder instead of being dropped when the output fills up.
+pub(crate) struct SampleReadPlan {
+    remaining_entries: usize,
+}
+
+impl SampleReadPlan {
+    /// Plans the read of up to `capacity` samples from a FIFO holding `level` entries.
+    pub(crate) fn new(decoder: &FifoDecoder, level: u16, capacity: usize) -> Self {
+        let axes = usize::from(decoder.format().axis_count());
+        let buffered = usize::from(level) / axes * axes;
+        let room = (capacity * axes).saturating_sub(decoder.carried_entries());
+        Self {
+            remaining_entries: buffered.min(room),
+        }
+    }
+
+    /// Returns the length of the next chunk to drain, or `None` once the plan is complete.
+    pub(crate) fn next_chunk(&mut self) -> Option<usize> {
+        if self.remaining_entries == 0 {
+            return None;
+        }
+        let entries = self
+            .remaining_entries
+            .min(FIFO_CHUNK_BYTES / FIFO_ENTRY_BYTES);
+        self.remaining_entries -= entries;
+        Some(entries * FIFO_ENTRY_BYTES)
+    }
+}
+
+/// Reads the number of entries currently stored in the FIFO.
+pub fn read_fifo_level<IFACE>(interface: &mut IFACE) -> Result<u16, IFACE::Error>
+where
+    IFACE: Adxl372Interface,
+{
+    let mut raw = [0u8; 2];
+    interface.read_many(REG_FIFO_ENTRIES2, &mut raw)?;
+    Ok(decode_fifo_level(raw))
+}
+
 /// Reads raw FIFO bytes into the caller-provided buffer.
+///
+/// Only whole two-byte entries are transferred; the returned value is the number of bytes
+/// written into `buf`.
 pub fn read_fifo_raw<IFACE>(interface: &mut IFACE, buf: &mut [u8]) -> Result<usize, IFACE::Error>
 where
     IFACE: Adxl372Interface,
 {
-    if buf.is_empty() {
+    let len = whole_entries(buf.len());
+    if len == 0 {
         return Ok(0);
     }
 
-    interface.read_many(crate::registers::REG_STATUS, buf)?;
-    Ok(buf.len())
+    interface.read_many(REG_FIFO_DATA, &mut buf[..len])?;
+    Ok(len)
 }
 
 /// Decodes FIFO samples into the provided output slice.
+///
+/// Only complete sets currently held by the FIFO are read, so a partially written set is left
+/// in place for the next call. The bytes are routed through a fresh [`FifoDecoder`], so entries
+/// preceding the first series-start flag are discarded rather than shifting the axis order.
+/// Returns the number of samples written into `samples`.
+///
+/// **Data loss:** when the FIFO is misaligned, the discarded entries have already been drained
+/// from the device and the tail of an incomplete set is dropped with the decoder. Keep a
+/// persistent decoder and call [`read_fifo_samples_with`] instead when every sample matters.
 pub fn read_fifo_samples<IFACE>(
     interface: &mut IFACE,
+    format: FifoFormat,
+    samples: &mut [Sample],
+) -> Result<usize, IFACE::Error>
+where
+    IFACE: Adxl372Interface,
+{
+    read_fifo_samples_with(interface, &mut FifoDecoder::new(format), samples)
+}
+
+/// Decodes FIFO samples through a caller-owned [`FifoDecoder`].
+///
+/// Behaves like [`read_fifo_samples`] with the decoder's format, but entries of a set split by
+/// misalignment stay in `decoder` and complete on the next call instead of being lost.
+pub fn read_fifo_samples_with<IFACE>(
+    interface: &mut IFACE,
+    decoder: &mut FifoDecoder,
     samples: &mut [Sample],
 ) -> Result<usize, IFACE::Error>
 where
     IFACE: Adxl372Interface,
 {
-    let mut raw = [0u8; 6];
-    let mut count = 0;
+    let mut plan = SampleReadPlan::new(decoder, read_fifo_level(interface)?, samples.len());
+    let mut raw = [0u8; FIFO_CHUNK_BYTES];
+    let mut produced = 0;
 
-    for sample in samples.iter_mut() {
-        let bytes_used = read_fifo_raw(interface, &mut raw)?;
-        if bytes_used < 2 {
-            break;
+    while let Some(len) = plan.next_chunk() {
+        let chunk = &mut raw[..len];
+        read_fifo_raw(interface, chunk)?;
+
+        produced += decoder.decode(chunk, &mut samples[produced..]).produced;
+    }
+
+    Ok(produced)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use core::convert::Infallible;
+
+    /// Minimal FIFO model serving `FIFO_ENTRIES` and `FIFO_DATA` reads.
+    struct FifoMock<'a> {
+        data: &'a [u8],
+        offset: usize,
+    }
+
+    impl Adxl372Interface for FifoMock<'_> {
+        type Error = Infallible;
+
+        fn write_register(
+            &mut self,
+            register: u8,
+            _value: u8,
+        ) -> core::result::Result<(), Self::Error> {
+            panic!("unexpected write to {register:#04x}");
+        }
+
+        fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+            panic!("unexpected single read of {register:#04x}");
+        }
+
+        fn read_many(
+            &mut self,
+            register: u8,
+            buf: &mut [u8],
+        ) -> core::result::Result<(), Self::Error> {
+            match register {
+                REG_FIFO_ENTRIES2 => {
+                    let entries = ((self.data.len() - self.offset) / FIFO_ENTRY_BYTES) as u16;
+                    buf.copy_from_slice(&entries.to_be_bytes());
+                }
+                REG_FIFO_DATA => {
+                    buf.copy_from_slice(&self.data[self.offset..self.offset + buf.len()]);
+                    self.offset += buf.len();
+                }
+                other => panic!("unexpected burst read of {other:#04x}"),
+            }
+            Ok(())
+        }
+
+        fn write_many(
+            &mut self,
+            register: u8,
+            _data: &[u8],
+        ) -> core::result::Result<(), Self::Error> {
+            panic!("unexpected burst write to {register:#04x}");
         }
+    }
 
-        sample.x = Some(i16::from_be_bytes([raw[0], raw[1]]));
-        sample.y = Some(i16::from_be_bytes([raw[2], raw[3]]));
-        sample.z = Some(i16::from_be_bytes([raw[4], raw[5]]));
-        sample.is_peak = false;
-        count += 1;
+    fn entries<const N: usize>(values: [i16; N]) -> [[u8; 2]; N] {
+        values.map(|value| (value << 4).to_be_bytes())
     }
 
-    Ok(count)
+    /// Encodes FIFO entries, flagging the first axis of every `axes`-sized set.
+    fn stream<const N: usize>(values: [i16; N], axes: usize) -> [[u8; 2]; N] {
+        let mut raw = entries(values);
+        for entry in raw.iter_mut().step_by(axes) {
+            entry[1] |= FIFO_SERIES_START_FLAG;
+        }
+        raw
+    }
+
+    /// Ensures watermark bit 8 lands in FIFO_CTL and survives a roundtrip.
+    #[test]
+    #[allow(clippy::unusual_byte_groupings)]
+    fn settings_roundtrip_through_registers() {
+        let settings = FifoSettings::new(0x1A5, FifoMode::Stream, FifoFormat::XZ);
+        let (samples, control) = settings.to_registers();
+
+        assert_eq!(samples, 0xA5);
+        assert_eq!(u8::from(control), 0b00_101_01_1);
+        assert_eq!(FifoSettings::from_registers(samples, control), settings);
+    }
+
+    /// Verifies dual-axis formats populate only the selected axes in X, Y, Z order.
+    #[test]
+    fn decode_dual_axis_formats() {
+        let raw = entries([-3, 7]);
+        let raw = raw.as_flattened();
+
+        let yz = decode_sample(FifoFormat::YZ, raw);
+        assert_eq!((yz.x, yz.y, yz.z), (None, Some(-3), Some(7)));
+
+        let xz = decode_sample(FifoFormat::XZ, raw);
+        assert_eq!((xz.x, xz.y, xz.z), (Some(-3), None, Some(7)));
+    }
+
+    /// Verifies single-axis and peak formats.
+    #[test]
+    fn decode_single_axis_and_peak_formats() {
+        let y = decode_sample(FifoFormat::Y, entries([12]).as_flattened());
+        assert_eq!((y.x, y.y, y.z, y.is_peak), (None, Some(12), None, false));
+
+        let raw = entries([1, 2, -2048]);
+        let peak = decode_sample(FifoFormat::Peak, raw.as_flattened());
+        assert_eq!(
+            (peak.x, peak.y, peak.z, peak.is_peak),
+            (Some(1), Some(2), Some(-2048), true)
+        );
+    }
+
+    /// Ensures only complete sets are consumed and a trailing partial set stays queued.
+    #[test]
+    fn read_fifo_samples_leaves_partial_set_queued() {
+        let raw = stream([1, 2, 3, 4, 5], 2);
+        let mut mock = FifoMock {
+            data: raw.as_flattened(),
+            offset: 0,
+        };
+
+        let mut samples = [Sample::default(); 4];
+        let count = read_fifo_samples(&mut mock, FifoFormat::XY, &mut samples).unwrap();
+
+        assert_eq!(count, 2);
+        assert_eq!((samples[0].x, samples[0].y), (Some(1), Some(2)));
+        assert_eq!((samples[1].x, samples[1].y), (Some(3), Some(4)));
+        assert_eq!(mock.offset, 8);
+    }
+
+    /// Ensures a set split by misalignment completes on the next call instead of being lost.
+    #[test]
+    fn read_fifo_samples_with_carries_split_set() {
+        // Trailing Z of an older set shifts every following set by one entry.
+        let mut raw = [[0u8; 2]; 7];
+        raw[..1].copy_from_slice(&entries([9]));
+        raw[1..].copy_from_slice(&stream([1, 2, 3, 4, 5, 6], 3));
+        let mut mock = FifoMock {
+            data: raw.as_flattened(),
+            offset: 0,
+        };
+        let mut decoder = FifoDecoder::new(FifoFormat::XYZ);
+        let mut samples = [Sample::default(); 1];
+
+        assert_eq!(
+            read_fifo_samples_with(&mut mock, &mut decoder, &mut samples).unwrap(),
+            0
+        );
+        assert_eq!(
+            read_fifo_samples_with(&mut mock, &mut decoder, &mut samples).unwrap(),
+            1
+        );
+        assert_eq!(
+            (samples[0].x, samples[0].y, samples[0].z),
+            (Some(1), Some(2), Some(3))
+        );
+        assert_eq!(
+            read_fifo_samples_with(&mut mock, &mut decoder, &mut samples).unwrap(),
+            1
+        );
+        assert_eq!(
+            (samples[0].x, samples[0].y, samples[0].z),
+            (Some(4), Some(5), Some(6))
+        );
+        assert_eq!(decoder.stats().discarded_entries, 1);
+    }
+
+    /// Verifies chunks that split entries and sets still decode in order.
+    #[test]
+    fn decoder_handles_arbitrary_chunk_boundaries() {
+        let raw = stream([1, 2, 3, 4, 5, 6], 3);
+        let raw = raw.as_flattened();
+        let mut decoder = FifoDecoder::new(FifoFormat::XYZ);
+        let mut samples = [Sample::default(); 2];
+        let mut produced = 0;
+
+        for chunk in raw.chunks(5) {
+            let progress = decoder.decode(chunk, &mut samples[produced..]);
+            assert_eq!(progress.consumed, chunk.len());
+            produced += progress.produced;
+        }
+
+        assert_eq!(produced, 2);
+        assert_eq!(
+            (samples[0].x, samples[0].y, samples[0].z),
+            (Some(1), Some(2), Some(3))
+        );
+        assert_eq!(
+            (samples[1].x, samples[1].y, samples[1].z),
+            (Some(4), Some(5), Some(6))
+        );
+        assert_eq!(decoder.stats(), FifoDecoderStats::default());
+    }
+
+    /// Ensures a dropped entry costs one set instead of rotating every following axis.
+    #[test]
+    fn decoder_resynchronises_after_dropped_entry() {
+        // Leading Z of a previous set, then a set missing its Z, then a clean set.
+        let mut raw = [[0u8; 2]; 6];
+        raw[..1].copy_from_slice(&entries([9]));
+        raw[1..3].copy_from_slice(&stream([1, 2], 3));
+        raw[3..].copy_from_slice(&stream([4, 5, 6], 3));
+
+        let mut decoder = FifoDecoder::new(FifoFormat::XYZ);
+        let mut samples = [Sample::default(); 2];
+        let progress = decoder.decode(raw.as_flattened(), &mut samples);
+
+        assert_eq!(progress.produced, 1);
+        assert_eq!(
+            (samples[0].x, samples[0].y, samples[0].z),
+            (Some(4), Some(5), Some(6))
+        );
+        assert_eq!(
+            decoder.stats(),
+            FifoDecoderStats {
+                discarded_entries: 3,
+                misaligned_sets: 1,
+            }
+        );
+    }
+
+    /// Verifies the peak flag marks samples and output exhaustion stops consumption.
+    #[test]
+    fn decoder_reports_peak_and_respects_output_capacity() {
+        let mut raw = stream([7, 8, 9, 10], 2);
+        raw[3][1] |= FIFO_PEAK_FLAG;
+
+        let mut decoder = FifoDecoder::new(FifoFormat::YZ);
+        let mut samples = [Sample::default(); 1];
+        let first = decoder.decode(raw.as_flattened(), &mut samples);
+        assert_eq!(
+            first,
+            DecodeProgress {
+                consumed: 4,
+                produced: 1
+            }
+        );
+        assert!(!samples[0].is_peak);
+
+        let second = decoder.decode(&raw.as_flattened()[4..], &mut samples);
+        assert_eq!(
+            second,
+            DecodeProgress {
+                consumed: 4,
+                produced: 1
+            }
+        );
+        assert_eq!(
+            (samples[0].x, samples[0].y, samples[0].z),
+            (None, Some(9), Some(10))
+        );
+        assert!(samples[0].is_peak);
+    }
 }
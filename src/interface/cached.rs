@@ -0,0 +1,357 @@
+//! Shadow-register cache layered over any [`Adxl372Interface`].
+//!
+//! The driver updates `TIMING`, `MEASURE`, `POWER_CTL` and `HPF` with read-modify-write
+//! sequences. Wrapping the bus in [`CachedInterface`] keeps a copy of every register the host
+//! fully owns, so those reads are answered locally once the value is known and configuration
+//! changes become write-only bus traffic. The set is derived from the register metadata: a
+//! register is cached when it is [`ReadWrite`](crate::registers::RegisterAccess::ReadWrite)
+//! and every bit reads back as written, see [`is_cacheable`].
+//!
+//! Status, data, FIFO and `SELF_TEST` registers change on their own and always go to the
+//! bus. Writing the reset code to `RESET` reloads every cached value from the datasheet reset
+//! values, so the first read-modify-write after a reset stays off the bus.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use embedded_hal::spi::SpiDevice;
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::interface::cached::CachedInterface;
+//! use adxl372::interface::spi::SpiInterface;
+//!
+//! # fn example<SPI: SpiDevice>(spi: SPI) {
+//! let interface = CachedInterface::new(SpiInterface::new(spi));
+//! let mut device = Adxl372::new(interface, Config::default());
+//!
+//! // Force the next read-back to hit the bus, e.g. before `verify_config`.
+//! device.interface_mut().invalidate();
+//! # }
+//! ```
+
+use super::Adxl372Interface;
+#[cfg(feature = "async")]
+use super::Adxl372InterfaceAsync;
+use crate::registers::{
+    REG_FIFO_DATA, REG_RESET, REGISTER_MAP, RESET_COMMAND, RegisterAccess, register_info,
+};
+
+// Addresses `0x00..=FIFO_DATA`, one valid bit each.
+const SHADOW_LEN: usize = REG_FIFO_DATA as usize + 1;
+
+/// Returns `true` when reads of `register` may be served from the shadow copy.
+///
+/// Only read/write registers whose whole content reads back as written qualify; such a
+/// register only changes when the host writes it.
+pub const fn is_cacheable(register: u8) -> bool {
+    match register_info(register) {
+        Some(info) => matches!(info.access, RegisterAccess::ReadWrite) && info.verify_mask == 0xFF,
+        None => false,
+    }
+}
+
+/// Bus interface wrapper that serves control-register reads from a shadow copy.
+///
+/// Every [cacheable](is_cacheable) register only changes when the host writes it, so the cached
+/// value stays authoritative until the device loses power. Call
+/// [`invalidate`](Self::invalidate) after a brown-out or when another bus master may have
+/// touched the device.
+pub struct CachedInterface<IFACE> {
+    inner: IFACE,
+    shadow: ShadowRegisters,
+}
+
+impl<IFACE> CachedInterface<IFACE> {
+    /// Wraps `inner` with an empty cache; each register is fetched once on first use.
+    pub const fn new(inner: IFACE) -> Self {
+        Self {
+            inner,
+            shadow: ShadowRegisters::new(),
+        }
+    }
+
+    /// Drops every cached value so the next access reads from the device.
+    pub fn invalidate(&mut self) {
+        self.shadow.invalidate();
+    }
+
+    /// Returns the cached value for `register`, if known.
+    pub fn cached(&self, register: u8) -> Option<u8> {
+        self.shadow.get(register)
+    }
+
+    /// Provides shared access to the wrapped interface.
+    pub fn inner(&self) -> &IFACE {
+        &self.inner
+    }
+
+    /// Provides mutable access to the wrapped interface.
+    ///
+    /// Writes issued directly through the inner interface bypass the cache; call
+    /// [`invalidate`](Self::invalidate) afterwards.
+    pub fn inner_mut(&mut self) -> &mut IFACE {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the inner interface.
+    pub fn release(self) -> IFACE {
+        self.inner
+    }
+}
+
+impl<IFACE> Adxl372Interface for CachedInterface<IFACE>
+where
+    IFACE: Adxl372Interface,
+{
+    type Error = IFACE::Error;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        self.write_many(register, core::slice::from_ref(&value))
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        self.read_many(register, &mut value)?;
+        Ok(value[0])
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        if self.shadow.load(register, buf) {
+            return Ok(());
+        }
+
+        self.inner.read_many(register, buf)?;
+        self.shadow.store(register, buf);
+        Ok(())
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_many(register, data);
+        self.shadow.observe_write(register, data, result.is_ok());
+        result
+    }
+}
+
+#[cfg(feature = "async")]
+impl<IFACE> Adxl372InterfaceAsync for CachedInterface<IFACE>
+where
+    IFACE: Adxl372InterfaceAsync,
+{
+    type Error = IFACE::Error;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372InterfaceAsync::write_many(self, register, core::slice::from_ref(&value)).await
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        Adxl372InterfaceAsync::read_many(self, register, &mut value).await?;
+        Ok(value[0])
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        if self.shadow.load(register, buf) {
+            return Ok(());
+        }
+
+        self.inner.read_many(register, buf).await?;
+        self.shadow.store(register, buf);
+        Ok(())
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_many(register, data).await;
+        self.shadow.observe_write(register, data, result.is_ok());
+        result
+    }
+}
+
+/// Shadow copy of the cacheable registers with a per-register valid bit.
+#[derive(Debug, Clone, Copy)]
+struct ShadowRegisters {
+    values: [u8; SHADOW_LEN],
+    valid: u128,
+}
+
+impl ShadowRegisters {
+    const fn new() -> Self {
+        Self {
+            values: [0; SHADOW_LEN],
+            valid: 0,
+        }
+    }
+
+    fn invalidate(&mut self) {
+        self.valid = 0;
+    }
+
+    fn get(&self, register: u8) -> Option<u8> {
+        let index = Self::index(register)?;
+        (self.valid & (1 << index) != 0).then_some(self.values[index])
+    }
+
+    // Fills `buf` from the cache when every register it spans is shadowed and known.
+    fn load(&self, register: u8, buf: &mut [u8]) -> bool {
+        if buf.is_empty() {
+            return false;
+        }
+
+        for (offset, slot) in buf.iter_mut().enumerate() {
+            match Self::address(register, offset).and_then(|address| self.get(address)) {
+                Some(value) => *slot = value,
+                None => return false,
+            }
+        }
+        true
+    }
+
+    fn store(&mut self, register: u8, data: &[u8]) {
+        for (offset, value) in data.iter().enumerate() {
+            if let Some(index) = Self::address(register, offset).and_then(Self::index) {
+                self.values[index] = *value;
+                self.valid |= 1 << index;
+            }
+        }
+    }
+
+    // Loads the datasheet reset value of every cacheable register.
+    fn load_reset_values(&mut self) {
+        self.invalidate();
+        for info in REGISTER_MAP {
+            if let Some(reset) = info.reset {
+                self.store(info.address, &[reset]);
+            }
+        }
+    }
+
+    fn observe_write(&mut self, register: u8, data: &[u8], succeeded: bool) {
+        if register == REG_RESET && data.first() == Some(&RESET_COMMAND) {
+            if succeeded {
+                self.load_reset_values();
+            } else {
+                self.invalidate();
+            }
+        } else if succeeded {
+            self.store(register, data);
+        } else {
+            // A failed write leaves the device contents unknown.
+            for offset in 0..data.len() {
+                if let Some(index) = Self::address(register, offset).and_then(Self::index) {
+                    self.valid &= !(1 << index);
+                }
+            }
+        }
+    }
+
+    fn address(register: u8, offset: usize) -> Option<u8> {
+        u8::try_from(offset)
+            .ok()
+            .and_then(|offset| register.checked_add(offset))
+    }
+
+    fn index(register: u8) -> Option<usize> {
+        is_cacheable(register).then_some(usize::from(register))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{CachedInterface, is_cacheable};
+    use crate::interface::Adxl372Interface;
+    use crate::interface::spi::SpiInterface;
+    use crate::registers::{
+        FifoControl, FifoData, FifoSamples, Hpf, Measure, OffsetX, PowerControl, REG_FIFO_SAMPLES,
+        REG_RESET, REG_STATUS, REG_TIMING, RESET_COMMAND, Register, Reset, SelfTest, Status,
+        Timing,
+    };
+    use embedded_hal_mock::eh1::spi::{Mock, Transaction};
+    use std::vec;
+    use std::vec::Vec;
+
+    fn transfer(command: u8, data: Transaction<u8>) -> [Transaction<u8>; 4] {
+        [
+            Transaction::transaction_start(),
+            Transaction::write_vec(vec![command]),
+            data,
+            Transaction::transaction_end(),
+        ]
+    }
+
+    fn covers<R: Register>() -> bool {
+        is_cacheable(R::ADDRESS)
+    }
+
+    /// Checks that host-owned control registers are cached and volatile ones are not.
+    #[test]
+    fn cacheable_set_follows_register_metadata() {
+        assert!(covers::<OffsetX>());
+        assert!(covers::<Hpf>());
+        assert!(covers::<FifoSamples>());
+        assert!(covers::<FifoControl>());
+        assert!(covers::<Timing>());
+        assert!(covers::<Measure>());
+        assert!(covers::<PowerControl>());
+
+        assert!(!covers::<Status>());
+        assert!(!covers::<SelfTest>());
+        assert!(!covers::<Reset>());
+        assert!(!covers::<FifoData>());
+        assert!(!is_cacheable(0x10));
+    }
+
+    /// Ensures a control register is read once, then served from the cache after writes.
+    #[test]
+    fn read_modify_write_hits_bus_once() {
+        let mut expectations = Vec::new();
+        expectations.extend(transfer(0x7B, Transaction::read_vec(vec![0x60])));
+        expectations.extend(transfer(0x7A, Transaction::write_vec(vec![0x80])));
+        expectations.extend(transfer(0x09, Transaction::read_vec(vec![0xA0])));
+        expectations.extend(transfer(0x09, Transaction::read_vec(vec![0xA1])));
+        let mut cached = CachedInterface::new(SpiInterface::new(Mock::new(&expectations)));
+
+        assert_eq!(cached.read_register(REG_TIMING).unwrap(), 0x60);
+        assert_eq!(cached.read_register(REG_TIMING).unwrap(), 0x60);
+        cached.write_register(REG_TIMING, 0x80).unwrap();
+        assert_eq!(cached.read_register(REG_TIMING).unwrap(), 0x80);
+
+        // Status registers are volatile and always read from the device.
+        assert_eq!(cached.read_register(REG_STATUS).unwrap(), 0xA0);
+        assert_eq!(cached.read_register(REG_STATUS).unwrap(), 0xA1);
+
+        cached.release().release().done();
+    }
+
+    /// Verifies a soft reset reloads the reset values, so the next read stays off the bus.
+    #[test]
+    fn reset_loads_reset_values() {
+        let mut expectations = Vec::new();
+        expectations.extend(transfer(0x7A, Transaction::write_vec(vec![0x80])));
+        expectations.extend(transfer(0x82, Transaction::write_vec(vec![RESET_COMMAND])));
+        let mut cached = CachedInterface::new(SpiInterface::new(Mock::new(&expectations)));
+
+        assert_eq!(cached.cached(REG_FIFO_SAMPLES), None);
+        cached.write_register(REG_TIMING, 0x80).unwrap();
+        assert_eq!(cached.cached(REG_TIMING), Some(0x80));
+
+        cached.write_register(REG_RESET, RESET_COMMAND).unwrap();
+        assert_eq!(cached.read_register(REG_TIMING).unwrap(), 0x00);
+        assert_eq!(cached.cached(REG_FIFO_SAMPLES), Some(0x80));
+        assert_eq!(cached.cached(REG_STATUS), None);
+
+        cached.release().release().done();
+    }
+}
@@ -0,0 +1,221 @@
+//! I2C interface implementation built on top of `embedded-hal` `I2c`.
+
+use embedded_hal::i2c::{I2c, Operation};
+
+use super::Adxl372Interface;
+#[cfg(feature = "async")]
+use super::Adxl372InterfaceAsync;
+
+/// 7-bit I2C device address selected by the `MISO/ASEL` strap.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum I2cAddress {
+    /// `MISO/ASEL` tied low (`0x1D`).
+    #[default]
+    Primary,
+    /// `MISO/ASEL` tied high (`0x53`).
+    Alternate,
+}
+
+impl I2cAddress {
+    /// Returns the raw 7-bit bus address.
+    pub const fn addr(self) -> u8 {
+        match self {
+            Self::Primary => 0x1D,
+            Self::Alternate => 0x53,
+        }
+    }
+}
+
+/// I2C-based interface implementation for the ADXL372 driver.
+pub struct I2cInterface<I2C> {
+    i2c: I2C,
+    address: I2cAddress,
+}
+
+impl<I2C> I2cInterface<I2C> {
+    /// Creates a new interface from the provided I2C bus and device address.
+    pub const fn new(i2c: I2C, address: I2cAddress) -> Self {
+        Self { i2c, address }
+    }
+
+    /// Returns the device address used for every transaction.
+    pub fn address(&self) -> I2cAddress {
+        self.address
+    }
+
+    /// Provides mutable access to the wrapped I2C bus.
+    pub fn i2c_mut(&mut self) -> &mut I2C {
+        &mut self.i2c
+    }
+
+    /// Consumes the interface and returns the owned I2C bus.
+    pub fn release(self) -> I2C {
+        self.i2c
+    }
+}
+
+impl<I2C> Adxl372Interface for I2cInterface<I2C>
+where
+    I2C: I2c,
+{
+    type Error = I2C::Error;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        self.write_many(register, core::slice::from_ref(&value))
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        self.read_many(register, &mut value)?;
+        Ok(value[0])
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        // The register pointer auto-increments after every byte, so a single
+        // repeated-start read covers the whole burst.
+        self.i2c.write_read(self.address.addr(), &[register], buf)
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        // Adjacent write operations are emitted back-to-back without a restart.
+        let command = [register];
+        let mut operations = [Operation::Write(&command), Operation::Write(data)];
+        self.i2c.transaction(self.address.addr(), &mut operations)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<I2C> Adxl372InterfaceAsync for I2cInterface<I2C>
+where
+    I2C: embedded_hal_async::i2c::I2c,
+{
+    type Error = I2C::Error;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372InterfaceAsync::write_many(self, register, core::slice::from_ref(&value)).await
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        Adxl372InterfaceAsync::read_many(self, register, &mut value).await?;
+        Ok(value[0])
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        embedded_hal_async::i2c::I2c::write_read(
+            &mut self.i2c,
+            self.address.addr(),
+            &[register],
+            buf,
+        )
+        .await
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let command = [register];
+        let mut operations = [Operation::Write(&command), Operation::Write(data)];
+        embedded_hal_async::i2c::I2c::transaction(
+            &mut self.i2c,
+            self.address.addr(),
+            &mut operations,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{I2cAddress, I2cInterface};
+    use crate::interface::Adxl372Interface;
+    use embedded_hal_mock::eh1::i2c::{Mock, Transaction};
+    use std::vec;
+
+    /// Ensures read_many issues a write-read burst against the selected address.
+    #[test]
+    fn read_many_uses_write_read_burst() {
+        let expectations = [Transaction::write_read(0x1D, vec![0x08], vec![0xAA, 0x55])];
+        let mut interface = I2cInterface::new(Mock::new(&expectations), I2cAddress::Primary);
+
+        let mut buffer = [0u8; 2];
+        interface.read_many(0x08, &mut buffer).unwrap();
+        assert_eq!(buffer, [0xAA, 0x55]);
+
+        interface.release().done();
+    }
+
+    /// Verifies write_many sends the register pointer followed by the payload in one transaction.
+    #[test]
+    fn write_many_prefixes_register_pointer() {
+        let expectations = [
+            Transaction::transaction_start(0x53),
+            Transaction::write(0x53, vec![0x3D]),
+            Transaction::write(0x53, vec![0x12, 0x34]),
+            Transaction::transaction_end(0x53),
+        ];
+        let mut interface = I2cInterface::new(Mock::new(&expectations), I2cAddress::Alternate);
+
+        interface.write_many(0x3D, &[0x12, 0x34]).unwrap();
+
+        interface.release().done();
+    }
+
+    /// Confirms single-register helpers reuse the burst implementations.
+    #[test]
+    fn single_register_helpers_reuse_bursts() {
+        let expectations = [
+            Transaction::write_read(0x1D, vec![0x02], vec![0xFA]),
+            Transaction::transaction_start(0x1D),
+            Transaction::write(0x1D, vec![0x41]),
+            Transaction::write(0x1D, vec![0x52]),
+            Transaction::transaction_end(0x1D),
+        ];
+        let mut interface = I2cInterface::new(Mock::new(&expectations), I2cAddress::Primary);
+
+        assert_eq!(interface.read_register(0x02).unwrap(), 0xFA);
+        interface.write_register(0x41, 0x52).unwrap();
+
+        interface.release().done();
+    }
+
+    /// Verifies empty buffers never touch the bus.
+    #[test]
+    fn empty_transfers_are_skipped() {
+        let expectations: [Transaction; 0] = [];
+        let mut interface = I2cInterface::new(Mock::new(&expectations), I2cAddress::Primary);
+
+        interface.read_many(0x08, &mut []).unwrap();
+        interface.write_many(0x08, &[]).unwrap();
+
+        interface.release().done();
+    }
+}
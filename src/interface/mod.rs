@@ -1,6 +1,10 @@
 //! Bus interface abstraction for the ADXL372 driver.
 
+pub mod cached;
+pub mod i2c;
+pub mod retry;
 pub mod spi;
+pub mod trace;
 
 /// Abstraction over the low-level bus access required by the driver.
 pub trait Adxl372Interface {
@@ -19,3 +23,35 @@ pub trait Adxl372Interface {
     /// Writes multiple consecutive registers from the provided buffer.
     fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error>;
 }
+
+/// Async abstraction over the low-level bus access required by [`Adxl372Async`](crate::asynch::Adxl372Async).
+#[cfg(feature = "async")]
+#[allow(async_fn_in_trait)]
+pub trait Adxl372InterfaceAsync {
+    /// Error type produced by the concrete bus implementation.
+    type Error;
+
+    /// Writes a single register.
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error>;
+
+    /// Reads a single register.
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error>;
+
+    /// Reads multiple consecutive registers into the provided buffer.
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error>;
+
+    /// Writes multiple consecutive registers from the provided buffer.
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error>;
+}
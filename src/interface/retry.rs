@@ -0,0 +1,493 @@
+//! Retry wrapper for transient bus faults, layered over any [`Adxl372Interface`].
+//!
+//! Without this layer the first bus error reaches the caller as
+//! [`Error::Interface`](crate::Error::Interface). [`RetryInterface`] re-issues the failed
+//! transfer after a backoff delay when the error is classified as transient, and keeps
+//! counters so flaky wiring shows up in diagnostics instead of as sporadic failures.
+//!
+//! Errors are classified through an [`ErrorClassifier`]. [`SpiClassifier`] uses
+//! [`embedded_hal::spi::Error::kind`] and is the default; [`I2cClassifier`] does the same for
+//! I2C buses, and any `Fn(&E) -> bool` closure works as well.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use embedded_hal::delay::DelayNs;
+//! # use embedded_hal::spi::SpiDevice;
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::interface::retry::{Backoff, RetryInterface, RetryPolicy};
+//! use adxl372::interface::spi::SpiInterface;
+//!
+//! # fn example<SPI: SpiDevice, D: DelayNs>(spi: SPI, delay: D) {
+//! let policy = RetryPolicy::new(3, Backoff::Fixed { us: 50 });
+//! let interface = RetryInterface::new(SpiInterface::new(spi), delay, policy);
+//! let mut device = Adxl372::new(interface, Config::default());
+//!
+//! let stats = device.interface_mut().stats();
+//! # let _ = stats;
+//! # }
+//! ```
+
+use super::Adxl372Interface;
+#[cfg(feature = "async")]
+use super::Adxl372InterfaceAsync;
+
+/// Delay inserted before each retry.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Backoff {
+    /// Retry immediately.
+    None,
+    /// Wait the same time before every retry.
+    Fixed {
+        /// Delay in microseconds.
+        us: u32,
+    },
+    /// Double the wait after every retry, up to `max_us`.
+    Exponential {
+        /// Delay before the first retry in microseconds.
+        initial_us: u32,
+        /// Upper bound for the delay in microseconds.
+        max_us: u32,
+    },
+}
+
+impl Backoff {
+    /// Returns the delay in microseconds before retry number `retry` (starting at 0).
+    pub const fn delay_us(&self, retry: u8) -> u32 {
+        match *self {
+            Self::None => 0,
+            Self::Fixed { us } => us,
+            Self::Exponential { initial_us, max_us } => {
+                let scaled = if retry >= 32 {
+                    u32::MAX
+                } else {
+                    initial_us.saturating_mul(1 << retry)
+                };
+                if scaled > max_us { max_us } else { scaled }
+            }
+        }
+    }
+}
+
+/// Number of retries and the backoff applied between them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RetryPolicy {
+    /// Retries attempted after the first failure of a transfer.
+    pub retries: u8,
+    /// Delay inserted before each retry.
+    pub backoff: Backoff,
+}
+
+impl RetryPolicy {
+    /// Creates a policy with `retries` retries and the given backoff.
+    pub const fn new(retries: u8, backoff: Backoff) -> Self {
+        Self { retries, backoff }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three retries starting at 100 µs, doubling up to 1 ms.
+    fn default() -> Self {
+        Self::new(
+            3,
+            Backoff::Exponential {
+                initial_us: 100,
+                max_us: 1_000,
+            },
+        )
+    }
+}
+
+/// Counters kept by [`RetryInterface`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct RetryStats {
+    /// Transfers re-issued after a transient error.
+    pub retries: u32,
+    /// Transfers that failed for good, either on a permanent error or after the last retry.
+    pub failures: u32,
+}
+
+/// Decides whether a bus error is worth retrying.
+pub trait ErrorClassifier<E> {
+    /// Returns `true` when the transfer may succeed if issued again.
+    fn is_transient(&self, error: &E) -> bool;
+}
+
+impl<E, F> ErrorClassifier<E> for F
+where
+    F: Fn(&E) -> bool,
+{
+    fn is_transient(&self, error: &E) -> bool {
+        self(error)
+    }
+}
+
+/// Classifies SPI errors by [`embedded_hal::spi::ErrorKind`].
+///
+/// Overruns, chip-select faults and unspecified errors are retried. Mode and frame format
+/// faults point at a bus misconfiguration and fail immediately.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SpiClassifier;
+
+impl<E: embedded_hal::spi::Error> ErrorClassifier<E> for SpiClassifier {
+    fn is_transient(&self, error: &E) -> bool {
+        use embedded_hal::spi::ErrorKind;
+
+        matches!(
+            error.kind(),
+            ErrorKind::Overrun | ErrorKind::ChipSelectFault | ErrorKind::Other
+        )
+    }
+}
+
+/// Classifies I2C errors by [`embedded_hal::i2c::ErrorKind`].
+///
+/// Bus errors, lost arbitration, missing acknowledges, overruns and unspecified errors are
+/// retried.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct I2cClassifier;
+
+impl<E: embedded_hal::i2c::Error> ErrorClassifier<E> for I2cClassifier {
+    fn is_transient(&self, error: &E) -> bool {
+        use embedded_hal::i2c::ErrorKind;
+
+        matches!(
+            error.kind(),
+            ErrorKind::Bus
+                | ErrorKind::ArbitrationLoss
+                | ErrorKind::NoAcknowledge(_)
+                | ErrorKind::Overrun
+                | ErrorKind::Other
+        )
+    }
+}
+
+/// Bus interface wrapper that retries transient errors with a backoff delay.
+///
+/// Each register access is retried as a whole, so a failed burst is re-issued from its first
+/// register. Reads of `FIFO_DATA` or clear-on-read status registers may lose data if the
+/// failed transfer reached the device; size the retry count accordingly.
+pub struct RetryInterface<IFACE, D, C = SpiClassifier> {
+    inner: IFACE,
+    delay: D,
+    policy: RetryPolicy,
+    classifier: C,
+    stats: RetryStats,
+}
+
+impl<IFACE, D> RetryInterface<IFACE, D> {
+    /// Wraps `inner`, classifying errors with [`SpiClassifier`].
+    pub const fn new(inner: IFACE, delay: D, policy: RetryPolicy) -> Self {
+        Self::with_classifier(inner, delay, policy, SpiClassifier)
+    }
+}
+
+impl<IFACE, D, C> RetryInterface<IFACE, D, C> {
+    /// Wraps `inner`, classifying errors with `classifier`.
+    pub const fn with_classifier(
+        inner: IFACE,
+        delay: D,
+        policy: RetryPolicy,
+        classifier: C,
+    ) -> Self {
+        Self {
+            inner,
+            delay,
+            policy,
+            classifier,
+            stats: RetryStats {
+                retries: 0,
+                failures: 0,
+            },
+        }
+    }
+
+    /// Returns the active retry policy.
+    pub fn policy(&self) -> RetryPolicy {
+        self.policy
+    }
+
+    /// Replaces the retry policy.
+    pub fn set_policy(&mut self, policy: RetryPolicy) {
+        self.policy = policy;
+    }
+
+    /// Returns the retry and failure counters.
+    pub fn stats(&self) -> RetryStats {
+        self.stats
+    }
+
+    /// Clears the retry and failure counters.
+    pub fn reset_stats(&mut self) {
+        self.stats = RetryStats::default();
+    }
+
+    /// Provides shared access to the wrapped interface.
+    pub fn inner(&self) -> &IFACE {
+        &self.inner
+    }
+
+    /// Provides mutable access to the wrapped interface.
+    pub fn inner_mut(&mut self) -> &mut IFACE {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the inner interface and delay.
+    pub fn release(self) -> (IFACE, D) {
+        (self.inner, self.delay)
+    }
+
+    // Records a failed attempt and returns the backoff before the next one, if any.
+    fn backoff<E>(&mut self, error: &E, attempt: &mut u8) -> Option<u32>
+    where
+        C: ErrorClassifier<E>,
+    {
+        if *attempt >= self.policy.retries || !self.classifier.is_transient(error) {
+            self.stats.failures = self.stats.failures.saturating_add(1);
+            return None;
+        }
+
+        let delay_us = self.policy.backoff.delay_us(*attempt);
+        *attempt += 1;
+        self.stats.retries = self.stats.retries.saturating_add(1);
+        Some(delay_us)
+    }
+}
+
+// Re-evaluates `$call` until it succeeds or the policy gives up, running `$wait` in between.
+macro_rules! retry {
+    ($self:ident, $call:expr, |$delay_us:ident| $wait:expr) => {{
+        let mut attempt = 0;
+        loop {
+            match $call {
+                Ok(value) => break Ok(value),
+                Err(error) => match $self.backoff(&error, &mut attempt) {
+                    Some($delay_us) => $wait,
+                    None => break Err(error),
+                },
+            }
+        }
+    }};
+}
+
+impl<IFACE, D, C> Adxl372Interface for RetryInterface<IFACE, D, C>
+where
+    IFACE: Adxl372Interface,
+    D: embedded_hal::delay::DelayNs,
+    C: ErrorClassifier<IFACE::Error>,
+{
+    type Error = IFACE::Error;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        retry!(self, self.inner.write_register(register, value), |us| {
+            self.delay.delay_us(us)
+        })
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        retry!(self, self.inner.read_register(register), |us| {
+            self.delay.delay_us(us)
+        })
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        retry!(self, self.inner.read_many(register, buf), |us| {
+            self.delay.delay_us(us)
+        })
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        retry!(self, self.inner.write_many(register, data), |us| {
+            self.delay.delay_us(us)
+        })
+    }
+}
+
+#[cfg(feature = "async")]
+impl<IFACE, D, C> Adxl372InterfaceAsync for RetryInterface<IFACE, D, C>
+where
+    IFACE: Adxl372InterfaceAsync,
+    D: embedded_hal_async::delay::DelayNs,
+    C: ErrorClassifier<IFACE::Error>,
+{
+    type Error = IFACE::Error;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        retry!(
+            self,
+            self.inner.write_register(register, value).await,
+            |us| { self.delay.delay_us(us).await }
+        )
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        retry!(self, self.inner.read_register(register).await, |us| {
+            self.delay.delay_us(us).await
+        })
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        retry!(self, self.inner.read_many(register, buf).await, |us| {
+            self.delay.delay_us(us).await
+        })
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        retry!(self, self.inner.write_many(register, data).await, |us| {
+            self.delay.delay_us(us).await
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Backoff, RetryInterface, RetryPolicy, RetryStats};
+    use crate::interface::Adxl372Interface;
+    use embedded_hal::spi::ErrorKind;
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct FakeError(ErrorKind);
+
+    impl embedded_hal::spi::Error for FakeError {
+        fn kind(&self) -> ErrorKind {
+            self.0
+        }
+    }
+
+    // Fails the first `failures` transfers with `kind`, then returns `value`.
+    struct FlakyInterface {
+        failures: usize,
+        kind: ErrorKind,
+        value: u8,
+        calls: usize,
+    }
+
+    impl FlakyInterface {
+        fn new(failures: usize, kind: ErrorKind) -> Self {
+            Self {
+                failures,
+                kind,
+                value: 0xAD,
+                calls: 0,
+            }
+        }
+
+        fn transfer(&mut self) -> Result<(), FakeError> {
+            self.calls += 1;
+            if self.calls <= self.failures {
+                Err(FakeError(self.kind))
+            } else {
+                Ok(())
+            }
+        }
+    }
+
+    impl Adxl372Interface for FlakyInterface {
+        type Error = FakeError;
+
+        fn write_register(&mut self, _register: u8, _value: u8) -> Result<(), Self::Error> {
+            self.transfer()
+        }
+
+        fn read_register(&mut self, _register: u8) -> Result<u8, Self::Error> {
+            self.transfer().map(|()| self.value)
+        }
+
+        fn read_many(&mut self, _register: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.transfer()?;
+            buf.fill(self.value);
+            Ok(())
+        }
+
+        fn write_many(&mut self, _register: u8, _data: &[u8]) -> Result<(), Self::Error> {
+            self.transfer()
+        }
+    }
+
+    fn policy(retries: u8) -> RetryPolicy {
+        RetryPolicy::new(retries, Backoff::Fixed { us: 10 })
+    }
+
+    /// Ensures transient errors are retried until the transfer succeeds.
+    #[test]
+    fn transient_errors_are_retried() {
+        let flaky = FlakyInterface::new(2, ErrorKind::Overrun);
+        let mut retry = RetryInterface::new(flaky, NoopDelay::new(), policy(3));
+
+        assert_eq!(retry.read_register(0x00), Ok(0xAD));
+        assert_eq!(
+            retry.stats(),
+            RetryStats {
+                retries: 2,
+                failures: 0
+            }
+        );
+        assert_eq!(retry.inner().calls, 3);
+    }
+
+    /// Checks exhausted retries and permanent errors are counted as failures.
+    #[test]
+    fn permanent_and_exhausted_errors_fail() {
+        let flaky = FlakyInterface::new(usize::MAX, ErrorKind::Other);
+        let mut retry = RetryInterface::new(flaky, NoopDelay::new(), policy(2));
+        let mut buf = [0u8; 2];
+        assert_eq!(
+            retry.read_many(0x08, &mut buf),
+            Err(FakeError(ErrorKind::Other))
+        );
+        assert_eq!(retry.inner().calls, 3);
+
+        let flaky = FlakyInterface::new(1, ErrorKind::ModeFault);
+        let mut retry = RetryInterface::new(flaky, NoopDelay::new(), policy(2));
+        assert_eq!(
+            retry.write_register(0x3F, 0x03),
+            Err(FakeError(ErrorKind::ModeFault))
+        );
+        assert_eq!(
+            retry.stats(),
+            RetryStats {
+                retries: 0,
+                failures: 1
+            }
+        );
+
+        // A custom classifier overrides the SPI error kinds.
+        let flaky = FlakyInterface::new(1, ErrorKind::ModeFault);
+        let mut retry =
+            RetryInterface::with_classifier(flaky, NoopDelay::new(), policy(2), |_: &FakeError| {
+                true
+            });
+        assert_eq!(retry.write_many(0x20, &[0, 0, 0]), Ok(()));
+    }
+
+    /// Verifies the exponential backoff doubles and saturates at its cap.
+    #[test]
+    fn exponential_backoff_is_capped() {
+        let backoff = Backoff::Exponential {
+            initial_us: 100,
+            max_us: 500,
+        };
+        assert_eq!(backoff.delay_us(0), 100);
+        assert_eq!(backoff.delay_us(2), 400);
+        assert_eq!(backoff.delay_us(3), 500);
+        assert_eq!(backoff.delay_us(40), 500);
+        assert_eq!(Backoff::Fixed { us: 7 }.delay_us(9), 7);
+    }
+}
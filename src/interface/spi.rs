@@ -3,6 +3,8 @@
 use embedded_hal::spi::{Operation, SpiDevice};
 
 use super::Adxl372Interface;
+#[cfg(feature = "async")]
+use super::Adxl372InterfaceAsync;
 
 /// SPI-based interface implementation for the ADXL372 driver.
 pub struct SpiInterface<SPI> {
@@ -72,6 +74,56 @@ where
     }
 }
 
+#[cfg(feature = "async")]
+impl<SPI> Adxl372InterfaceAsync for SpiInterface<SPI>
+where
+    SPI: embedded_hal_async::spi::SpiDevice,
+{
+    type Error = SPI::Error;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372InterfaceAsync::write_many(self, register, core::slice::from_ref(&value)).await
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        Adxl372InterfaceAsync::read_many(self, register, &mut value).await?;
+        Ok(value[0])
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        if buf.is_empty() {
+            return Ok(());
+        }
+
+        let command = [Self::command_byte(register, true)];
+        let mut operations = [Operation::Write(&command), Operation::Read(buf)];
+        embedded_hal_async::spi::SpiDevice::transaction(&mut self.spi, &mut operations).await
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        if data.is_empty() {
+            return Ok(());
+        }
+
+        let command = [Self::command_byte(register, false)];
+        let mut operations = [Operation::Write(&command), Operation::Write(data)];
+        embedded_hal_async::spi::SpiDevice::transaction(&mut self.spi, &mut operations).await
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::SpiInterface;
@@ -0,0 +1,781 @@
+//! Bus transaction recording and replay, layered over any [`Adxl372Interface`].
+//!
+//! [`RecordingInterface`] forwards every access to the wrapped bus and appends it, with its
+//! payload and outcome, to a caller-supplied byte buffer. The buffer holds the trace in its
+//! binary format directly, so a capture taken on a field device can be dumped as-is and fed
+//! to [`ReplayInterface`] on the host, where the driver is checked against the recorded
+//! traffic.
+//!
+//! # Trace format
+//!
+//! A trace starts with the four bytes [`TRACE_MAGIC`], followed by one record per access:
+//!
+//! | Bytes | Content                                                               |
+//! |-------|-----------------------------------------------------------------------|
+//! | 1     | [`Operation`] code in bits 1:0, bit 7 set when the access failed     |
+//! | 1     | Register address                                                      |
+//! | 2     | Transfer length, little endian                                        |
+//! | n     | Payload: the data written, or the data read when the read succeeded |
+//!
+//! Failed reads carry no payload. Bus error values are not stored; replay reports them as
+//! [`ReplayError::RecordedFailure`].
+//!
+//! # Examples
+//!
+//! ```rust
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::interface::trace::{ReplayInterface, Trace};
+//!
+//! // Trace of `check_ids`: one burst read of the four identification registers.
+//! let bytes = [b'A', b'X', b'L', 1, 0x01, 0x00, 4, 0, 0xAD, 0x1D, 0xFA, 0x02];
+//! let trace = Trace::parse(&bytes).unwrap();
+//!
+//! let mut device = Adxl372::new(ReplayInterface::new(trace), Config::default());
+//! assert_eq!(device.check_ids().unwrap(), 0x02);
+//! assert!(device.interface_mut().is_finished());
+//! ```
+
+use core::fmt;
+
+use super::Adxl372Interface;
+#[cfg(feature = "async")]
+use super::Adxl372InterfaceAsync;
+
+/// Magic bytes and format version at the start of every trace.
+pub const TRACE_MAGIC: [u8; 4] = [b'A', b'X', b'L', 1];
+
+const HEADER_LEN: usize = 4;
+const FAILED_FLAG: u8 = 0x80;
+const OPERATION_MASK: u8 = 0x03;
+
+/// Kind of bus access stored in a trace record.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum Operation {
+    /// [`Adxl372Interface::read_register`].
+    ReadRegister = 0,
+    /// [`Adxl372Interface::read_many`].
+    ReadMany = 1,
+    /// [`Adxl372Interface::write_register`].
+    WriteRegister = 2,
+    /// [`Adxl372Interface::write_many`].
+    WriteMany = 3,
+}
+
+impl Operation {
+    /// Returns `true` for the write operations.
+    pub const fn is_write(self) -> bool {
+        matches!(self, Self::WriteRegister | Self::WriteMany)
+    }
+
+    const fn from_code(code: u8) -> Self {
+        match code & OPERATION_MASK {
+            0 => Self::ReadRegister,
+            1 => Self::ReadMany,
+            2 => Self::WriteRegister,
+            _ => Self::WriteMany,
+        }
+    }
+}
+
+/// One recorded bus access.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Record<'a> {
+    /// Kind of access.
+    pub operation: Operation,
+    /// First register address.
+    pub register: u8,
+    /// Number of bytes transferred.
+    pub len: usize,
+    /// Data written, or data read; empty for a failed read.
+    pub payload: &'a [u8],
+    /// `true` when the bus reported an error.
+    pub failed: bool,
+}
+
+impl Record<'_> {
+    fn encoded_len(&self) -> usize {
+        4 + self.payload.len()
+    }
+}
+
+/// Errors reported by [`Trace::parse`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum TraceError {
+    /// The data does not start with [`TRACE_MAGIC`].
+    BadHeader,
+    /// The record starting at this byte offset is cut short.
+    Truncated {
+        /// Byte offset of the incomplete record.
+        offset: usize,
+    },
+    /// The record starting at this byte offset uses undefined flag bits.
+    InvalidRecord {
+        /// Byte offset of the invalid record.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for TraceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::BadHeader => write!(f, "missing trace header"),
+            Self::Truncated { offset } => write!(f, "record at byte {offset} is truncated"),
+            Self::InvalidRecord { offset } => write!(f, "record at byte {offset} is invalid"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TraceError {}
+
+/// Validated view over an encoded trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Trace<'a> {
+    bytes: &'a [u8],
+}
+
+impl<'a> Trace<'a> {
+    /// Checks the header and every record of `bytes`.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, TraceError> {
+        let records = bytes
+            .strip_prefix(&TRACE_MAGIC)
+            .ok_or(TraceError::BadHeader)?;
+
+        let mut offset = 0;
+        while offset < records.len() {
+            let record = decode(&records[offset..]).map_err(|err| match err {
+                DecodeError::Truncated => TraceError::Truncated {
+                    offset: HEADER_LEN + offset,
+                },
+                DecodeError::Invalid => TraceError::InvalidRecord {
+                    offset: HEADER_LEN + offset,
+                },
+            })?;
+            offset += record.encoded_len();
+        }
+        Ok(Self { bytes })
+    }
+
+    /// Returns the encoded trace, header included.
+    pub fn as_bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Iterates over the recorded accesses.
+    pub fn records(&self) -> Records<'a> {
+        Records {
+            remaining: self.bytes.get(HEADER_LEN..).unwrap_or(&[]),
+        }
+    }
+
+    /// Returns the number of recorded accesses.
+    pub fn len(&self) -> usize {
+        self.records().count()
+    }
+
+    /// Returns `true` when the trace holds no accesses.
+    pub fn is_empty(&self) -> bool {
+        self.bytes.len() <= HEADER_LEN
+    }
+
+    /// Writes the encoded trace to `writer`.
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        writer.write_all(self.as_bytes())
+    }
+
+    /// Saves the encoded trace to the file at `path`.
+    #[cfg(feature = "std")]
+    pub fn save<P: AsRef<std::path::Path>>(&self, path: P) -> std::io::Result<()> {
+        std::fs::write(path, self.as_bytes())
+    }
+}
+
+/// Loads and validates a trace file saved with [`Trace::save`].
+///
+/// Parse the returned bytes with [`Trace::parse`] to replay them.
+#[cfg(feature = "std")]
+pub fn load<P: AsRef<std::path::Path>>(path: P) -> std::io::Result<std::vec::Vec<u8>> {
+    let bytes = std::fs::read(path)?;
+    Trace::parse(&bytes)
+        .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+    Ok(bytes)
+}
+
+/// Iterator over the records of a [`Trace`].
+#[derive(Debug, Clone)]
+pub struct Records<'a> {
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for Records<'a> {
+    type Item = Record<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let record = decode(self.remaining).ok()?;
+        self.remaining = &self.remaining[record.encoded_len()..];
+        Some(record)
+    }
+}
+
+enum DecodeError {
+    Truncated,
+    Invalid,
+}
+
+fn decode(bytes: &[u8]) -> Result<Record<'_>, DecodeError> {
+    let [code, register, len_lo, len_hi, rest @ ..] = bytes else {
+        return Err(DecodeError::Truncated);
+    };
+    if code & !(FAILED_FLAG | OPERATION_MASK) != 0 {
+        return Err(DecodeError::Invalid);
+    }
+
+    let operation = Operation::from_code(*code);
+    let failed = code & FAILED_FLAG != 0;
+    let len = usize::from(u16::from_le_bytes([*len_lo, *len_hi]));
+    let single = matches!(
+        operation,
+        Operation::ReadRegister | Operation::WriteRegister
+    );
+    if single && len != 1 {
+        return Err(DecodeError::Invalid);
+    }
+
+    let payload_len = if failed && !operation.is_write() {
+        0
+    } else {
+        len
+    };
+    let payload = rest.get(..payload_len).ok_or(DecodeError::Truncated)?;
+    Ok(Record {
+        operation,
+        register: *register,
+        len,
+        payload,
+        failed,
+    })
+}
+
+/// Bus interface wrapper that appends every access to a trace buffer.
+///
+/// Recording stops at the first access that no longer fits, so the stored trace is always a
+/// complete prefix of the bus traffic; [`dropped`](Self::dropped) counts the accesses missed
+/// after that point.
+pub struct RecordingInterface<'a, IFACE> {
+    inner: IFACE,
+    buf: &'a mut [u8],
+    len: usize,
+    dropped: u32,
+}
+
+impl<'a, IFACE> RecordingInterface<'a, IFACE> {
+    /// Wraps `inner`, recording into `buf`.
+    ///
+    /// The header takes the first four bytes of `buf`; a shorter buffer records nothing.
+    pub fn new(inner: IFACE, buf: &'a mut [u8]) -> Self {
+        let mut recorder = Self {
+            inner,
+            buf,
+            len: 0,
+            dropped: 0,
+        };
+        recorder.clear();
+        recorder
+    }
+
+    /// Discards the recorded accesses and the dropped counter.
+    pub fn clear(&mut self) {
+        self.dropped = 0;
+        self.len = match self.buf.get_mut(..HEADER_LEN) {
+            Some(header) => {
+                header.copy_from_slice(&TRACE_MAGIC);
+                HEADER_LEN
+            }
+            None => 0,
+        };
+    }
+
+    /// Returns the trace recorded so far.
+    pub fn trace(&self) -> Trace<'_> {
+        Trace {
+            bytes: &self.buf[..self.len],
+        }
+    }
+
+    /// Returns the number of accesses missed because the buffer was full.
+    pub fn dropped(&self) -> u32 {
+        self.dropped
+    }
+
+    /// Provides shared access to the wrapped interface.
+    pub fn inner(&self) -> &IFACE {
+        &self.inner
+    }
+
+    /// Provides mutable access to the wrapped interface.
+    pub fn inner_mut(&mut self) -> &mut IFACE {
+        &mut self.inner
+    }
+
+    /// Consumes the wrapper and returns the inner interface and the encoded trace.
+    pub fn release(self) -> (IFACE, &'a [u8]) {
+        let buf: &'a [u8] = self.buf;
+        (self.inner, &buf[..self.len])
+    }
+
+    fn record(&mut self, operation: Operation, register: u8, data: &[u8], failed: bool) {
+        let payload = if failed && !operation.is_write() {
+            &[][..]
+        } else {
+            data
+        };
+        let end = self.len + 4 + payload.len();
+
+        let len = u16::try_from(data.len()).ok();
+        let fits = self.len >= HEADER_LEN && self.dropped == 0 && end <= self.buf.len();
+        let Some(len) = len.filter(|_| fits) else {
+            self.dropped = self.dropped.saturating_add(1);
+            return;
+        };
+
+        let code = operation as u8 | if failed { FAILED_FLAG } else { 0 };
+        let [len_lo, len_hi] = len.to_le_bytes();
+        self.buf[self.len..self.len + 4].copy_from_slice(&[code, register, len_lo, len_hi]);
+        self.buf[self.len + 4..end].copy_from_slice(payload);
+        self.len = end;
+    }
+}
+
+impl<IFACE> Adxl372Interface for RecordingInterface<'_, IFACE>
+where
+    IFACE: Adxl372Interface,
+{
+    type Error = IFACE::Error;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_register(register, value);
+        self.record(
+            Operation::WriteRegister,
+            register,
+            &[value],
+            result.is_err(),
+        );
+        result
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let result = self.inner.read_register(register);
+        let value = *result.as_ref().unwrap_or(&0);
+        self.record(Operation::ReadRegister, register, &[value], result.is_err());
+        result
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.read_many(register, buf);
+        self.record(Operation::ReadMany, register, buf, result.is_err());
+        result
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_many(register, data);
+        self.record(Operation::WriteMany, register, data, result.is_err());
+        result
+    }
+}
+
+#[cfg(feature = "async")]
+impl<IFACE> Adxl372InterfaceAsync for RecordingInterface<'_, IFACE>
+where
+    IFACE: Adxl372InterfaceAsync,
+{
+    type Error = IFACE::Error;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_register(register, value).await;
+        self.record(
+            Operation::WriteRegister,
+            register,
+            &[value],
+            result.is_err(),
+        );
+        result
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let result = self.inner.read_register(register).await;
+        let value = *result.as_ref().unwrap_or(&0);
+        self.record(Operation::ReadRegister, register, &[value], result.is_err());
+        result
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.read_many(register, buf).await;
+        self.record(Operation::ReadMany, register, buf, result.is_err());
+        result
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        let result = self.inner.write_many(register, data).await;
+        self.record(Operation::WriteMany, register, data, result.is_err());
+        result
+    }
+}
+
+/// Access made during replay that does not match the trace.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct Divergence {
+    /// Index of the record the access was checked against; equal to the trace length when
+    /// the trace was already exhausted.
+    pub index: usize,
+    /// Kind of access the driver made.
+    pub operation: Operation,
+    /// First register the driver accessed.
+    pub register: u8,
+}
+
+/// Errors returned by [`ReplayInterface`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub enum ReplayError {
+    /// The driver's access differs from the recorded one, or the trace is exhausted.
+    Diverged(Divergence),
+    /// The access matches a record whose bus transfer failed.
+    RecordedFailure {
+        /// Index of the failed record.
+        index: usize,
+    },
+}
+
+/// Bus interface that answers accesses from a recorded trace.
+///
+/// Each access must match the next record in operation, register, length and, for writes,
+/// payload. Reads return the recorded data. The first mismatch is kept in
+/// [`divergence`](Self::divergence) and every later access fails with it.
+pub struct ReplayInterface<'a> {
+    records: Records<'a>,
+    index: usize,
+    divergence: Option<Divergence>,
+}
+
+impl<'a> ReplayInterface<'a> {
+    /// Creates a replayer positioned at the first record of `trace`.
+    pub fn new(trace: Trace<'a>) -> Self {
+        Self {
+            records: trace.records(),
+            index: 0,
+            divergence: None,
+        }
+    }
+
+    /// Returns the number of records served so far.
+    pub fn position(&self) -> usize {
+        self.index
+    }
+
+    /// Returns `true` when every record has been served without divergence.
+    pub fn is_finished(&self) -> bool {
+        self.divergence.is_none() && self.records.remaining.is_empty()
+    }
+
+    /// Returns the first divergence from the trace, if any.
+    pub fn divergence(&self) -> Option<Divergence> {
+        self.divergence
+    }
+
+    // Matches an access against the next record and returns that record.
+    fn next(
+        &mut self,
+        operation: Operation,
+        register: u8,
+        len: usize,
+        written: Option<&[u8]>,
+    ) -> Result<Record<'a>, ReplayError> {
+        if let Some(divergence) = self.divergence {
+            return Err(ReplayError::Diverged(divergence));
+        }
+
+        let index = self.index;
+        let matches = |record: &Record<'_>| {
+            record.operation == operation
+                && record.register == register
+                && record.len == len
+                && written.is_none_or(|data| data == record.payload)
+        };
+        match self.records.clone().next().filter(matches) {
+            Some(record) => {
+                self.records.next();
+                self.index += 1;
+                if record.failed {
+                    Err(ReplayError::RecordedFailure { index })
+                } else {
+                    Ok(record)
+                }
+            }
+            None => {
+                let divergence = Divergence {
+                    index,
+                    operation,
+                    register,
+                };
+                self.divergence = Some(divergence);
+                Err(ReplayError::Diverged(divergence))
+            }
+        }
+    }
+}
+
+impl Adxl372Interface for ReplayInterface<'_> {
+    type Error = ReplayError;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        self.next(Operation::WriteRegister, register, 1, Some(&[value]))
+            .map(|_| ())
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        self.next(Operation::ReadRegister, register, 1, None)
+            .map(|record| record.payload[0])
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        let record = self.next(Operation::ReadMany, register, buf.len(), None)?;
+        buf.copy_from_slice(record.payload);
+        Ok(())
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        self.next(Operation::WriteMany, register, data.len(), Some(data))
+            .map(|_| ())
+    }
+}
+
+#[cfg(feature = "async")]
+impl Adxl372InterfaceAsync for ReplayInterface<'_> {
+    type Error = ReplayError;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_register(self, register, value)
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        Adxl372Interface::read_register(self, register)
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::read_many(self, register, buf)
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_many(self, register, data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        Divergence, Operation, RecordingInterface, ReplayError, ReplayInterface, TRACE_MAGIC,
+        Trace, TraceError,
+    };
+    use crate::config::Config;
+    use crate::device::Adxl372;
+    use crate::error::Error;
+    use crate::interface::Adxl372Interface;
+    use crate::registers::{REG_DEVID_AD, REG_TIMING, Timing};
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    struct BusFault;
+
+    // Register file answering the identification registers; fails every access when `broken`.
+    struct FakeBus {
+        registers: [u8; 0x43],
+        broken: bool,
+    }
+
+    impl FakeBus {
+        fn new() -> Self {
+            let mut registers = [0u8; 0x43];
+            registers[..4].copy_from_slice(&[0xAD, 0x1D, 0xFA, 0x02]);
+            Self {
+                registers,
+                broken: false,
+            }
+        }
+
+        fn check(&self) -> Result<(), BusFault> {
+            if self.broken { Err(BusFault) } else { Ok(()) }
+        }
+    }
+
+    impl Adxl372Interface for FakeBus {
+        type Error = BusFault;
+
+        fn write_register(&mut self, register: u8, value: u8) -> Result<(), Self::Error> {
+            self.write_many(register, &[value])
+        }
+
+        fn read_register(&mut self, register: u8) -> Result<u8, Self::Error> {
+            let mut value = [0u8; 1];
+            self.read_many(register, &mut value)?;
+            Ok(value[0])
+        }
+
+        fn read_many(&mut self, register: u8, buf: &mut [u8]) -> Result<(), Self::Error> {
+            self.check()?;
+            let start = usize::from(register);
+            buf.copy_from_slice(&self.registers[start..start + buf.len()]);
+            Ok(())
+        }
+
+        fn write_many(&mut self, register: u8, data: &[u8]) -> Result<(), Self::Error> {
+            self.check()?;
+            let start = usize::from(register);
+            self.registers[start..start + data.len()].copy_from_slice(data);
+            Ok(())
+        }
+    }
+
+    /// Records driver traffic and replays it, then flags a driver that deviates.
+    #[test]
+    fn recorded_trace_replays_and_detects_divergence() {
+        let timing = Timing::from(0x60);
+        let mut buf = [0u8; 64];
+        let mut device = Adxl372::new(
+            RecordingInterface::new(FakeBus::new(), &mut buf),
+            Config::default(),
+        );
+        device.check_ids().unwrap();
+        device.write_reg(timing).unwrap();
+        assert_eq!(device.read_reg::<Timing>().unwrap(), timing);
+        let (interface, _) = device.release();
+        let (_, bytes) = interface.release();
+
+        let trace = Trace::parse(bytes).unwrap();
+        assert_eq!(trace.len(), 3);
+        let record = trace.records().nth(1).unwrap();
+        assert_eq!(record.operation, Operation::WriteRegister);
+        assert_eq!((record.register, record.payload), (REG_TIMING, &[0x60][..]));
+
+        let mut device = Adxl372::new(ReplayInterface::new(trace), Config::default());
+        device.check_ids().unwrap();
+        device.write_reg(timing).unwrap();
+        assert_eq!(device.read_reg::<Timing>().unwrap(), timing);
+        assert!(device.interface_mut().is_finished());
+
+        let mut device = Adxl372::new(ReplayInterface::new(trace), Config::default());
+        device.check_ids().unwrap();
+        let divergence = Divergence {
+            index: 1,
+            operation: Operation::WriteRegister,
+            register: REG_TIMING,
+        };
+        assert_eq!(
+            device.write_reg(Timing::from(0x40)),
+            Err(Error::Interface(ReplayError::Diverged(divergence)))
+        );
+        assert_eq!(device.interface_mut().divergence(), Some(divergence));
+        assert!(device.read_reg::<Timing>().is_err());
+        assert_eq!(device.interface_mut().position(), 1);
+    }
+
+    /// Ensures failures are recorded and replayed, and recording stops once the buffer is full.
+    #[test]
+    fn failures_and_full_buffers_are_recorded() {
+        let mut buf = [0u8; 13];
+        let mut recorder = RecordingInterface::new(FakeBus::new(), &mut buf);
+        recorder.inner_mut().broken = true;
+        let mut ids = [0u8; 4];
+        assert_eq!(recorder.read_many(REG_DEVID_AD, &mut ids), Err(BusFault));
+        recorder.inner_mut().broken = false;
+        recorder.read_many(REG_DEVID_AD, &mut ids).unwrap();
+        recorder.read_register(REG_TIMING).unwrap();
+        assert_eq!(recorder.dropped(), 2);
+        assert_eq!(recorder.trace().len(), 1);
+
+        let (_, bytes) = recorder.release();
+        assert_eq!(bytes, &[b'A', b'X', b'L', 1, 0x81, 0x00, 4, 0]);
+        let mut replay = ReplayInterface::new(Trace::parse(bytes).unwrap());
+        assert_eq!(
+            replay.read_many(REG_DEVID_AD, &mut ids),
+            Err(ReplayError::RecordedFailure { index: 0 })
+        );
+        assert!(replay.is_finished());
+        assert!(matches!(
+            replay.read_register(REG_TIMING),
+            Err(ReplayError::Diverged(Divergence { index: 1, .. }))
+        ));
+    }
+
+    /// Verifies malformed traces are rejected with the offending offset.
+    #[test]
+    fn parse_rejects_malformed_traces() {
+        assert_eq!(Trace::parse(&[0, 1, 2, 3]), Err(TraceError::BadHeader));
+        assert!(Trace::parse(&TRACE_MAGIC).unwrap().is_empty());
+
+        let mut bytes = [0u8; 10];
+        bytes[..4].copy_from_slice(&TRACE_MAGIC);
+        bytes[4..].copy_from_slice(&[0x02, 0x3D, 1, 0, 0x60, 0x01]);
+        assert_eq!(
+            Trace::parse(&bytes),
+            Err(TraceError::Truncated { offset: 9 })
+        );
+        assert_eq!(
+            Trace::parse(&bytes[..8]),
+            Err(TraceError::Truncated { offset: 4 })
+        );
+        bytes[4] = 0x42;
+        assert_eq!(
+            Trace::parse(&bytes),
+            Err(TraceError::InvalidRecord { offset: 4 })
+        );
+    }
+
+    /// Round-trips a trace through a file.
+    #[cfg(feature = "std")]
+    #[test]
+    fn trace_files_round_trip() {
+        let mut buf = [0u8; 32];
+        let mut recorder = RecordingInterface::new(FakeBus::new(), &mut buf);
+        recorder.write_register(REG_TIMING, 0x60).unwrap();
+
+        let path = std::env::temp_dir().join("adxl372-trace-round-trip.bin");
+        recorder.trace().save(&path).unwrap();
+        let bytes = super::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(Trace::parse(&bytes).unwrap(), recorder.trace());
+
+        std::fs::write(&path, [0u8; 4]).unwrap();
+        let err = super::load(&path).unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+}
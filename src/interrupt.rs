@@ -0,0 +1,734 @@
+//! INT1/INT2 interrupt pin mapping and interrupt-driven acquisition.
+//!
+//! Each pin has its own `INTx_MAP` register selecting which status events assert it and the
+//! pin polarity. [`InterruptMap`] is the typed view used by [`Config`]
+//! and returned when reading the mapping back from the device.
+//!
+//! [`Adxl372WithIrq`] (and `Adxl372AsyncWithIrq` with the `async` feature) takes ownership of
+//! the wired pins and blocks until a mapped event fires, decoding `STATUS`/`STATUS2` to confirm
+//! which event asserted the pin.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use adxl372::config::Config;
+//! use adxl372::interrupt::InterruptMap;
+//! use adxl372::params::InterruptPolarity;
+//!
+//! let config = Config::new()
+//!     .int1_map(InterruptMap {
+//!         fifo_ready: true,
+//!         fifo_overrun: true,
+//!         ..InterruptMap::NONE
+//!     })
+//!     .int2_map(InterruptMap {
+//!         activity: true,
+//!         polarity: InterruptPolarity::ActiveLow,
+//!         ..InterruptMap::NONE
+//!     })
+//!     .build();
+//! # let _ = config;
+//! ```
+
+use embedded_hal::digital::{ErrorKind, ErrorType, InputPin};
+#[cfg(feature = "async")]
+use embedded_hal_async::digital::Wait;
+
+use crate::config::Config;
+use crate::device::{Adxl372, StatusSnapshot};
+use crate::error::{Error, Result};
+use crate::interface::Adxl372Interface;
+use crate::params::InterruptPolarity;
+use crate::registers::InterruptMapRegister;
+
+#[cfg(feature = "async")]
+use crate::asynch::Adxl372Async;
+#[cfg(feature = "async")]
+use crate::interface::Adxl372InterfaceAsync;
+
+/// Events routed to a single interrupt pin and the pin polarity.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InterruptMap {
+    /// Assert on `DATA_RDY`.
+    pub data_ready: bool,
+    /// Assert on `FIFO_RDY` (watermark reached).
+    pub fifo_ready: bool,
+    /// Assert on `FIFO_FULL`.
+    pub fifo_full: bool,
+    /// Assert on `FIFO_OVR`.
+    pub fifo_overrun: bool,
+    /// Assert on inactivity events.
+    pub inactivity: bool,
+    /// Assert on activity events.
+    pub activity: bool,
+    /// Assert while the device is awake (link/loop modes).
+    pub awake: bool,
+    /// Electrical polarity of the pin.
+    pub polarity: InterruptPolarity,
+}
+
+impl InterruptMap {
+    /// No event mapped, active-high polarity (reset state).
+    pub const NONE: Self = Self {
+        data_ready: false,
+        fifo_ready: false,
+        fifo_full: false,
+        fifo_overrun: false,
+        inactivity: false,
+        activity: false,
+        awake: false,
+        polarity: InterruptPolarity::ActiveHigh,
+    };
+
+    /// Returns `true` when at least one event is routed to the pin.
+    pub const fn any_event(&self) -> bool {
+        self.data_ready
+            || self.fifo_ready
+            || self.fifo_full
+            || self.fifo_overrun
+            || self.inactivity
+            || self.activity
+            || self.awake
+    }
+}
+
+impl From<InterruptMapRegister> for InterruptMap {
+    fn from(value: InterruptMapRegister) -> Self {
+        Self {
+            data_ready: value.data_ready(),
+            fifo_ready: value.fifo_ready(),
+            fifo_full: value.fifo_full(),
+            fifo_overrun: value.fifo_overrun(),
+            inactivity: value.inactivity(),
+            activity: value.activity(),
+            awake: value.awake(),
+            polarity: value.polarity(),
+        }
+    }
+}
+
+impl From<InterruptMap> for InterruptMapRegister {
+    fn from(value: InterruptMap) -> Self {
+        InterruptMapRegister::new()
+            .with_data_ready(value.data_ready)
+            .with_fifo_ready(value.fifo_ready)
+            .with_fifo_full(value.fifo_full)
+            .with_fifo_overrun(value.fifo_overrun)
+            .with_inactivity(value.inactivity)
+            .with_activity(value.activity)
+            .with_awake(value.awake)
+            .with_polarity(value.polarity)
+    }
+}
+
+impl From<u8> for InterruptMap {
+    fn from(value: u8) -> Self {
+        InterruptMapRegister::from(value).into()
+    }
+}
+
+impl From<InterruptMap> for u8 {
+    fn from(value: InterruptMap) -> Self {
+        InterruptMapRegister::from(value).into()
+    }
+}
+
+/// Read-back of both interrupt pin mappings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct InterruptMaps {
+    /// Mapping of the INT1 pin.
+    pub int1: InterruptMap,
+    /// Mapping of the INT2 pin.
+    pub int2: InterruptMap,
+}
+
+impl InterruptMaps {
+    /// Decodes both maps from the adjacent `INT1_MAP` and `INT2_MAP` register values.
+    pub fn from_registers(raw: [u8; 2]) -> Self {
+        Self {
+            int1: InterruptMap::from(raw[0]),
+            int2: InterruptMap::from(raw[1]),
+        }
+    }
+
+    /// Encodes both maps into `INT1_MAP` and `INT2_MAP` register values.
+    pub fn to_registers(&self) -> [u8; 2] {
+        [u8::from(self.int1), u8::from(self.int2)]
+    }
+}
+
+/// Status event that can be awaited on an interrupt pin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptEvent {
+    /// `DATA_RDY`: a new XYZ sample is available.
+    DataReady,
+    /// `FIFO_RDY`: the FIFO reached its watermark.
+    FifoWatermark,
+    /// `ACT`: the activity detector fired.
+    Activity,
+    /// `INACT`: the inactivity detector fired.
+    Inactivity,
+}
+
+impl InterruptEvent {
+    /// Returns `true` when the event is routed by `map`.
+    pub const fn is_mapped(self, map: &InterruptMap) -> bool {
+        match self {
+            Self::DataReady => map.data_ready,
+            Self::FifoWatermark => map.fifo_ready,
+            Self::Activity => map.activity,
+            Self::Inactivity => map.inactivity,
+        }
+    }
+
+    /// Returns `true` when the event flag is set in `status`.
+    pub const fn is_set(self, status: &StatusSnapshot) -> bool {
+        match self {
+            Self::DataReady => status.data_rdy,
+            Self::FifoWatermark => status.fifo_rdy,
+            Self::Activity => status.activity,
+            Self::Inactivity => status.inact,
+        }
+    }
+
+    /// Returns `true` when `map` routes a level-held source that can keep the pin asserted
+    /// while this event is clear.
+    ///
+    /// `DATA_RDY`, `FIFO_RDY`, `FIFO_FULL`, `FIFO_OVR` and `AWAKE` are not cleared by reading
+    /// the status registers. A full or overrun FIFO is always above the watermark, so those two
+    /// do not mask [`FifoWatermark`](Self::FifoWatermark).
+    pub const fn is_masked_by(self, map: &InterruptMap) -> bool {
+        let fifo_level = map.fifo_full || map.fifo_overrun;
+        match self {
+            Self::DataReady => map.fifo_ready || fifo_level || map.awake,
+            Self::FifoWatermark => map.data_ready || map.awake,
+            Self::Activity | Self::Inactivity => {
+                map.data_ready || map.fifo_ready || fifo_level || map.awake
+            }
+        }
+    }
+}
+
+/// Physical interrupt output of the sensor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InterruptPin {
+    /// The INT1 pin.
+    Int1,
+    /// The INT2 pin.
+    Int2,
+}
+
+impl InterruptPin {
+    /// Selects the pin carrying `event` in `config`, preferring INT1 when both are mapped.
+    pub fn for_event(config: &Config, event: InterruptEvent) -> Option<(Self, InterruptPolarity)> {
+        if event.is_mapped(&config.int1_map) {
+            Some((Self::Int1, config.int1_map.polarity))
+        } else if event.is_mapped(&config.int2_map) {
+            Some((Self::Int2, config.int2_map.polarity))
+        } else {
+            None
+        }
+    }
+
+    // Picks the pin `wait_for` polls, skipping pins a level-held source could hold asserted.
+    fn for_wait<E>(config: &Config, event: InterruptEvent) -> Result<(Self, InterruptPolarity), E> {
+        let pins = [
+            (Self::Int1, &config.int1_map),
+            (Self::Int2, &config.int2_map),
+        ];
+        let mut mapped = false;
+        for (pin, map) in pins {
+            if event.is_mapped(map) {
+                if !event.is_masked_by(map) {
+                    return Ok((pin, map.polarity));
+                }
+                mapped = true;
+            }
+        }
+
+        Err(if mapped {
+            Error::SharedInterruptPin
+        } else {
+            Error::EventNotMapped
+        })
+    }
+}
+
+/// Placeholder for an interrupt pin that is not wired to the MCU.
+///
+/// Every read or wait reports an error, surfaced as [`Error::Pin`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct NoPin;
+
+impl ErrorType for NoPin {
+    type Error = ErrorKind;
+}
+
+impl InputPin for NoPin {
+    fn is_high(&mut self) -> core::result::Result<bool, Self::Error> {
+        Err(ErrorKind::Other)
+    }
+
+    fn is_low(&mut self) -> core::result::Result<bool, Self::Error> {
+        Err(ErrorKind::Other)
+    }
+}
+
+#[cfg(feature = "async")]
+impl Wait for NoPin {
+    async fn wait_for_high(&mut self) -> core::result::Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_low(&mut self) -> core::result::Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_rising_edge(&mut self) -> core::result::Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_falling_edge(&mut self) -> core::result::Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+
+    async fn wait_for_any_edge(&mut self) -> core::result::Result<(), Self::Error> {
+        Err(ErrorKind::Other)
+    }
+}
+
+/// Blocking driver that owns the INT1/INT2 pins wired to the sensor.
+pub struct Adxl372WithIrq<IFACE, INT1, INT2 = NoPin> {
+    device: Adxl372<IFACE>,
+    int1: INT1,
+    int2: INT2,
+}
+
+impl<IFACE, INT1, INT2> Adxl372WithIrq<IFACE, INT1, INT2> {
+    // ==================================================================
+    // == Driver Construction & Ownership ===============================
+    // ==================================================================
+    /// Wraps a configured driver together with both interrupt pins.
+    pub fn new(device: Adxl372<IFACE>, int1: INT1, int2: INT2) -> Self {
+        Self { device, int1, int2 }
+    }
+
+    /// Consumes the wrapper and returns the driver and pins.
+    pub fn release(self) -> (Adxl372<IFACE>, INT1, INT2) {
+        (self.device, self.int1, self.int2)
+    }
+
+    /// Returns a shared reference to the wrapped driver.
+    pub fn device(&self) -> &Adxl372<IFACE> {
+        &self.device
+    }
+
+    /// Provides mutable access to the wrapped driver.
+    pub fn device_mut(&mut self) -> &mut Adxl372<IFACE> {
+        &mut self.device
+    }
+}
+
+impl<IFACE, INT1> Adxl372WithIrq<IFACE, INT1, NoPin> {
+    /// Wraps a driver when only the INT1 pin is wired.
+    pub fn with_int1(device: Adxl372<IFACE>, int1: INT1) -> Self {
+        Self::new(device, int1, NoPin)
+    }
+}
+
+impl<IFACE, CommE, INT1, INT2> Adxl372WithIrq<IFACE, INT1, INT2>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+    INT1: InputPin,
+    INT2: InputPin,
+{
+    // ==================================================================
+    // == Interrupt-Driven Acquisition ==================================
+    // ==================================================================
+    /// Waits for `DATA_RDY` and returns the raw sample, which clears the flag.
+    pub fn wait_for_data_ready(&mut self) -> Result<[i16; 3], CommE> {
+        self.wait_for(InterruptEvent::DataReady)?;
+        self.device.read_xyz_raw()
+    }
+
+    /// Waits for `FIFO_RDY` and returns the number of buffered FIFO entries.
+    ///
+    /// The flag clears once the FIFO is drained below the watermark.
+    pub fn wait_for_fifo_watermark(&mut self) -> Result<u16, CommE> {
+        self.wait_for(InterruptEvent::FifoWatermark)?;
+        self.device.read_fifo_level()
+    }
+
+    /// Waits for an activity event; reading `STATUS2` clears it.
+    pub fn wait_for_activity(&mut self) -> Result<StatusSnapshot, CommE> {
+        self.wait_for(InterruptEvent::Activity)
+    }
+
+    /// Waits for an inactivity event; reading `STATUS2` clears it.
+    pub fn wait_for_inactivity(&mut self) -> Result<StatusSnapshot, CommE> {
+        self.wait_for(InterruptEvent::Inactivity)
+    }
+
+    /// Waits until the pin carrying `event` asserts and the status registers confirm it.
+    ///
+    /// Returns [`Error::EventNotMapped`] when the event is not mapped to either pin. A pin also
+    /// carrying a level-held source (see [`InterruptEvent::is_masked_by`]) could stay asserted
+    /// with `event` clear, so it is skipped; [`Error::SharedInterruptPin`] is returned when no
+    /// other pin carries `event`. Activity and inactivity events sharing the pin are cleared by
+    /// the status read and the wait continues.
+    pub fn wait_for(&mut self, event: InterruptEvent) -> Result<StatusSnapshot, CommE> {
+        let (pin, polarity) = InterruptPin::for_wait(self.device.config(), event)?;
+
+        loop {
+            self.wait_for_pin(pin, polarity)?;
+
+            let status = self.device.read_status()?;
+            if event.is_set(&status) {
+                return Ok(status);
+            }
+        }
+    }
+
+    fn wait_for_pin(
+        &mut self,
+        pin: InterruptPin,
+        polarity: InterruptPolarity,
+    ) -> Result<(), CommE> {
+        loop {
+            let asserted = match pin {
+                InterruptPin::Int1 => is_asserted(&mut self.int1, polarity)?,
+                InterruptPin::Int2 => is_asserted(&mut self.int2, polarity)?,
+            };
+
+            if asserted {
+                return Ok(());
+            }
+            core::hint::spin_loop();
+        }
+    }
+}
+
+fn is_asserted<P: InputPin, CommE>(
+    pin: &mut P,
+    polarity: InterruptPolarity,
+) -> Result<bool, CommE> {
+    match polarity {
+        InterruptPolarity::ActiveHigh => pin.is_high(),
+        InterruptPolarity::ActiveLow => pin.is_low(),
+    }
+    .map_err(|_| Error::Pin)
+}
+
+/// Async driver that owns the INT1/INT2 pins wired to the sensor.
+#[cfg(feature = "async")]
+pub struct Adxl372AsyncWithIrq<IFACE, INT1, INT2 = NoPin> {
+    device: Adxl372Async<IFACE>,
+    int1: INT1,
+    int2: INT2,
+}
+
+#[cfg(feature = "async")]
+impl<IFACE, INT1, INT2> Adxl372AsyncWithIrq<IFACE, INT1, INT2> {
+    // ==================================================================
+    // == Driver Construction & Ownership ===============================
+    // ==================================================================
+    /// Wraps a configured driver together with both interrupt pins.
+    pub fn new(device: Adxl372Async<IFACE>, int1: INT1, int2: INT2) -> Self {
+        Self { device, int1, int2 }
+    }
+
+    /// Consumes the wrapper and returns the driver and pins.
+    pub fn release(self) -> (Adxl372Async<IFACE>, INT1, INT2) {
+        (self.device, self.int1, self.int2)
+    }
+
+    /// Returns a shared reference to the wrapped driver.
+    pub fn device(&self) -> &Adxl372Async<IFACE> {
+        &self.device
+    }
+
+    /// Provides mutable access to the wrapped driver.
+    pub fn device_mut(&mut self) -> &mut Adxl372Async<IFACE> {
+        &mut self.device
+    }
+}
+
+#[cfg(feature = "async")]
+impl<IFACE, INT1> Adxl372AsyncWithIrq<IFACE, INT1, NoPin> {
+    /// Wraps a driver when only the INT1 pin is wired.
+    pub fn with_int1(device: Adxl372Async<IFACE>, int1: INT1) -> Self {
+        Self::new(device, int1, NoPin)
+    }
+}
+
+#[cfg(feature = "async")]
+impl<IFACE, CommE, INT1, INT2> Adxl372AsyncWithIrq<IFACE, INT1, INT2>
+where
+    IFACE: Adxl372InterfaceAsync<Error = CommE>,
+    INT1: Wait,
+    INT2: Wait,
+{
+    // ==================================================================
+    // == Interrupt-Driven Acquisition ==================================
+    // ==================================================================
+    /// Waits for `DATA_RDY` and returns the raw sample, which clears the flag.
+    pub async fn wait_for_data_ready(&mut self) -> Result<[i16; 3], CommE> {
+        self.wait_for(InterruptEvent::DataReady).await?;
+        self.device.read_xyz_raw().await
+    }
+
+    /// Waits for `FIFO_RDY` and returns the number of buffered FIFO entries.
+    ///
+    /// The flag clears once the FIFO is drained below the watermark.
+    pub async fn wait_for_fifo_watermark(&mut self) -> Result<u16, CommE> {
+        self.wait_for(InterruptEvent::FifoWatermark).await?;
+        self.device.read_fifo_level().await
+    }
+
+    /// Waits for an activity event; reading `STATUS2` clears it.
+    pub async fn wait_for_activity(&mut self) -> Result<StatusSnapshot, CommE> {
+        self.wait_for(InterruptEvent::Activity).await
+    }
+
+    /// Waits for an inactivity event; reading `STATUS2` clears it.
+    pub async fn wait_for_inactivity(&mut self) -> Result<StatusSnapshot, CommE> {
+        self.wait_for(InterruptEvent::Inactivity).await
+    }
+
+    /// Waits until the pin carrying `event` asserts and the status registers confirm it.
+    ///
+    /// See [`Adxl372WithIrq::wait_for`] for the error and shared-pin semantics.
+    pub async fn wait_for(&mut self, event: InterruptEvent) -> Result<StatusSnapshot, CommE> {
+        let (pin, polarity) = InterruptPin::for_wait(self.device.config(), event)?;
+
+        loop {
+            match pin {
+                InterruptPin::Int1 => wait_asserted(&mut self.int1, polarity).await?,
+                InterruptPin::Int2 => wait_asserted(&mut self.int2, polarity).await?,
+            }
+
+            let status = self.device.read_status().await?;
+            if event.is_set(&status) {
+                return Ok(status);
+            }
+        }
+    }
+}
+
+#[cfg(feature = "async")]
+async fn wait_asserted<P: Wait, CommE>(
+    pin: &mut P,
+    polarity: InterruptPolarity,
+) -> Result<(), CommE> {
+    match polarity {
+        InterruptPolarity::ActiveHigh => pin.wait_for_high().await,
+        InterruptPolarity::ActiveLow => pin.wait_for_low().await,
+    }
+    .map_err(|_| Error::Pin)
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::{Adxl372WithIrq, InterruptMap, InterruptMaps};
+    use crate::config::Config;
+    use crate::device::Adxl372;
+    use crate::error::Error;
+    use crate::params::InterruptPolarity;
+    use embedded_hal_mock::eh1::digital::{Mock as PinMock, State, Transaction as PinTransaction};
+    use embedded_hal_mock::eh1::spi::{Mock as SpiMock, Transaction as SpiTransaction};
+    use std::vec;
+
+    fn read(command: u8, response: std::vec::Vec<u8>) -> [SpiTransaction<u8>; 4] {
+        [
+            SpiTransaction::transaction_start(),
+            SpiTransaction::write_vec(vec![command]),
+            SpiTransaction::read_vec(response),
+            SpiTransaction::transaction_end(),
+        ]
+    }
+
+    /// Ensures both maps round-trip through their register encoding.
+    #[test]
+    fn maps_roundtrip_through_registers() {
+        let maps = InterruptMaps {
+            int1: InterruptMap {
+                data_ready: true,
+                fifo_full: true,
+                ..InterruptMap::NONE
+            },
+            int2: InterruptMap {
+                inactivity: true,
+                awake: true,
+                polarity: InterruptPolarity::ActiveLow,
+                ..InterruptMap::NONE
+            },
+        };
+
+        assert_eq!(maps.to_registers(), [0x05, 0xD0]);
+        assert_eq!(InterruptMaps::from_registers([0x05, 0xD0]), maps);
+        assert!(!InterruptMap::NONE.any_event());
+    }
+
+    /// Polls an active-low INT1 until it asserts, then confirms the event through STATUS2.
+    #[test]
+    fn wait_for_activity_polls_pin_and_decodes_status() {
+        let config = Config::new()
+            .int1_map(InterruptMap {
+                activity: true,
+                polarity: InterruptPolarity::ActiveLow,
+                ..InterruptMap::NONE
+            })
+            .build();
+        let spi = read(0x09, vec![0x00, 0x20]);
+        let pin = [
+            PinTransaction::get(State::High),
+            PinTransaction::get(State::Low),
+        ];
+        let mut irq = Adxl372WithIrq::with_int1(
+            Adxl372::new_spi(SpiMock::new(&spi), config),
+            PinMock::new(&pin),
+        );
+
+        let status = irq.wait_for_activity().unwrap();
+        assert!(status.activity);
+
+        let (device, mut int1, _) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+    }
+
+    /// Skips an inactivity assertion sharing INT1; the STATUS2 read clears it.
+    #[test]
+    fn wait_for_activity_consumes_latched_event_sharing_pin() {
+        let config = Config::new()
+            .int1_map(InterruptMap {
+                activity: true,
+                inactivity: true,
+                ..InterruptMap::NONE
+            })
+            .build();
+        let mut spi = vec![];
+        spi.extend(read(0x09, vec![0x00, 0x10]));
+        spi.extend(read(0x09, vec![0x00, 0x20]));
+        let pin = [
+            PinTransaction::get(State::High),
+            PinTransaction::get(State::Low),
+            PinTransaction::get(State::High),
+        ];
+        let mut irq = Adxl372WithIrq::with_int1(
+            Adxl372::new_spi(SpiMock::new(&spi), config),
+            PinMock::new(&pin),
+        );
+
+        let status = irq.wait_for_activity().unwrap();
+        assert!(status.activity && !status.inact);
+
+        let (device, mut int1, _) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+    }
+
+    /// Ensures a pin that DATA_RDY can hold asserted is never polled for activity.
+    #[test]
+    fn level_held_source_sharing_pin_is_skipped() {
+        let shared = InterruptMap {
+            activity: true,
+            data_ready: true,
+            ..InterruptMap::NONE
+        };
+        let spi: [SpiTransaction<u8>; 0] = [];
+        let pin: [PinTransaction; 0] = [];
+        let mut irq = Adxl372WithIrq::with_int1(
+            Adxl372::new_spi(SpiMock::new(&spi), Config::new().int1_map(shared).build()),
+            PinMock::new(&pin),
+        );
+        assert_eq!(irq.wait_for_activity(), Err(Error::SharedInterruptPin));
+        let (device, mut int1, _) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+
+        let config = Config::new()
+            .int1_map(shared)
+            .int2_map(InterruptMap {
+                activity: true,
+                ..InterruptMap::NONE
+            })
+            .build();
+        let spi = read(0x09, vec![0x00, 0x20]);
+        let int2 = [PinTransaction::get(State::High)];
+        let mut irq = Adxl372WithIrq::new(
+            Adxl372::new_spi(SpiMock::new(&spi), config),
+            PinMock::new(&pin),
+            PinMock::new(&int2),
+        );
+        assert!(irq.wait_for_activity().unwrap().activity);
+
+        let (device, mut int1, mut int2) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+        int2.done();
+    }
+
+    /// Verifies waiting on an unmapped event is rejected without touching the bus.
+    #[test]
+    fn unmapped_event_is_rejected() {
+        let spi: [SpiTransaction<u8>; 0] = [];
+        let pin: [PinTransaction; 0] = [];
+        let mut irq = Adxl372WithIrq::with_int1(
+            Adxl372::new_spi(SpiMock::new(&spi), Config::default()),
+            PinMock::new(&pin),
+        );
+
+        assert_eq!(irq.wait_for_inactivity(), Err(Error::EventNotMapped));
+
+        let (device, mut int1, _) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+    }
+
+    /// Routes DATA_RDY through INT2 on the async wrapper and reads the sample that clears it.
+    #[cfg(feature = "async")]
+    #[test]
+    fn async_wait_for_data_ready_reads_sample() {
+        use super::Adxl372AsyncWithIrq;
+        use crate::asynch::Adxl372Async;
+        use core::future::Future;
+        use core::pin::pin;
+        use core::task::{Context, Poll, Waker};
+
+        let config = Config::new()
+            .int2_map(InterruptMap {
+                data_ready: true,
+                ..InterruptMap::NONE
+            })
+            .build();
+        let mut spi = vec![];
+        spi.extend(read(0x09, vec![0x01, 0x00]));
+        spi.extend(read(0x11, vec![0x00, 0x10, 0x00, 0x20, 0x00, 0x30]));
+        let int1: [PinTransaction; 0] = [];
+        let int2 = [PinTransaction::wait_for_state(State::High)];
+        let mut irq = Adxl372AsyncWithIrq::new(
+            Adxl372Async::new_spi(SpiMock::new(&spi), config),
+            PinMock::new(&int1),
+            PinMock::new(&int2),
+        );
+
+        let xyz = {
+            let mut future = pin!(irq.wait_for_data_ready());
+            let mut cx = Context::from_waker(Waker::noop());
+            loop {
+                if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                    break output;
+                }
+            }
+        };
+        assert_eq!(xyz, Ok([1, 2, 3]));
+
+        let (device, mut int1, mut int2) = irq.release();
+        device.release_spi().0.done();
+        int1.done();
+        int2.done();
+    }
+}
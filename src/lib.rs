@@ -9,6 +9,13 @@
 //! Optional Cargo features:
 //! 
 //! - `defmt`: enable `defmt` logging for internal debug traces.
+//! - `async`: enable the [`asynch::Adxl372Async`] driver and async bus interfaces built on
+//!   `embedded-hal-async`.
+//! - `float`: enable `f32` conversions of [`units::Acceleration`] to g and m/s².
+//! - `std`: link the standard library for host-side tooling.
+//! - `sim`: enable the host-side register-level simulator in `sim` (implies `std`).
+//! - `testing`: enable the scriptable mock interface and sequence helpers in `testing`
+//!   (implies `std`).
 //!
 //! # Usage
 //! Import the relevant HAL crate for your platform. For this example I'm using esp-hal on ESP32C3.
@@ -87,16 +94,31 @@
 //! ```
 #![no_std]
 
+#[cfg(any(test, feature = "std"))]
+extern crate std;
+
 mod error;
 
+pub mod activity;
+#[cfg(feature = "async")]
+pub mod asynch;
+pub mod calibration;
 pub mod config;
 pub mod device;
+pub mod dump;
 pub mod fifo;
 pub mod interface;
+pub mod interrupt;
 mod log;
 pub mod params;
 pub mod registers;
 pub mod self_test;
+#[cfg(feature = "sim")]
+pub mod sim;
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+pub mod typestate;
+pub mod units;
 
 pub use crate::device::Adxl372;
 pub use crate::error::{Error, Result};
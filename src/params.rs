@@ -107,7 +107,10 @@ pub enum FifoFormat {
     XZ = 0b101,
     /// Y and Z axes interleaved.
     YZ = 0b110,
-    /// Peak acceleration reporting.
+    /// Peak acceleration reporting: one XYZ set per over-threshold event (instant-on mode).
+    ///
+    /// Unlike the `MAXPEAK` registers, which only keep the overall maximum, each event's
+    /// peak is queued separately.
     Peak = 0b111,
 }
 
@@ -360,3 +363,15 @@ pub enum HpfDisable {
     /// High-pass filter disabled (dc coupling).
     Disabled = 1,
 }
+
+/// Interrupt pin polarity selected by `INTx_MAP.INT_LOW`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Specifier)]
+#[repr(u8)]
+#[bits = 1]
+pub enum InterruptPolarity {
+    /// Pin is driven high while the interrupt is asserted.
+    #[default]
+    ActiveHigh = 0,
+    /// Pin is driven low while the interrupt is asserted.
+    ActiveLow = 1,
+}
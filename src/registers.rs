@@ -4,8 +4,8 @@
 use modular_bitfield::prelude::*;
 
 use crate::params::{
-    Bandwidth, ExtClk, ExtSync, FifoFormat, FifoMode, InstantOnThreshold, LinkLoopMode, LowNoise,
-    OutputDataRate, PowerMode, SettleFilter, WakeUpRate,
+    Bandwidth, ExtClk, ExtSync, FifoFormat, FifoMode, HighPassCorner, InstantOnThreshold,
+    InterruptPolarity, LinkLoopMode, LowNoise, OutputDataRate, PowerMode, SettleFilter, WakeUpRate,
 };
 
 /// Register address of `DEVID_AD`.
@@ -36,16 +36,78 @@ pub const REG_YDATA_L: u8 = 0x0B;
 pub const REG_ZDATA_H: u8 = 0x0C;
 /// Register address of `ZDATA_L`.
 pub const REG_ZDATA_L: u8 = 0x0D;
-/// Register address of `TEMP_DATA`.
-pub const REG_TEMP_DATA: u8 = 0x0E;
+/// Register address of `MAXPEAK_X_H`.
+pub const REG_MAXPEAK_X_H: u8 = 0x15;
+/// Register address of `MAXPEAK_X_L`.
+pub const REG_MAXPEAK_X_L: u8 = 0x16;
+/// Register address of `MAXPEAK_Y_H`.
+pub const REG_MAXPEAK_Y_H: u8 = 0x17;
+/// Register address of `MAXPEAK_Y_L`.
+pub const REG_MAXPEAK_Y_L: u8 = 0x18;
+/// Register address of `MAXPEAK_Z_H`.
+pub const REG_MAXPEAK_Z_H: u8 = 0x19;
+/// Register address of `MAXPEAK_Z_L`.
+pub const REG_MAXPEAK_Z_L: u8 = 0x1A;
+/// Register address of `OFFSET_X`.
+pub const REG_OFFSET_X: u8 = 0x20;
+/// Register address of `OFFSET_Y`.
+pub const REG_OFFSET_Y: u8 = 0x21;
+/// Register address of `OFFSET_Z`.
+pub const REG_OFFSET_Z: u8 = 0x22;
+/// Register address of `THRESH_ACT_X_H`.
+pub const REG_THRESH_ACT_X_H: u8 = 0x23;
+/// Register address of `THRESH_ACT_X_L`.
+pub const REG_THRESH_ACT_X_L: u8 = 0x24;
+/// Register address of `THRESH_ACT_Y_H`.
+pub const REG_THRESH_ACT_Y_H: u8 = 0x25;
+/// Register address of `THRESH_ACT_Y_L`.
+pub const REG_THRESH_ACT_Y_L: u8 = 0x26;
+/// Register address of `THRESH_ACT_Z_H`.
+pub const REG_THRESH_ACT_Z_H: u8 = 0x27;
+/// Register address of `THRESH_ACT_Z_L`.
+pub const REG_THRESH_ACT_Z_L: u8 = 0x28;
+/// Register address of `TIME_ACT`.
+pub const REG_TIME_ACT: u8 = 0x29;
+/// Register address of `THRESH_INACT_X_H`.
+pub const REG_THRESH_INACT_X_H: u8 = 0x2A;
+/// Register address of `THRESH_INACT_X_L`.
+pub const REG_THRESH_INACT_X_L: u8 = 0x2B;
+/// Register address of `THRESH_INACT_Y_H`.
+pub const REG_THRESH_INACT_Y_H: u8 = 0x2C;
+/// Register address of `THRESH_INACT_Y_L`.
+pub const REG_THRESH_INACT_Y_L: u8 = 0x2D;
+/// Register address of `THRESH_INACT_Z_H`.
+pub const REG_THRESH_INACT_Z_H: u8 = 0x2E;
+/// Register address of `THRESH_INACT_Z_L`.
+pub const REG_THRESH_INACT_Z_L: u8 = 0x2F;
+/// Register address of `TIME_INACT_H`.
+pub const REG_TIME_INACT_H: u8 = 0x30;
+/// Register address of `TIME_INACT_L`.
+pub const REG_TIME_INACT_L: u8 = 0x31;
+/// Register address of `THRESH_ACT2_X_H`.
+pub const REG_THRESH_ACT2_X_H: u8 = 0x32;
+/// Register address of `THRESH_ACT2_X_L`.
+pub const REG_THRESH_ACT2_X_L: u8 = 0x33;
+/// Register address of `THRESH_ACT2_Y_H`.
+pub const REG_THRESH_ACT2_Y_H: u8 = 0x34;
+/// Register address of `THRESH_ACT2_Y_L`.
+pub const REG_THRESH_ACT2_Y_L: u8 = 0x35;
+/// Register address of `THRESH_ACT2_Z_H`.
+pub const REG_THRESH_ACT2_Z_H: u8 = 0x36;
+/// Register address of `THRESH_ACT2_Z_L`.
+pub const REG_THRESH_ACT2_Z_L: u8 = 0x37;
 /// Register address of `FIFO_DATA`.
 pub const REG_FIFO_DATA: u8 = 0x42;
+/// Register address of `HPF`.
+pub const REG_HPF: u8 = 0x38;
 /// Register address of `FIFO_SAMPLES`.
 pub const REG_FIFO_SAMPLES: u8 = 0x39;
 /// Register address of `FIFO_CTL`.
 pub const REG_FIFO_CTL: u8 = 0x3A;
-/// Register address of `HPF`.
-pub const REG_HPF: u8 = 0x3C;
+/// Register address of `INT1_MAP`.
+pub const REG_INT1_MAP: u8 = 0x3B;
+/// Register address of `INT2_MAP`.
+pub const REG_INT2_MAP: u8 = 0x3C;
 /// Register address of `TIMING`.
 pub const REG_TIMING: u8 = 0x3D;
 /// Register address of `MEASURE`.
@@ -80,6 +142,21 @@ pub trait Register {
     const RESET_VALUE: Option<Self::Raw>;
 }
 
+/// Registers the host may read back, i.e. any access other than [`RegisterAccess::WriteOnly`].
+pub trait ReadableRegister: Register<Raw = u8> + From<u8> + Copy {}
+
+/// Registers the host may write, i.e. any access other than [`RegisterAccess::ReadOnly`].
+///
+/// Read-only registers such as [`Status`] do not implement this trait, so typed writes to
+/// them are rejected at compile time.
+pub trait WritableRegister: Register<Raw = u8> + Into<u8> + Copy {
+    /// Bits expected to read back as written.
+    ///
+    /// Self-clearing and read-only status bits are excluded; a zero mask means the register
+    /// cannot be verified by reading it back.
+    const VERIFY_MASK: u8 = 0xFF;
+}
+
 /// Bitfield representation of the `STATUS` register (address `0x04`).
 #[allow(unused_parens)]
 #[bitfield]
@@ -162,6 +239,41 @@ impl FifoEntriesUpper {
     }
 }
 
+impl From<u8> for FifoEntriesUpper {
+    fn from(value: u8) -> Self {
+        Self::from_bytes([value])
+    }
+}
+
+impl From<FifoEntriesUpper> for u8 {
+    fn from(value: FifoEntriesUpper) -> Self {
+        value.into_bytes()[0]
+    }
+}
+
+/// Bitfield representation of the `HPF` register (address `0x38`).
+#[allow(unused_parens)]
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Hpf {
+    // High-pass filter corner selection (bits 1:0).
+    pub corner: HighPassCorner,
+    #[skip]
+    __: B6,
+}
+
+impl From<u8> for Hpf {
+    fn from(value: u8) -> Self {
+        Self::from_bytes([value])
+    }
+}
+
+impl From<Hpf> for u8 {
+    fn from(value: Hpf) -> Self {
+        value.into_bytes()[0]
+    }
+}
+
 /// Bitfield representation of the `FIFO_CTL` register (address `0x3A`).
 #[allow(unused_parens)]
 #[bitfield]
@@ -189,6 +301,105 @@ impl From<FifoControl> for u8 {
     }
 }
 
+/// Bitfield representation of the `OFFSET_X/Y/Z` registers (addresses `0x20`-`0x22`).
+#[allow(unused_parens)]
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Offset {
+    // Four-bit two's complement user offset trim (bits 3:0).
+    pub user_offset: B4,
+    #[skip]
+    __: B4,
+}
+
+impl Offset {
+    /// Returns the sign-extended trim code.
+    pub fn code(self) -> i8 {
+        ((self.user_offset() << 4) as i8) >> 4
+    }
+
+    /// Builds the register image for a trim code; bits above the 4-bit field are dropped.
+    pub fn from_code(code: i8) -> Self {
+        Self::new().with_user_offset((code as u8) & 0x0F)
+    }
+}
+
+impl From<u8> for Offset {
+    fn from(value: u8) -> Self {
+        Self::from_bytes([value])
+    }
+}
+
+impl From<Offset> for u8 {
+    fn from(value: Offset) -> Self {
+        value.into_bytes()[0]
+    }
+}
+
+/// Bitfield representation of the `THRESH_*_L` registers (activity, inactivity and Activity2).
+///
+/// Only the X-axis register carries the referenced-mode bit; it is reserved on Y and Z.
+#[allow(unused_parens)]
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ThresholdLow {
+    // Per-axis detector enable (bit 0).
+    pub enable: bool,
+    // Referenced (AC-coupled) detection mode, X-axis register only (bit 1).
+    pub reference: bool,
+    #[skip]
+    __: B3,
+    // Threshold bits 2:0 (bits 7:5).
+    pub threshold_lsb: B3,
+}
+
+impl From<u8> for ThresholdLow {
+    fn from(value: u8) -> Self {
+        Self::from_bytes([value])
+    }
+}
+
+impl From<ThresholdLow> for u8 {
+    fn from(value: ThresholdLow) -> Self {
+        value.into_bytes()[0]
+    }
+}
+
+/// Bitfield representation of the `INT1_MAP`/`INT2_MAP` registers (addresses `0x3B`/`0x3C`).
+#[allow(unused_parens)]
+#[bitfield]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InterruptMapRegister {
+    // DATA_RDY routed to the pin (bit 0).
+    pub data_ready: bool,
+    // FIFO_RDY routed to the pin (bit 1).
+    pub fifo_ready: bool,
+    // FIFO_FULL routed to the pin (bit 2).
+    pub fifo_full: bool,
+    // FIFO_OVR routed to the pin (bit 3).
+    pub fifo_overrun: bool,
+    // INACT routed to the pin (bit 4).
+    pub inactivity: bool,
+    // ACT routed to the pin (bit 5).
+    pub activity: bool,
+    // AWAKE routed to the pin (bit 6).
+    pub awake: bool,
+    // Pin polarity (bit 7).
+    pub polarity: InterruptPolarity,
+}
+
+impl From<u8> for InterruptMapRegister {
+    fn from(value: u8) -> Self {
+        Self::from_bytes([value])
+    }
+}
+
+impl From<InterruptMapRegister> for u8 {
+    fn from(value: InterruptMapRegister) -> Self {
+        value.into_bytes()[0]
+    }
+}
+
 /// Bitfield representation of the `TIMING` register (address `0x3D`).
 #[allow(unused_parens)]
 #[bitfield]
@@ -319,6 +530,20 @@ impl Register for Status2 {
     const RESET_VALUE: Option<Self::Raw> = Some(0x00);
 }
 
+impl Register for FifoEntriesUpper {
+    type Raw = u8;
+    const ADDRESS: u8 = REG_FIFO_ENTRIES2;
+    const ACCESS: RegisterAccess = RegisterAccess::ReadOnly;
+    const RESET_VALUE: Option<Self::Raw> = Some(0x00);
+}
+
+impl Register for Hpf {
+    type Raw = u8;
+    const ADDRESS: u8 = REG_HPF;
+    const ACCESS: RegisterAccess = RegisterAccess::ReadWrite;
+    const RESET_VALUE: Option<Self::Raw> = Some(0x00);
+}
+
 impl Register for FifoControl {
     type Raw = u8;
     const ADDRESS: u8 = REG_FIFO_CTL;
@@ -354,6 +579,166 @@ impl Register for SelfTest {
     const RESET_VALUE: Option<Self::Raw> = Some(0x00);
 }
 
+impl ReadableRegister for Status {}
+impl ReadableRegister for Status2 {}
+impl ReadableRegister for FifoEntriesUpper {}
+impl ReadableRegister for Hpf {}
+impl ReadableRegister for FifoControl {}
+impl ReadableRegister for Timing {}
+impl ReadableRegister for Measure {}
+impl ReadableRegister for PowerControl {}
+impl ReadableRegister for SelfTest {}
+
+impl WritableRegister for Hpf {}
+impl WritableRegister for FifoControl {}
+impl WritableRegister for Timing {}
+impl WritableRegister for Measure {}
+impl WritableRegister for PowerControl {}
+impl WritableRegister for SelfTest {
+    // ST clears itself when the test completes; ST_DONE and USER_ST are read-only results.
+    const VERIFY_MASK: u8 = 0x00;
+}
+
+// Declares a typed single-address register around a raw byte or a shared bitfield layout.
+macro_rules! typed_register {
+    ($(#[$meta:meta])* $name:ident($inner:ty) = $address:ident, $access:ident, $reset:expr;) => {
+        $(#[$meta])*
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct $name(pub $inner);
+
+        impl From<u8> for $name {
+            fn from(value: u8) -> Self {
+                Self(<$inner>::from(value))
+            }
+        }
+
+        impl From<$name> for u8 {
+            fn from(value: $name) -> Self {
+                u8::from(value.0)
+            }
+        }
+
+        impl Register for $name {
+            type Raw = u8;
+            const ADDRESS: u8 = $address;
+            const ACCESS: RegisterAccess = RegisterAccess::$access;
+            const RESET_VALUE: Option<Self::Raw> = Some($reset);
+        }
+
+        typed_register!(@access $name $access);
+    };
+    ($($(#[$meta:meta])* $name:ident($inner:ty) = $address:ident, $access:ident, $reset:expr;)+) => {
+        $(typed_register!($(#[$meta])* $name($inner) = $address, $access, $reset;);)+
+    };
+    (@access $name:ident ReadOnly) => {
+        impl ReadableRegister for $name {}
+    };
+    (@access $name:ident WriteOnly) => {
+        impl WritableRegister for $name {
+            const VERIFY_MASK: u8 = 0x00;
+        }
+    };
+    (@access $name:ident ReadWrite) => {
+        impl ReadableRegister for $name {}
+        impl WritableRegister for $name {}
+    };
+}
+
+typed_register! {
+    /// `DEVID_AD` register (address `0x00`), the Analog Devices ID.
+    DevIdAd(u8) = REG_DEVID_AD, ReadOnly, EXPECTED_DEVID_AD;
+    /// `DEVID_MST` register (address `0x01`), the MEMS ID.
+    DevIdMst(u8) = REG_DEVID_MST, ReadOnly, EXPECTED_DEVID_MST;
+    /// `PARTID` register (address `0x02`).
+    PartId(u8) = REG_PARTID, ReadOnly, EXPECTED_PART_ID;
+    /// `REVID` register (address `0x03`), the silicon revision.
+    RevId(u8) = REG_REVID, ReadOnly, 0x02;
+    /// `FIFO_ENTRIES` register (address `0x07`), bits 7:0 of the FIFO entry count.
+    FifoEntries(u8) = REG_FIFO_ENTRIES, ReadOnly, 0x00;
+    /// `XDATA_H` register (address `0x08`), X-axis data bits 11:4.
+    XDataH(u8) = REG_XDATA_H, ReadOnly, 0x00;
+    /// `XDATA_L` register (address `0x09`), X-axis data bits 3:0 in bits 7:4.
+    XDataL(u8) = REG_XDATA_L, ReadOnly, 0x00;
+    /// `YDATA_H` register (address `0x0A`), Y-axis data bits 11:4.
+    YDataH(u8) = REG_YDATA_H, ReadOnly, 0x00;
+    /// `YDATA_L` register (address `0x0B`), Y-axis data bits 3:0 in bits 7:4.
+    YDataL(u8) = REG_YDATA_L, ReadOnly, 0x00;
+    /// `ZDATA_H` register (address `0x0C`), Z-axis data bits 11:4.
+    ZDataH(u8) = REG_ZDATA_H, ReadOnly, 0x00;
+    /// `ZDATA_L` register (address `0x0D`), Z-axis data bits 3:0 in bits 7:4.
+    ZDataL(u8) = REG_ZDATA_L, ReadOnly, 0x00;
+    /// `MAXPEAK_X_H` register (address `0x15`), X-axis peak bits 11:4.
+    MaxPeakXH(u8) = REG_MAXPEAK_X_H, ReadOnly, 0x00;
+    /// `MAXPEAK_X_L` register (address `0x16`), X-axis peak bits 3:0 in bits 7:4.
+    MaxPeakXL(u8) = REG_MAXPEAK_X_L, ReadOnly, 0x00;
+    /// `MAXPEAK_Y_H` register (address `0x17`), Y-axis peak bits 11:4.
+    MaxPeakYH(u8) = REG_MAXPEAK_Y_H, ReadOnly, 0x00;
+    /// `MAXPEAK_Y_L` register (address `0x18`), Y-axis peak bits 3:0 in bits 7:4.
+    MaxPeakYL(u8) = REG_MAXPEAK_Y_L, ReadOnly, 0x00;
+    /// `MAXPEAK_Z_H` register (address `0x19`), Z-axis peak bits 11:4.
+    MaxPeakZH(u8) = REG_MAXPEAK_Z_H, ReadOnly, 0x00;
+    /// `MAXPEAK_Z_L` register (address `0x1A`), Z-axis peak bits 3:0 in bits 7:4.
+    MaxPeakZL(u8) = REG_MAXPEAK_Z_L, ReadOnly, 0x00;
+    /// `OFFSET_X` register (address `0x20`).
+    OffsetX(Offset) = REG_OFFSET_X, ReadWrite, 0x00;
+    /// `OFFSET_Y` register (address `0x21`).
+    OffsetY(Offset) = REG_OFFSET_Y, ReadWrite, 0x00;
+    /// `OFFSET_Z` register (address `0x22`).
+    OffsetZ(Offset) = REG_OFFSET_Z, ReadWrite, 0x00;
+    /// `THRESH_ACT_X_H` register (address `0x23`), activity threshold bits 10:3.
+    ThreshActXH(u8) = REG_THRESH_ACT_X_H, ReadWrite, 0x00;
+    /// `THRESH_ACT_X_L` register (address `0x24`).
+    ThreshActXL(ThresholdLow) = REG_THRESH_ACT_X_L, ReadWrite, 0x00;
+    /// `THRESH_ACT_Y_H` register (address `0x25`), activity threshold bits 10:3.
+    ThreshActYH(u8) = REG_THRESH_ACT_Y_H, ReadWrite, 0x00;
+    /// `THRESH_ACT_Y_L` register (address `0x26`).
+    ThreshActYL(ThresholdLow) = REG_THRESH_ACT_Y_L, ReadWrite, 0x00;
+    /// `THRESH_ACT_Z_H` register (address `0x27`), activity threshold bits 10:3.
+    ThreshActZH(u8) = REG_THRESH_ACT_Z_H, ReadWrite, 0x00;
+    /// `THRESH_ACT_Z_L` register (address `0x28`).
+    ThreshActZL(ThresholdLow) = REG_THRESH_ACT_Z_L, ReadWrite, 0x00;
+    /// `TIME_ACT` register (address `0x29`), activity timer in samples.
+    TimeAct(u8) = REG_TIME_ACT, ReadWrite, 0x00;
+    /// `THRESH_INACT_X_H` register (address `0x2A`), inactivity threshold bits 10:3.
+    ThreshInactXH(u8) = REG_THRESH_INACT_X_H, ReadWrite, 0x00;
+    /// `THRESH_INACT_X_L` register (address `0x2B`).
+    ThreshInactXL(ThresholdLow) = REG_THRESH_INACT_X_L, ReadWrite, 0x00;
+    /// `THRESH_INACT_Y_H` register (address `0x2C`), inactivity threshold bits 10:3.
+    ThreshInactYH(u8) = REG_THRESH_INACT_Y_H, ReadWrite, 0x00;
+    /// `THRESH_INACT_Y_L` register (address `0x2D`).
+    ThreshInactYL(ThresholdLow) = REG_THRESH_INACT_Y_L, ReadWrite, 0x00;
+    /// `THRESH_INACT_Z_H` register (address `0x2E`), inactivity threshold bits 10:3.
+    ThreshInactZH(u8) = REG_THRESH_INACT_Z_H, ReadWrite, 0x00;
+    /// `THRESH_INACT_Z_L` register (address `0x2F`).
+    ThreshInactZL(ThresholdLow) = REG_THRESH_INACT_Z_L, ReadWrite, 0x00;
+    /// `TIME_INACT_H` register (address `0x30`), inactivity timer bits 15:8.
+    TimeInactH(u8) = REG_TIME_INACT_H, ReadWrite, 0x00;
+    /// `TIME_INACT_L` register (address `0x31`), inactivity timer bits 7:0.
+    TimeInactL(u8) = REG_TIME_INACT_L, ReadWrite, 0x00;
+    /// `THRESH_ACT2_X_H` register (address `0x32`), Activity2 threshold bits 10:3.
+    ThreshAct2XH(u8) = REG_THRESH_ACT2_X_H, ReadWrite, 0x00;
+    /// `THRESH_ACT2_X_L` register (address `0x33`).
+    ThreshAct2XL(ThresholdLow) = REG_THRESH_ACT2_X_L, ReadWrite, 0x00;
+    /// `THRESH_ACT2_Y_H` register (address `0x34`), Activity2 threshold bits 10:3.
+    ThreshAct2YH(u8) = REG_THRESH_ACT2_Y_H, ReadWrite, 0x00;
+    /// `THRESH_ACT2_Y_L` register (address `0x35`).
+    ThreshAct2YL(ThresholdLow) = REG_THRESH_ACT2_Y_L, ReadWrite, 0x00;
+    /// `THRESH_ACT2_Z_H` register (address `0x36`), Activity2 threshold bits 10:3.
+    ThreshAct2ZH(u8) = REG_THRESH_ACT2_Z_H, ReadWrite, 0x00;
+    /// `THRESH_ACT2_Z_L` register (address `0x37`).
+    ThreshAct2ZL(ThresholdLow) = REG_THRESH_ACT2_Z_L, ReadWrite, 0x00;
+    /// `FIFO_SAMPLES` register (address `0x39`), watermark bits 7:0.
+    FifoSamples(u8) = REG_FIFO_SAMPLES, ReadWrite, 0x80;
+    /// `INT1_MAP` register (address `0x3B`).
+    Int1Map(InterruptMapRegister) = REG_INT1_MAP, ReadWrite, 0x00;
+    /// `INT2_MAP` register (address `0x3C`).
+    Int2Map(InterruptMapRegister) = REG_INT2_MAP, ReadWrite, 0x00;
+    /// `RESET` register (address `0x41`); write [`RESET_COMMAND`] to reset the device.
+    Reset(u8) = REG_RESET, WriteOnly, 0x00;
+    /// `FIFO_DATA` register (address `0x42`), the FIFO read port.
+    FifoData(u8) = REG_FIFO_DATA, ReadOnly, 0x00;
+}
+
 /// Encodes the FIFO entry count from the upper and lower registers.
 pub fn fifo_entry_count(upper: FifoEntriesUpper, lower: u8) -> u16 {
     (upper.as_u16() << 8) | lower as u16
@@ -366,6 +751,123 @@ pub fn split_fifo_entry_count(count: u16) -> (FifoEntriesUpper, u8) {
     (upper, lower)
 }
 
+/// Metadata of one typed register, as listed in [`REGISTER_MAP`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct RegisterInfo {
+    pub(crate) address: u8,
+    pub(crate) access: RegisterAccess,
+    pub(crate) reset: Option<u8>,
+    /// [`WritableRegister::VERIFY_MASK`], zero for read-only registers.
+    pub(crate) verify_mask: u8,
+}
+
+impl RegisterInfo {
+    const fn read_only<R: ReadableRegister>() -> Self {
+        Self {
+            address: R::ADDRESS,
+            access: R::ACCESS,
+            reset: R::RESET_VALUE,
+            verify_mask: 0x00,
+        }
+    }
+
+    const fn writable<R: WritableRegister>() -> Self {
+        Self {
+            address: R::ADDRESS,
+            access: R::ACCESS,
+            reset: R::RESET_VALUE,
+            verify_mask: R::VERIFY_MASK,
+        }
+    }
+}
+
+/// Every typed register in address order.
+pub(crate) const REGISTER_MAP: [RegisterInfo; 55] = [
+    RegisterInfo::read_only::<DevIdAd>(),
+    RegisterInfo::read_only::<DevIdMst>(),
+    RegisterInfo::read_only::<PartId>(),
+    RegisterInfo::read_only::<RevId>(),
+    RegisterInfo::read_only::<Status>(),
+    RegisterInfo::read_only::<Status2>(),
+    RegisterInfo::read_only::<FifoEntriesUpper>(),
+    RegisterInfo::read_only::<FifoEntries>(),
+    RegisterInfo::read_only::<XDataH>(),
+    RegisterInfo::read_only::<XDataL>(),
+    RegisterInfo::read_only::<YDataH>(),
+    RegisterInfo::read_only::<YDataL>(),
+    RegisterInfo::read_only::<ZDataH>(),
+    RegisterInfo::read_only::<ZDataL>(),
+    RegisterInfo::read_only::<MaxPeakXH>(),
+    RegisterInfo::read_only::<MaxPeakXL>(),
+    RegisterInfo::read_only::<MaxPeakYH>(),
+    RegisterInfo::read_only::<MaxPeakYL>(),
+    RegisterInfo::read_only::<MaxPeakZH>(),
+    RegisterInfo::read_only::<MaxPeakZL>(),
+    RegisterInfo::writable::<OffsetX>(),
+    RegisterInfo::writable::<OffsetY>(),
+    RegisterInfo::writable::<OffsetZ>(),
+    RegisterInfo::writable::<ThreshActXH>(),
+    RegisterInfo::writable::<ThreshActXL>(),
+    RegisterInfo::writable::<ThreshActYH>(),
+    RegisterInfo::writable::<ThreshActYL>(),
+    RegisterInfo::writable::<ThreshActZH>(),
+    RegisterInfo::writable::<ThreshActZL>(),
+    RegisterInfo::writable::<TimeAct>(),
+    RegisterInfo::writable::<ThreshInactXH>(),
+    RegisterInfo::writable::<ThreshInactXL>(),
+    RegisterInfo::writable::<ThreshInactYH>(),
+    RegisterInfo::writable::<ThreshInactYL>(),
+    RegisterInfo::writable::<ThreshInactZH>(),
+    RegisterInfo::writable::<ThreshInactZL>(),
+    RegisterInfo::writable::<TimeInactH>(),
+    RegisterInfo::writable::<TimeInactL>(),
+    RegisterInfo::writable::<ThreshAct2XH>(),
+    RegisterInfo::writable::<ThreshAct2XL>(),
+    RegisterInfo::writable::<ThreshAct2YH>(),
+    RegisterInfo::writable::<ThreshAct2YL>(),
+    RegisterInfo::writable::<ThreshAct2ZH>(),
+    RegisterInfo::writable::<ThreshAct2ZL>(),
+    RegisterInfo::writable::<Hpf>(),
+    RegisterInfo::writable::<FifoSamples>(),
+    RegisterInfo::writable::<FifoControl>(),
+    RegisterInfo::writable::<Int1Map>(),
+    RegisterInfo::writable::<Int2Map>(),
+    RegisterInfo::writable::<Timing>(),
+    RegisterInfo::writable::<Measure>(),
+    RegisterInfo::writable::<PowerControl>(),
+    RegisterInfo::writable::<SelfTest>(),
+    RegisterInfo::writable::<Reset>(),
+    RegisterInfo::read_only::<FifoData>(),
+];
+
+/// Looks up the [`REGISTER_MAP`] entry of a raw register address.
+pub(crate) const fn register_info(address: u8) -> Option<RegisterInfo> {
+    let mut index = 0;
+    while index < REGISTER_MAP.len() {
+        if REGISTER_MAP[index].address == address {
+            return Some(REGISTER_MAP[index]);
+        }
+        index += 1;
+    }
+    None
+}
+
+/// Looks up [`WritableRegister::VERIFY_MASK`] for a raw register address.
+///
+/// Addresses outside [`REGISTER_MAP`] have no known contents and are never verified.
+pub(crate) const fn verify_mask(address: u8) -> u8 {
+    match register_info(address) {
+        Some(info) => info.verify_mask,
+        None => 0x00,
+    }
+}
+
+/// Decodes a 12-bit left-justified two's complement axis sample.
+#[inline]
+pub(crate) fn unpack_axis(msb: u8, lsb: u8) -> i16 {
+    i16::from_be_bytes([msb, lsb]) >> 4
+}
+
 /// Soft reset command value written to the `RESET` register.
 pub const RESET_COMMAND: u8 = 0x52;
 
@@ -380,6 +882,139 @@ pub const EXPECTED_PART_ID: u8 = 0xFA;
 mod tests {
     use super::*;
 
+    fn readable<R: ReadableRegister>() -> bool {
+        R::ACCESS != RegisterAccess::WriteOnly
+    }
+
+    fn writable<R: WritableRegister>() -> bool {
+        R::ACCESS != RegisterAccess::ReadOnly
+    }
+
+    fn entry<R: Register<Raw = u8>>() -> (u8, RegisterAccess, Option<u8>) {
+        (R::ADDRESS, R::ACCESS, R::RESET_VALUE)
+    }
+
+    /// Checks every register's address, access and reset value against the datasheet map.
+    #[test]
+    fn register_map_matches_datasheet() {
+        use RegisterAccess::{ReadOnly as R, ReadWrite as RW, WriteOnly as W};
+
+        let table = [
+            (entry::<DevIdAd>(), 0x00, R, 0xAD),
+            (entry::<DevIdMst>(), 0x01, R, 0x1D),
+            (entry::<PartId>(), 0x02, R, 0xFA),
+            (entry::<RevId>(), 0x03, R, 0x02),
+            (entry::<Status>(), 0x04, R, 0xA0),
+            (entry::<Status2>(), 0x05, R, 0x00),
+            (entry::<FifoEntriesUpper>(), 0x06, R, 0x00),
+            (entry::<FifoEntries>(), 0x07, R, 0x00),
+            (entry::<XDataH>(), 0x08, R, 0x00),
+            (entry::<XDataL>(), 0x09, R, 0x00),
+            (entry::<YDataH>(), 0x0A, R, 0x00),
+            (entry::<YDataL>(), 0x0B, R, 0x00),
+            (entry::<ZDataH>(), 0x0C, R, 0x00),
+            (entry::<ZDataL>(), 0x0D, R, 0x00),
+            (entry::<MaxPeakXH>(), 0x15, R, 0x00),
+            (entry::<MaxPeakXL>(), 0x16, R, 0x00),
+            (entry::<MaxPeakYH>(), 0x17, R, 0x00),
+            (entry::<MaxPeakYL>(), 0x18, R, 0x00),
+            (entry::<MaxPeakZH>(), 0x19, R, 0x00),
+            (entry::<MaxPeakZL>(), 0x1A, R, 0x00),
+            (entry::<OffsetX>(), 0x20, RW, 0x00),
+            (entry::<OffsetY>(), 0x21, RW, 0x00),
+            (entry::<OffsetZ>(), 0x22, RW, 0x00),
+            (entry::<ThreshActXH>(), 0x23, RW, 0x00),
+            (entry::<ThreshActXL>(), 0x24, RW, 0x00),
+            (entry::<ThreshActYH>(), 0x25, RW, 0x00),
+            (entry::<ThreshActYL>(), 0x26, RW, 0x00),
+            (entry::<ThreshActZH>(), 0x27, RW, 0x00),
+            (entry::<ThreshActZL>(), 0x28, RW, 0x00),
+            (entry::<TimeAct>(), 0x29, RW, 0x00),
+            (entry::<ThreshInactXH>(), 0x2A, RW, 0x00),
+            (entry::<ThreshInactXL>(), 0x2B, RW, 0x00),
+            (entry::<ThreshInactYH>(), 0x2C, RW, 0x00),
+            (entry::<ThreshInactYL>(), 0x2D, RW, 0x00),
+            (entry::<ThreshInactZH>(), 0x2E, RW, 0x00),
+            (entry::<ThreshInactZL>(), 0x2F, RW, 0x00),
+            (entry::<TimeInactH>(), 0x30, RW, 0x00),
+            (entry::<TimeInactL>(), 0x31, RW, 0x00),
+            (entry::<ThreshAct2XH>(), 0x32, RW, 0x00),
+            (entry::<ThreshAct2XL>(), 0x33, RW, 0x00),
+            (entry::<ThreshAct2YH>(), 0x34, RW, 0x00),
+            (entry::<ThreshAct2YL>(), 0x35, RW, 0x00),
+            (entry::<ThreshAct2ZH>(), 0x36, RW, 0x00),
+            (entry::<ThreshAct2ZL>(), 0x37, RW, 0x00),
+            (entry::<Hpf>(), 0x38, RW, 0x00),
+            (entry::<FifoSamples>(), 0x39, RW, 0x80),
+            (entry::<FifoControl>(), 0x3A, RW, 0x00),
+            (entry::<Int1Map>(), 0x3B, RW, 0x00),
+            (entry::<Int2Map>(), 0x3C, RW, 0x00),
+            (entry::<Timing>(), 0x3D, RW, 0x00),
+            (entry::<Measure>(), 0x3E, RW, 0x00),
+            (entry::<PowerControl>(), 0x3F, RW, 0x00),
+            (entry::<SelfTest>(), 0x40, RW, 0x00),
+            (entry::<Reset>(), 0x41, W, 0x00),
+            (entry::<FifoData>(), 0x42, R, 0x00),
+        ];
+
+        let mut previous = None;
+        for ((address, access, reset), expected_address, expected_access, expected_reset) in table {
+            assert_eq!(address, expected_address);
+            assert_eq!(access, expected_access, "access of 0x{address:02X}");
+            assert_eq!(
+                reset,
+                Some(expected_reset),
+                "reset value of 0x{address:02X}"
+            );
+            assert!(previous < Some(address), "0x{address:02X} out of order");
+            previous = Some(address);
+        }
+    }
+
+    /// Ensures the typed access markers agree with each register's declared access.
+    #[test]
+    fn access_markers_match_metadata() {
+        assert!(readable::<Status>() && readable::<Status2>());
+        assert!(readable::<Timing>() && writable::<Timing>());
+        assert!(readable::<Measure>() && writable::<Measure>());
+        assert!(readable::<PowerControl>() && writable::<PowerControl>());
+        assert!(readable::<Hpf>() && writable::<Hpf>());
+        assert!(readable::<FifoControl>() && writable::<FifoControl>());
+        assert!(readable::<SelfTest>() && writable::<SelfTest>());
+    }
+
+    /// Ensures the address lookup agrees with the per-register verify masks.
+    #[test]
+    fn verify_masks_skip_self_clearing_bits() {
+        fn mask<R: WritableRegister>() -> (u8, u8) {
+            (verify_mask(R::ADDRESS), R::VERIFY_MASK)
+        }
+
+        assert_eq!(mask::<PowerControl>(), (0xFF, 0xFF));
+        assert_eq!(mask::<Timing>(), (0xFF, 0xFF));
+        assert_eq!(mask::<SelfTest>(), (0x00, 0x00));
+        assert_eq!(mask::<Reset>(), (0x00, 0x00));
+        assert_eq!(verify_mask(REG_STATUS), 0x00);
+        assert_eq!(verify_mask(0x10), 0x00);
+    }
+
+    /// Ensures the metadata table lists each address once, in order, with its access class.
+    #[test]
+    fn register_map_is_ordered_and_consistent() {
+        let mut previous = None;
+        for info in REGISTER_MAP {
+            assert!(previous < Some(info.address), "0x{:02X}", info.address);
+            previous = Some(info.address);
+            if info.access == RegisterAccess::ReadOnly {
+                assert_eq!(info.verify_mask, 0x00);
+            }
+        }
+        assert_eq!(
+            register_info(REG_FIFO_SAMPLES).and_then(|i| i.reset),
+            Some(0x80)
+        );
+    }
+
     /// Validates that Status bitfields match the datasheet layout.
     #[test]
     fn status_layout_matches_datasheet() {
@@ -395,6 +1030,7 @@ mod tests {
 
     /// Ensures Timing encodes and decodes as expected across all fields.
     #[test]
+    #[allow(clippy::unusual_byte_groupings)]
     fn timing_roundtrip() {
         let timing = Timing::new()
             .with_ext_sync(ExtSync::Enabled)
@@ -409,4 +1045,50 @@ mod tests {
         assert_eq!(decoded.ext_sync(), ExtSync::Enabled);
         assert_eq!(decoded.ext_clk(), ExtClk::Disabled);
     }
+
+    /// Ensures HPF sits at 0x38, clear of INT2_MAP, with the corner in bits 1:0.
+    #[test]
+    fn hpf_register_matches_datasheet() {
+        assert_eq!(<Hpf as Register>::ADDRESS, 0x38);
+        assert_ne!(REG_HPF, REG_INT2_MAP);
+        assert_eq!(
+            u8::from(Hpf::new().with_corner(HighPassCorner::Corner3)),
+            0x03
+        );
+        assert_eq!(Hpf::from(0xFE).corner(), HighPassCorner::Corner2);
+    }
+
+    /// Verifies offset trims sign-extend from the four-bit field.
+    #[test]
+    fn offset_code_is_four_bit_twos_complement() {
+        assert_eq!(u8::from(Offset::from_code(-2)), 0x0E);
+        assert_eq!(Offset::from(0x0E).code(), -2);
+        assert_eq!(Offset::from(0xF7).code(), 7);
+        assert_eq!(Offset::from(0x08).code(), -8);
+    }
+
+    /// Ensures the interrupt map places INT_LOW in the top bit.
+    #[test]
+    fn interrupt_map_layout_matches_datasheet() {
+        let map = InterruptMapRegister::new()
+            .with_data_ready(true)
+            .with_awake(true)
+            .with_polarity(InterruptPolarity::ActiveLow);
+
+        assert_eq!(u8::from(map), 0b1100_0001);
+        assert!(InterruptMapRegister::from(0b0010_1000).activity());
+        assert!(InterruptMapRegister::from(0b0010_1000).fifo_overrun());
+    }
+
+    /// Checks the threshold low-byte layout: LSBs on top, reference and enable at the bottom.
+    #[test]
+    fn threshold_low_layout_matches_datasheet() {
+        let low = ThresholdLow::new()
+            .with_enable(true)
+            .with_reference(true)
+            .with_threshold_lsb(0b101);
+
+        assert_eq!(u8::from(low), 0b1010_0011);
+        assert_eq!(ThresholdLow::from(0b0110_0001).threshold_lsb(), 0b011);
+    }
 }
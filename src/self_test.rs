@@ -2,11 +2,18 @@
 
 use embedded_hal::delay::DelayNs;
 
+#[cfg(feature = "async")]
+use embedded_hal_async::delay::DelayNs as AsyncDelayNs;
+
+#[cfg(feature = "async")]
+use crate::asynch::Adxl372Async;
 use crate::device::Adxl372;
-use crate::error::{Error, Result};
+use crate::error::Result;
 use crate::interface::Adxl372Interface;
+#[cfg(feature = "async")]
+use crate::interface::Adxl372InterfaceAsync;
 use crate::params::{PowerMode, SettleFilter};
-use crate::registers::{PowerControl, REG_POWER_CTL, REG_SELF_TEST, SelfTest as SelfTestReg};
+use crate::registers::{PowerControl, SelfTest as SelfTestReg};
 
 const SELF_TEST_THRESHOLD_LSB: i16 = 5;
 const SELF_TEST_TIMEOUT_MS: u16 = 500;
@@ -17,6 +24,10 @@ const SELF_TEST_SETTLE_DELAY_MS: u32 = SELF_TEST_FILTER_SETTLE.millis() as u32;
 // Datasheet self-test errata requires averaging the first and last 50 ms at the default 400 Hz ODR
 // (see documents/adxl372-2.txt#L5596-L5608), yielding 20 samples per window.
 const SELF_TEST_SAMPLES_PER_WINDOW: usize = 20;
+// Z samples collected before the completion poll gives up.
+#[cfg(any(test, feature = "testing"))]
+pub(crate) const SELF_TEST_MAX_SAMPLES: usize =
+    (SELF_TEST_TIMEOUT_MS as usize * 1_000_000).div_ceil(SELF_TEST_SAMPLE_PERIOD_NS as usize);
 
 /// Result produced by the self-test routine.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -37,6 +48,7 @@ pub struct SelfTestReport {
     pub timed_out: bool,
 }
 
+#[allow(clippy::derivable_impls)]
 impl Default for SelfTestReport {
     fn default() -> Self {
         Self {
@@ -65,12 +77,6 @@ pub fn run_self_test<IFACE, CommE>(
 where
     IFACE: Adxl372Interface<Error = CommE>,
 {
-    device.reset()?;
-    configure_power_control_for_self_test(device)?;
-    if SELF_TEST_SETTLE_DELAY_MS > 0 {
-        delay.delay_ms(SELF_TEST_SETTLE_DELAY_MS);
-    }
-
     let result = execute_self_test_sequence(device, delay);
 
     match result {
@@ -92,105 +98,135 @@ fn execute_self_test_sequence<IFACE, CommE>(
 where
     IFACE: Adxl372Interface<Error = CommE>,
 {
-    clear_self_test_trigger(device)?;
-
-    if SELF_TEST_ACTIVATION_GUARD_MS > 0 {
-        delay.delay_ms(u32::from(SELF_TEST_ACTIVATION_GUARD_MS));
+    let mut report = SelfTestReport::default();
+    for step in SELF_TEST_SEQUENCE {
+        match step {
+            SelfTestStep::Reset => device.reset()?,
+            SelfTestStep::PreparePowerControl => {
+                device.modify_reg(prepare_power_control)?;
+            }
+            SelfTestStep::DelayMs(ms) => delay.delay_ms(ms),
+            SelfTestStep::SetTrigger(st) => {
+                device.modify_reg(|reg: &mut SelfTestReg| reg.set_st(st))?;
+            }
+            SelfTestStep::Collect => report = collect_self_test_windows(device, delay)?,
+        }
     }
-
-    trigger_self_test(device)?;
-
-    let window_stats = collect_self_test_windows(device, delay)?;
-    clear_self_test_trigger(device)?;
-
-    let baseline_avg = window_stats.baseline_avg;
-    let stimulated_avg = window_stats.stimulated_avg;
-    let delta = stimulated_avg - baseline_avg;
-
-    let user_flag = window_stats.final_reg.user_st();
-    let displacement_ok = delta.abs() >= SELF_TEST_THRESHOLD_LSB;
-    let passed = !window_stats.timed_out && user_flag && displacement_ok;
-
-    Ok(SelfTestReport {
-        passed,
-        baseline_avg_z: baseline_avg,
-        stimulated_avg_z: stimulated_avg,
-        delta_z_lsb: delta,
-        samples_per_window: window_stats
-            .baseline_samples
-            .min(window_stats.stimulated_samples),
-        user_flag,
-        timed_out: window_stats.timed_out,
-    })
+    Ok(report)
 }
 
-fn trigger_self_test<IFACE, CommE>(device: &mut Adxl372<IFACE>) -> Result<(), CommE>
+fn collect_self_test_windows<IFACE, CommE>(
+    device: &mut Adxl372<IFACE>,
+    delay: &mut impl DelayNs,
+) -> Result<SelfTestReport, CommE>
 where
     IFACE: Adxl372Interface<Error = CommE>,
 {
-    update_self_test_register(device, |reg| reg.set_st(true))?;
-    Ok(())
+    let mut run = SelfTestRun::new(device.read_reg()?);
+    while run.poll_due() {
+        if run.record_status(device.read_reg()?) {
+            break;
+        }
+        run.record_sample(device.read_z_raw()?);
+        delay.delay_ns(SELF_TEST_SAMPLE_PERIOD_NS);
+    }
+    Ok(run.finish())
 }
 
-fn clear_self_test_trigger<IFACE, CommE>(device: &mut Adxl372<IFACE>) -> Result<(), CommE>
-where
-    IFACE: Adxl372Interface<Error = CommE>,
-{
-    update_self_test_register(device, |reg| reg.set_st(false))?;
-    Ok(())
+// ======================================================================
+// == Shared Self-Test Bookkeeping =======================================
+// ======================================================================
+
+/// One step of the self-test sequence, executed in the order of [`SELF_TEST_SEQUENCE`].
+///
+/// Shared by the blocking and async routines; each only performs the bus accesses and delays.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum SelfTestStep {
+    /// Soft reset to the factory defaults.
+    Reset,
+    /// Read-modify-write of `POWER_CTL` with [`prepare_power_control`].
+    PreparePowerControl,
+    /// Fixed wait.
+    DelayMs(u32),
+    /// Read-modify-write of `SELF_TEST.ST`.
+    SetTrigger(bool),
+    /// Poll for completion while sampling Z, see [`SelfTestRun`].
+    Collect,
 }
 
-fn update_self_test_register<IFACE, CommE, F>(
-    device: &mut Adxl372<IFACE>,
-    mut mutate: F,
-) -> Result<(), CommE>
-where
-    IFACE: Adxl372Interface<Error = CommE>,
-    F: FnMut(&mut SelfTestReg),
-{
-    let iface = device.interface_mut();
-    let current = iface.read_register(REG_SELF_TEST).map_err(Error::from)?;
-    let mut reg = SelfTestReg::from(current);
-    mutate(&mut reg);
-    let updated = u8::from(reg);
-    if updated != current {
-        iface
-            .write_register(REG_SELF_TEST, updated)
-            .map_err(Error::from)?;
+/// Self-test sequence up to, but not including, the concluding reset.
+pub(crate) const SELF_TEST_SEQUENCE: [SelfTestStep; 8] = [
+    SelfTestStep::Reset,
+    SelfTestStep::PreparePowerControl,
+    SelfTestStep::DelayMs(SELF_TEST_SETTLE_DELAY_MS),
+    SelfTestStep::SetTrigger(false),
+    SelfTestStep::DelayMs(SELF_TEST_ACTIVATION_GUARD_MS as u32),
+    SelfTestStep::SetTrigger(true),
+    SelfTestStep::Collect,
+    SelfTestStep::SetTrigger(false),
+];
+
+/// Completion polling and sample bookkeeping for [`SelfTestStep::Collect`].
+struct SelfTestRun {
+    windows: SelfTestWindows,
+    elapsed_ns: u32,
+    last_reg: SelfTestReg,
+    timed_out: bool,
+}
+
+impl SelfTestRun {
+    // Starts a run from the `SELF_TEST` value read right after triggering.
+    fn new(initial: SelfTestReg) -> Self {
+        Self {
+            windows: SelfTestWindows::new(),
+            elapsed_ns: 0,
+            last_reg: initial,
+            timed_out: false,
+        }
+    }
+
+    // Returns whether another completion poll fits in the timeout.
+    fn poll_due(&mut self) -> bool {
+        self.timed_out = self.elapsed_ns >= u32::from(SELF_TEST_TIMEOUT_MS) * 1_000_000;
+        !self.timed_out
+    }
+
+    // Records a polled `SELF_TEST` value and returns whether the test has completed.
+    fn record_status(&mut self, reg: SelfTestReg) -> bool {
+        self.last_reg = reg;
+        reg.st_done()
+    }
+
+    // Records a Z sample; the caller then waits one sample period.
+    fn record_sample(&mut self, z: i16) {
+        self.windows.push(z);
+        self.elapsed_ns = self.elapsed_ns.saturating_add(SELF_TEST_SAMPLE_PERIOD_NS);
+    }
+
+    fn finish(self) -> SelfTestReport {
+        self.windows
+            .finish(self.last_reg, self.timed_out)
+            .into_report()
     }
-    Ok(())
 }
 
-fn configure_power_control_for_self_test<IFACE, CommE>(
-    device: &mut Adxl372<IFACE>,
-) -> Result<(), CommE>
-where
-    IFACE: Adxl372Interface<Error = CommE>,
-{
-    let iface = device.interface_mut();
-    let current = iface.read_register(REG_POWER_CTL).map_err(Error::from)?;
-    let mut reg = PowerControl::from(current);
+/// Applies the `POWER_CTL` fields required while the self-test runs.
+pub(crate) fn prepare_power_control(reg: &mut PowerControl) {
     reg.set_lpf_disable(false);
     reg.set_mode(PowerMode::Measure);
     reg.set_filter_settle(SELF_TEST_FILTER_SETTLE);
-    let updated = u8::from(reg);
-    if updated != current {
-        iface
-            .write_register(REG_POWER_CTL, updated)
-            .map_err(Error::from)?;
-    }
-    Ok(())
 }
 
-fn read_self_test_register<IFACE, CommE>(device: &mut Adxl372<IFACE>) -> Result<SelfTestReg, CommE>
-where
-    IFACE: Adxl372Interface<Error = CommE>,
-{
-    let value = device
-        .interface_mut()
-        .read_register(REG_SELF_TEST)
-        .map_err(Error::from)?;
-    Ok(SelfTestReg::from(value))
+/// Evaluates the report the routine produces for the Z samples it collected.
+#[cfg(any(test, feature = "testing"))]
+pub(crate) fn evaluate_samples(
+    samples: impl IntoIterator<Item = i16>,
+    final_reg: SelfTestReg,
+    timed_out: bool,
+) -> SelfTestReport {
+    let mut windows = SelfTestWindows::new();
+    samples.into_iter().for_each(|z| windows.push(z));
+    windows.finish(final_reg, timed_out).into_report()
 }
 
 struct SelfTestWindowStats {
@@ -202,208 +238,188 @@ struct SelfTestWindowStats {
     timed_out: bool,
 }
 
-fn collect_self_test_windows<IFACE, CommE>(
-    device: &mut Adxl372<IFACE>,
-    delay: &mut impl DelayNs,
-) -> Result<SelfTestWindowStats, CommE>
-where
-    IFACE: Adxl372Interface<Error = CommE>,
-{
-    let target_samples = SELF_TEST_SAMPLES_PER_WINDOW as u16;
-    let mut baseline_sum: i16 = 0;
-    let mut baseline_count: u16 = 0;
-
-    let mut rolling_samples = [0i16; SELF_TEST_SAMPLES_PER_WINDOW];
-    let mut rolling_sum: i16 = 0;
-    let mut rolling_count: u16 = 0;
-    let mut rolling_index: usize = 0;
-
-    let mut elapsed_ns: u32 = 0;
-    let timeout_ns = u32::from(SELF_TEST_TIMEOUT_MS) * 1_000_000;
-    let mut timed_out = false;
-    let mut last_reg = read_self_test_register(device)?;
-
-    loop {
-        if elapsed_ns >= timeout_ns {
-            timed_out = true;
-            break;
+impl SelfTestWindowStats {
+    fn into_report(self) -> SelfTestReport {
+        let delta = self.stimulated_avg - self.baseline_avg;
+        let user_flag = self.final_reg.user_st();
+        let displacement_ok = delta.abs() >= SELF_TEST_THRESHOLD_LSB;
+        let passed = !self.timed_out && user_flag && displacement_ok;
+
+        SelfTestReport {
+            passed,
+            baseline_avg_z: self.baseline_avg,
+            stimulated_avg_z: self.stimulated_avg,
+            delta_z_lsb: delta,
+            samples_per_window: self.baseline_samples.min(self.stimulated_samples),
+            user_flag,
+            timed_out: self.timed_out,
         }
+    }
+}
 
-        last_reg = read_self_test_register(device)?;
-        if last_reg.st_done() {
-            break;
+/// Accumulates the leading baseline window and the trailing stimulated window.
+struct SelfTestWindows {
+    baseline_sum: i16,
+    baseline_count: u16,
+    rolling_samples: [i16; SELF_TEST_SAMPLES_PER_WINDOW],
+    rolling_sum: i16,
+    rolling_count: u16,
+    rolling_index: usize,
+}
+
+impl SelfTestWindows {
+    const fn new() -> Self {
+        Self {
+            baseline_sum: 0,
+            baseline_count: 0,
+            rolling_samples: [0; SELF_TEST_SAMPLES_PER_WINDOW],
+            rolling_sum: 0,
+            rolling_count: 0,
+            rolling_index: 0,
         }
+    }
 
-        let z = device.read_z_raw()?;
+    fn push(&mut self, z: i16) {
+        let target_samples = SELF_TEST_SAMPLES_PER_WINDOW as u16;
 
-        if baseline_count < target_samples {
-            baseline_sum = baseline_sum.saturating_add(z);
-            baseline_count = baseline_count.saturating_add(1);
+        if self.baseline_count < target_samples {
+            self.baseline_sum = self.baseline_sum.saturating_add(z);
+            self.baseline_count = self.baseline_count.saturating_add(1);
         }
 
-        if rolling_count == target_samples {
-            let removed = rolling_samples[rolling_index];
-            rolling_sum = rolling_sum.saturating_add(-removed);
+        if self.rolling_count == target_samples {
+            let removed = self.rolling_samples[self.rolling_index];
+            self.rolling_sum = self.rolling_sum.saturating_add(-removed);
         } else {
-            rolling_count = rolling_count.saturating_add(1);
-        }
-        rolling_samples[rolling_index] = z;
-        rolling_sum = rolling_sum.saturating_add(z);
-        rolling_index = (rolling_index + 1) % SELF_TEST_SAMPLES_PER_WINDOW;
-
-        if SELF_TEST_SAMPLE_PERIOD_NS > 0 {
-            delay.delay_ns(SELF_TEST_SAMPLE_PERIOD_NS);
-            elapsed_ns = elapsed_ns.saturating_add(SELF_TEST_SAMPLE_PERIOD_NS);
+            self.rolling_count = self.rolling_count.saturating_add(1);
         }
+        self.rolling_samples[self.rolling_index] = z;
+        self.rolling_sum = self.rolling_sum.saturating_add(z);
+        self.rolling_index = (self.rolling_index + 1) % SELF_TEST_SAMPLES_PER_WINDOW;
     }
 
-    let baseline_divisor = baseline_count as i16;
-    let baseline_avg = if baseline_divisor > 0 {
-        baseline_sum / baseline_divisor
-    } else {
-        0
-    };
-
-    let stimulated_divisor = rolling_count as i16;
-    let stimulated_avg = if stimulated_divisor > 0 {
-        rolling_sum / stimulated_divisor
-    } else {
-        0
-    };
-
-    let timed_out = timed_out || !last_reg.st_done();
-
-    Ok(SelfTestWindowStats {
-        baseline_avg,
-        baseline_samples: baseline_count,
-        stimulated_avg,
-        stimulated_samples: rolling_count,
-        final_reg: last_reg,
-        timed_out,
-    })
-}
-
-#[cfg(test)]
-mod tests {
-    extern crate std;
-
-    use super::*;
-    use crate::config::Config;
-    use crate::registers::{REG_POWER_CTL, REG_RESET, REG_SELF_TEST, REG_ZDATA_H, RESET_COMMAND};
-    use core::convert::Infallible;
-    use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction};
-    use std::vec::Vec;
+    fn finish(self, final_reg: SelfTestReg, timed_out: bool) -> SelfTestWindowStats {
+        let baseline_divisor = self.baseline_count as i16;
+        let baseline_avg = if baseline_divisor > 0 {
+            self.baseline_sum / baseline_divisor
+        } else {
+            0
+        };
 
-    #[derive(Debug, Clone, PartialEq, Eq)]
-    enum Expectation {
-        ReadRegister { register: u8, value: u8 },
-        WriteRegister { register: u8, value: u8 },
-        ReadMany { register: u8, data: [u8; 2] },
+        let stimulated_divisor = self.rolling_count as i16;
+        let stimulated_avg = if stimulated_divisor > 0 {
+            self.rolling_sum / stimulated_divisor
+        } else {
+            0
+        };
+
+        SelfTestWindowStats {
+            baseline_avg,
+            baseline_samples: self.baseline_count,
+            stimulated_avg,
+            stimulated_samples: self.rolling_count,
+            final_reg,
+            timed_out: timed_out || !final_reg.st_done(),
+        }
     }
+}
 
-    struct MockInterface {
-        expectations: Vec<Expectation>,
-        index: usize,
-    }
+// ======================================================================
+// == Async Self-Test ====================================================
+// ======================================================================
 
-    impl MockInterface {
-        fn new(expectations: Vec<Expectation>) -> Self {
-            Self {
-                expectations,
-                index: 0,
-            }
-        }
+/// Async counterpart of [`run_self_test`] for [`Adxl372Async`].
+///
+/// Follows the exact same register sequence and pass criteria as the blocking routine, so the
+/// same reset caveats apply: run it before [`Adxl372Async::init`] or any configuration helper.
+#[cfg(feature = "async")]
+pub async fn run_self_test_async<IFACE, CommE>(
+    device: &mut Adxl372Async<IFACE>,
+    delay: &mut impl AsyncDelayNs,
+) -> Result<SelfTestReport, CommE>
+where
+    IFACE: Adxl372InterfaceAsync<Error = CommE>,
+{
+    let result = execute_self_test_sequence_async(device, delay).await;
 
-        fn next_expectation(&mut self) -> Expectation {
-            if self.index >= self.expectations.len() {
-                panic!("unexpected interface call");
-            }
-            let expectation = self.expectations[self.index].clone();
-            self.index += 1;
-            expectation
+    match result {
+        Ok(report) => {
+            device.reset().await?;
+            Ok(report)
         }
-    }
-
-    impl Drop for MockInterface {
-        fn drop(&mut self) {
-            assert_eq!(
-                self.index,
-                self.expectations.len(),
-                "not all interface expectations consumed"
-            );
+        Err(err) => {
+            let _ = device.reset().await;
+            Err(err)
         }
     }
+}
 
-    impl Adxl372Interface for MockInterface {
-        type Error = Infallible;
-
-        fn write_register(
-            &mut self,
-            register: u8,
-            value: u8,
-        ) -> core::result::Result<(), Self::Error> {
-            match self.next_expectation() {
-                Expectation::WriteRegister {
-                    register: expected_reg,
-                    value: expected_value,
-                } => {
-                    assert_eq!(register, expected_reg, "write register mismatch");
-                    assert_eq!(value, expected_value, "write value mismatch");
-                }
-                other => panic!("unexpected write_register call: {other:?}"),
+#[cfg(feature = "async")]
+async fn execute_self_test_sequence_async<IFACE, CommE>(
+    device: &mut Adxl372Async<IFACE>,
+    delay: &mut impl AsyncDelayNs,
+) -> Result<SelfTestReport, CommE>
+where
+    IFACE: Adxl372InterfaceAsync<Error = CommE>,
+{
+    let mut report = SelfTestReport::default();
+    for step in SELF_TEST_SEQUENCE {
+        match step {
+            SelfTestStep::Reset => device.reset().await?,
+            SelfTestStep::PreparePowerControl => {
+                device.modify_reg(prepare_power_control).await?;
             }
-            Ok(())
-        }
-
-        fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
-            match self.next_expectation() {
-                Expectation::ReadRegister {
-                    register: expected_reg,
-                    value,
-                } => {
-                    assert_eq!(register, expected_reg, "read register mismatch");
-                    Ok(value)
-                }
-                other => panic!("unexpected read_register call: {other:?}"),
+            SelfTestStep::DelayMs(ms) => delay.delay_ms(ms).await,
+            SelfTestStep::SetTrigger(st) => {
+                device
+                    .modify_reg(|reg: &mut SelfTestReg| reg.set_st(st))
+                    .await?;
             }
-        }
-
-        fn read_many(
-            &mut self,
-            register: u8,
-            buf: &mut [u8],
-        ) -> core::result::Result<(), Self::Error> {
-            match self.next_expectation() {
-                Expectation::ReadMany {
-                    register: expected_reg,
-                    data,
-                } => {
-                    assert_eq!(register, expected_reg, "read_many register mismatch");
-                    assert_eq!(buf.len(), data.len(), "read_many length mismatch");
-                    buf.copy_from_slice(&data);
-                }
-                other => panic!("unexpected read_many call: {other:?}"),
+            SelfTestStep::Collect => {
+                report = collect_self_test_windows_async(device, delay).await?;
             }
-            Ok(())
         }
+    }
+    Ok(report)
+}
 
-        fn write_many(
-            &mut self,
-            register: u8,
-            data: &[u8],
-        ) -> core::result::Result<(), Self::Error> {
-            panic!(
-                "unexpected write_many call: register={register:#04x}, len={}",
-                data.len()
-            );
+#[cfg(feature = "async")]
+async fn collect_self_test_windows_async<IFACE, CommE>(
+    device: &mut Adxl372Async<IFACE>,
+    delay: &mut impl AsyncDelayNs,
+) -> Result<SelfTestReport, CommE>
+where
+    IFACE: Adxl372InterfaceAsync<Error = CommE>,
+{
+    let mut run = SelfTestRun::new(device.read_reg().await?);
+    while run.poll_due() {
+        if run.record_status(device.read_reg().await?) {
+            break;
         }
+        run.record_sample(device.read_z_raw().await?);
+        delay.delay_ns(SELF_TEST_SAMPLE_PERIOD_NS).await;
     }
+    Ok(run.finish())
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::config::Config;
+    use crate::registers::{REG_POWER_CTL, REG_RESET, REG_SELF_TEST, REG_ZDATA_H, RESET_COMMAND};
+    use crate::testing::{Expectation, MockInterface};
+    use embedded_hal_mock::eh1::delay::{CheckedDelay, Transaction};
+    use std::vec::Vec;
 
+    #[allow(clippy::unnecessary_cast)]
     fn sample_bytes(z: i16) -> [u8; 2] {
         let raw = (z << 4) as i16;
         raw.to_be_bytes()
     }
 
+    #[allow(clippy::vec_init_then_push)]
     fn build_success_expectations() -> Vec<Expectation> {
         let mut expectations = Vec::new();
         expectations.push(Expectation::WriteRegister {
@@ -443,7 +459,7 @@ mod tests {
             });
             expectations.push(Expectation::ReadMany {
                 register: REG_ZDATA_H,
-                data: sample_bytes(z),
+                data: sample_bytes(z).to_vec(),
             });
         }
 
@@ -462,6 +478,7 @@ mod tests {
         expectations
     }
 
+    #[allow(clippy::vec_init_then_push)]
     fn build_timeout_expectations() -> Vec<Expectation> {
         let mut expectations = Vec::new();
         expectations.push(Expectation::WriteRegister {
@@ -500,7 +517,7 @@ mod tests {
             });
             expectations.push(Expectation::ReadMany {
                 register: REG_ZDATA_H,
-                data: sample_bytes(0),
+                data: sample_bytes(0).to_vec(),
             });
         }
 
@@ -0,0 +1,925 @@
+//! Host-side, register-level model of the ADXL372.
+//!
+//! [`Adxl372Sim`] implements [`Adxl372Interface`], so the real [`Adxl372`](crate::Adxl372)
+//! driver runs against it unmodified. Instead of scripting every bus transaction, tests set the
+//! acceleration seen by the sensor, advance simulated time and check what the driver reports.
+//! The acceleration is either a constant or a [`Stimulus`] built from per-axis waveforms.
+//!
+//! The model covers:
+//!
+//! - Datasheet reset values, and a soft reset when [`RESET_COMMAND`] is written to `RESET`.
+//! - Writes to read-only and reserved addresses are ignored; `RESET` reads back as zero.
+//! - Burst accesses auto-increment, except on `FIFO_DATA`, which keeps popping the FIFO.
+//! - While `POWER_CTL` selects any mode other than standby, a sample is produced every ODR
+//!   period. The stimulus is clipped at ±200 g and shaped by the low-pass and high-pass filters
+//!   unless `POWER_CTL` disables them. Each sample updates the data registers, the `MAXPEAK`
+//!   latches, the FIFO and the activity, inactivity and Activity2 detectors.
+//! - `STATUS` latches `DATA_RDY`, `FIFO_OVR`, `USER_NVM_BUSY` and `ERR_USER_REGS` until it is
+//!   read; `FIFO_RDY` (watermark reached), `FIFO_FULL` and `AWAKE` follow the current state.
+//!   `STATUS2` latches the detector events until it is read, and reading an axis'
+//!   `MAXPEAK_*_L` register clears that axis' peak.
+//! - The FIFO honours the `FIFO_CTL` mode and format: stream and trigger mode discard the
+//!   oldest set on overflow, oldest-saved mode drops the new one, and bypass empties it.
+//! - Setting `ST` in `SELF_TEST` deflects the Z axis after a short settling time; once
+//!   [`SELF_TEST_DURATION`] has passed, `ST` clears and `ST_DONE` and `USER_ST` are set.
+//!
+//! The wake-up and instant-on timing, the link/loop state machine and the external clock and
+//! trigger inputs are not modelled, and the peak FIFO format stores every sample.
+//!
+//! Simulated time only moves when the [`SimClock`] is advanced. Pass a clock obtained from
+//! [`Adxl372Sim::clock`] wherever the driver expects a delay, and every delay the driver waits
+//! lets the sensor produce the matching samples.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::params::{HpfDisable, PowerMode};
+//! use adxl372::sim::Adxl372Sim;
+//!
+//! let mut sim = Adxl372Sim::new();
+//! sim.set_acceleration_mg([0, 0, 1_000]);
+//! let mut clock = sim.clock();
+//!
+//! // Keep the high-pass filter from removing gravity.
+//! let config = Config::new()
+//!     .hpf_disable(HpfDisable::Disabled)
+//!     .power_mode(PowerMode::Measure)
+//!     .build();
+//! let mut device = Adxl372::new(sim, config);
+//! device.init(&mut clock).unwrap();
+//!
+//! clock.advance(Duration::from_millis(10));
+//! assert_eq!(device.read_xyz_raw().unwrap(), [0, 0, 10]);
+//! ```
+
+use core::cell::Cell;
+use core::convert::Infallible;
+use core::time::Duration;
+use std::collections::VecDeque;
+use std::rc::Rc;
+
+pub mod stimulus;
+
+use self::stimulus::{SignalChain, Stimulus};
+
+use crate::activity::{
+    MotionThreshold, THRESHOLD_BYTES, ThresholdMode, activity_time_ms, inactivity_time_ms,
+};
+use crate::dump::reset_values;
+use crate::fifo::{FIFO_CAPACITY, FIFO_SERIES_START_FLAG, FifoSettings, axis_layout};
+use crate::interface::Adxl372Interface;
+#[cfg(feature = "async")]
+use crate::interface::Adxl372InterfaceAsync;
+use crate::params::{FifoMode, OutputDataRate, PowerMode};
+use crate::registers::{
+    FifoControl, Hpf, Measure, Offset, PowerControl, REG_FIFO_CTL, REG_FIFO_DATA, REG_FIFO_ENTRIES,
+    REG_FIFO_ENTRIES2, REG_FIFO_SAMPLES, REG_HPF, REG_MAXPEAK_X_H, REG_MAXPEAK_Z_L, REG_MEASURE,
+    REG_OFFSET_X, REG_POWER_CTL, REG_RESET, REG_SELF_TEST, REG_STATUS, REG_STATUS2,
+    REG_THRESH_ACT_X_H, REG_THRESH_ACT2_X_H, REG_THRESH_INACT_X_H, REG_TIME_ACT, REG_TIME_INACT_H,
+    REG_TIME_INACT_L, REG_TIMING, REG_XDATA_H, REG_ZDATA_L, RESET_COMMAND, Register, SelfTest,
+    Status, Status2, Timing,
+};
+use crate::units::{MG_PER_LSB, raw_to_mg};
+
+/// Time from setting `ST` until the simulated self-test reports `ST_DONE`.
+pub const SELF_TEST_DURATION: Duration = Duration::from_millis(300);
+/// Z-axis deflection applied during the self-test unless overridden, in LSB.
+pub const DEFAULT_SELF_TEST_RESPONSE_LSB: i16 = 20;
+
+// Number of modelled addresses, `DEVID_AD` through `FIFO_DATA`.
+const REGISTER_COUNT: usize = REG_FIFO_DATA as usize + 1;
+// The self-test deflection starts after this part of the test has elapsed.
+const SELF_TEST_SETTLE_NS: u64 = 100_000_000;
+const AXIS_MIN_LSB: i32 = -2048;
+const AXIS_MAX_LSB: i32 = 2047;
+
+/// Shared simulated time base.
+///
+/// Clones share the same time, so the clock handed to the driver as its delay advances the
+/// simulator it was taken from.
+#[derive(Debug, Clone, Default)]
+pub struct SimClock {
+    now_ns: Rc<Cell<u64>>,
+}
+
+impl SimClock {
+    /// Creates a clock starting at zero.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the elapsed simulated time.
+    pub fn now(&self) -> Duration {
+        Duration::from_nanos(self.now_ns.get())
+    }
+
+    /// Moves simulated time forward.
+    pub fn advance(&self, duration: Duration) {
+        let step = u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX);
+        self.advance_ns(step);
+    }
+
+    fn advance_ns(&self, step: u64) {
+        self.now_ns.set(self.now_ns.get().saturating_add(step));
+    }
+
+    fn now_ns(&self) -> u64 {
+        self.now_ns.get()
+    }
+}
+
+impl embedded_hal::delay::DelayNs for SimClock {
+    fn delay_ns(&mut self, ns: u32) {
+        self.advance_ns(u64::from(ns));
+    }
+}
+
+#[cfg(feature = "async")]
+impl embedded_hal_async::delay::DelayNs for SimClock {
+    async fn delay_ns(&mut self, ns: u32) {
+        self.advance_ns(u64::from(ns));
+    }
+}
+
+/// Stateful register-level ADXL372 model.
+#[derive(Debug, Clone)]
+pub struct Adxl372Sim {
+    clock: SimClock,
+    registers: [u8; REGISTER_COUNT],
+    stimulus: Stimulus,
+    // Simulated time the stimulus was applied; waveform time is measured from here.
+    stimulus_origin_ns: u64,
+    signal: SignalChain,
+    self_test_response_lsb: i16,
+    // Time of the next sample while the device is out of standby.
+    next_sample_ns: Option<u64>,
+    self_test_started_ns: Option<u64>,
+    sample: [i16; 3],
+    peak: [i16; 3],
+    fifo: VecDeque<[u8; 2]>,
+    // Second byte of a FIFO entry whose first byte has been read.
+    fifo_pending: Option<u8>,
+    status: Status,
+    status2: Status2,
+    awake: bool,
+    activity: Detector,
+    inactivity: Detector,
+}
+
+impl Default for Adxl372Sim {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Adxl372Sim {
+    // ==================================================================
+    // == Construction & Stimulus =======================================
+    // ==================================================================
+    /// Creates a simulator in its power-on state with its own clock.
+    pub fn new() -> Self {
+        Self::with_clock(SimClock::new())
+    }
+
+    /// Creates a simulator in its power-on state driven by `clock`.
+    pub fn with_clock(clock: SimClock) -> Self {
+        let mut sim = Self {
+            clock,
+            registers: [0; REGISTER_COUNT],
+            stimulus: Stimulus::default(),
+            stimulus_origin_ns: 0,
+            signal: SignalChain::default(),
+            self_test_response_lsb: DEFAULT_SELF_TEST_RESPONSE_LSB,
+            next_sample_ns: None,
+            self_test_started_ns: None,
+            sample: [0; 3],
+            peak: [0; 3],
+            fifo: VecDeque::with_capacity(usize::from(FIFO_CAPACITY)),
+            fifo_pending: None,
+            status: Status::new(),
+            status2: Status2::new(),
+            awake: false,
+            activity: Detector::default(),
+            inactivity: Detector::default(),
+        };
+        sim.soft_reset();
+        sim
+    }
+
+    /// Returns a handle to the simulator's clock.
+    pub fn clock(&self) -> SimClock {
+        self.clock.clone()
+    }
+
+    /// Moves simulated time forward; samples are produced on the next bus access.
+    pub fn advance(&mut self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
+    /// Sets a constant acceleration applied to the sensor, in milli-g per axis.
+    pub fn set_acceleration_mg(&mut self, acceleration_mg: [i32; 3]) {
+        self.set_stimulus(Stimulus::constant_mg(acceleration_mg));
+    }
+
+    /// Returns the acceleration the stimulus applies at the current time, in milli-g per axis.
+    pub fn acceleration_mg(&self) -> [i32; 3] {
+        self.stimulus_at(self.clock.now_ns())
+            .map(|mg| mg.round() as i32)
+    }
+
+    /// Applies `stimulus` to the sensor; its waveforms start at the current simulated time.
+    pub fn set_stimulus(&mut self, stimulus: Stimulus) {
+        self.catch_up();
+        self.stimulus = stimulus;
+        self.stimulus_origin_ns = self.clock.now_ns();
+    }
+
+    /// Returns the stimulus applied to the sensor.
+    pub fn stimulus(&self) -> &Stimulus {
+        &self.stimulus
+    }
+
+    /// Sets the Z-axis deflection produced by the self-test, in LSB.
+    ///
+    /// A response below the driver's pass threshold makes [`run_self_test`] fail.
+    ///
+    /// [`run_self_test`]: crate::self_test::run_self_test
+    pub fn set_self_test_response(&mut self, response_lsb: i16) {
+        self.self_test_response_lsb = response_lsb;
+    }
+
+    // ==================================================================
+    // == Inspection ====================================================
+    // ==================================================================
+    /// Returns the value `address` would read, without clear-on-read or FIFO side effects.
+    pub fn peek(&mut self, address: u8) -> u8 {
+        self.catch_up();
+        self.peek_register(address)
+    }
+
+    /// Returns the number of entries currently held in the FIFO.
+    pub fn fifo_entries(&mut self) -> usize {
+        self.catch_up();
+        self.fifo.len()
+    }
+
+    /// Returns the power mode selected in `POWER_CTL`.
+    pub fn power_mode(&self) -> PowerMode {
+        self.power_control().mode()
+    }
+
+    // ==================================================================
+    // == Register Access ===============================================
+    // ==================================================================
+    fn read_byte(&mut self, address: u8) -> u8 {
+        let value = self.peek_register(address);
+        match address {
+            REG_STATUS => self.status = Status::new(),
+            REG_STATUS2 => self.status2 = Status2::new(),
+            REG_FIFO_DATA => return self.pop_fifo_byte(),
+            REG_MAXPEAK_X_H..=REG_MAXPEAK_Z_L if (address - REG_MAXPEAK_X_H) % 2 == 1 => {
+                self.peak[usize::from((address - REG_MAXPEAK_X_H) / 2)] = 0;
+            }
+            _ => {}
+        }
+        value
+    }
+
+    fn write_byte(&mut self, address: u8, value: u8) {
+        match address {
+            REG_RESET if value == RESET_COMMAND => self.soft_reset(),
+            REG_SELF_TEST => self.write_self_test(SelfTest::from(value)),
+            REG_POWER_CTL => {
+                let was_standby = self.power_mode() == PowerMode::Standby;
+                self.registers[usize::from(address)] = value;
+                match (was_standby, self.power_mode() == PowerMode::Standby) {
+                    (true, false) => self.start_sampling(),
+                    (false, true) => self.next_sample_ns = None,
+                    _ => {}
+                }
+            }
+            REG_FIFO_CTL => {
+                self.registers[usize::from(address)] = value;
+                if FifoControl::from(value).mode() == FifoMode::Bypass {
+                    self.fifo.clear();
+                    self.fifo_pending = None;
+                }
+            }
+            REG_OFFSET_X..REG_SELF_TEST => self.registers[usize::from(address)] = value,
+            // Read-only and reserved addresses ignore writes.
+            _ => {}
+        }
+    }
+
+    // Value of `address` as seen by a read, before any clear-on-read side effect.
+    fn peek_register(&self, address: u8) -> u8 {
+        match address {
+            REG_FIFO_DATA => self
+                .fifo_pending
+                .or_else(|| self.fifo.front().map(|entry| entry[0]))
+                .unwrap_or(0),
+            _ => self.register_value(address),
+        }
+    }
+
+    fn register_value(&self, address: u8) -> u8 {
+        match address {
+            REG_STATUS => u8::from(self.status_register()),
+            REG_STATUS2 => u8::from(self.status2),
+            REG_FIFO_ENTRIES2 => ((self.fifo.len() >> 8) & 0x03) as u8,
+            REG_FIFO_ENTRIES => (self.fifo.len() & 0xFF) as u8,
+            REG_XDATA_H..=REG_ZDATA_L => axis_byte(&self.sample, address - REG_XDATA_H),
+            REG_MAXPEAK_X_H..=REG_MAXPEAK_Z_L => axis_byte(&self.peak, address - REG_MAXPEAK_X_H),
+            REG_RESET => 0,
+            _ => self
+                .registers
+                .get(usize::from(address))
+                .copied()
+                .unwrap_or(0),
+        }
+    }
+
+    fn status_register(&self) -> Status {
+        let fifo = self.fifo_settings();
+        let entries = self.fifo.len();
+        self.status
+            .with_fifo_ready(entries > 0 && entries >= usize::from(fifo.watermark))
+            .with_fifo_full(entries >= usize::from(FIFO_CAPACITY))
+            .with_awake(self.awake)
+    }
+
+    fn soft_reset(&mut self) {
+        self.registers = [0; REGISTER_COUNT];
+        for (address, value) in reset_values() {
+            self.registers[usize::from(address)] = value;
+        }
+
+        self.next_sample_ns = None;
+        self.self_test_started_ns = None;
+        self.sample = [0; 3];
+        self.peak = [0; 3];
+        self.signal.reset();
+        self.fifo.clear();
+        self.fifo_pending = None;
+        self.status = Status::from(Status::RESET_VALUE.unwrap_or(0))
+            .with_fifo_ready(false)
+            .with_fifo_full(false)
+            .with_awake(false);
+        self.status2 = Status2::new();
+        self.awake = false;
+        self.activity = Detector::default();
+        self.inactivity = Detector::default();
+    }
+
+    // ==================================================================
+    // == Sampling ======================================================
+    // ==================================================================
+    // Produces every sample due up to the current simulated time.
+    fn catch_up(&mut self) {
+        let now = self.clock.now_ns();
+        while let Some(due) = self.next_sample_ns.filter(|due| *due <= now) {
+            self.take_sample(due);
+            self.next_sample_ns = Some(due + self.sample_period_ns());
+        }
+        self.update_self_test(now);
+    }
+
+    fn start_sampling(&mut self) {
+        self.next_sample_ns = Some(self.clock.now_ns() + self.sample_period_ns());
+        self.signal.reset();
+        self.activity = Detector::default();
+        self.inactivity = Detector::default();
+    }
+
+    fn take_sample(&mut self, at_ns: u64) {
+        self.update_self_test(at_ns);
+
+        let power = self.power_control();
+        let odr = self.odr();
+        let low_pass = (!power.lpf_disable()).then(|| {
+            Measure::from(self.registers[usize::from(REG_MEASURE)])
+                .bandwidth_or_err()
+                .map_or(odr.hz() / 2, |bandwidth| bandwidth.max_hz())
+        });
+        let high_pass = (!power.hpf_disable()).then(|| {
+            Hpf::from(self.registers[usize::from(REG_HPF)])
+                .corner()
+                .hz(odr)
+        });
+        let filtered = self.signal.process(
+            self.stimulus_at(at_ns),
+            f64::from(odr.hz()),
+            low_pass.map(f64::from),
+            high_pass.map(f64::from),
+        );
+
+        let mut sample = [0i16; 3];
+        for (axis, value) in sample.iter_mut().enumerate() {
+            let trim = Offset::from(self.registers[usize::from(REG_OFFSET_X) + axis]).code();
+            let mut lsb = (filtered[axis] / f64::from(MG_PER_LSB)).round() as i32 + i32::from(trim);
+            if axis == 2 && self.self_test_excited(at_ns) {
+                lsb += i32::from(self.self_test_response_lsb);
+            }
+            *value = lsb.clamp(AXIS_MIN_LSB, AXIS_MAX_LSB) as i16;
+        }
+
+        self.sample = sample;
+        self.status.set_data_ready(true);
+        for (peak, value) in self.peak.iter_mut().zip(sample) {
+            if value.unsigned_abs() > peak.unsigned_abs() {
+                *peak = value;
+            }
+        }
+
+        self.push_fifo(&sample);
+        self.run_detectors(&sample, at_ns);
+    }
+
+    fn stimulus_at(&self, at_ns: u64) -> [f64; 3] {
+        let elapsed = at_ns.saturating_sub(self.stimulus_origin_ns);
+        self.stimulus.sample_mg(Duration::from_nanos(elapsed))
+    }
+
+    fn sample_period_ns(&self) -> u64 {
+        1_000_000_000 / u64::from(self.odr().hz())
+    }
+
+    fn odr(&self) -> OutputDataRate {
+        Timing::from(self.registers[usize::from(REG_TIMING)])
+            .odr_or_err()
+            .unwrap_or(OutputDataRate::Od400Hz)
+    }
+
+    fn power_control(&self) -> PowerControl {
+        PowerControl::from(self.registers[usize::from(REG_POWER_CTL)])
+    }
+
+    // ==================================================================
+    // == FIFO ==========================================================
+    // ==================================================================
+    fn fifo_settings(&self) -> FifoSettings {
+        FifoSettings::from_registers(
+            self.registers[usize::from(REG_FIFO_SAMPLES)],
+            FifoControl::from(self.registers[usize::from(REG_FIFO_CTL)]),
+        )
+    }
+
+    fn push_fifo(&mut self, sample: &[i16; 3]) {
+        let settings = self.fifo_settings();
+        if settings.mode == FifoMode::Bypass {
+            return;
+        }
+
+        let (x, y, z) = axis_layout(settings.format);
+        let axes = [x, y, z];
+        let set_len = usize::from(settings.format.axis_count());
+        let capacity = usize::from(FIFO_CAPACITY);
+
+        if self.fifo.len() + set_len > capacity {
+            self.status.set_fifo_overrun(true);
+            if settings.mode == FifoMode::OldestSaved {
+                return;
+            }
+            while self.fifo.len() + set_len > capacity {
+                self.fifo.pop_front();
+            }
+        }
+
+        let mut flag = FIFO_SERIES_START_FLAG;
+        for (value, _) in sample.iter().zip(axes).filter(|(_, stored)| *stored) {
+            let [high, low] = (value << 4).to_be_bytes();
+            self.fifo.push_back([high, low | flag]);
+            flag = 0;
+        }
+    }
+
+    fn pop_fifo_byte(&mut self) -> u8 {
+        if let Some(low) = self.fifo_pending.take() {
+            return low;
+        }
+        match self.fifo.pop_front() {
+            Some([high, low]) => {
+                self.fifo_pending = Some(low);
+                high
+            }
+            None => 0,
+        }
+    }
+
+    // ==================================================================
+    // == Motion Detection ==============================================
+    // ==================================================================
+    fn run_detectors(&mut self, sample: &[i16; 3], at_ns: u64) {
+        let odr = self.odr();
+
+        let activity = self.threshold(REG_THRESH_ACT_X_H);
+        let activity_ms =
+            activity_time_ms(u32::from(self.registers[usize::from(REG_TIME_ACT)]), odr);
+        if self
+            .activity
+            .update(&activity, sample, at_ns, activity_ms, Comparison::Above)
+        {
+            self.status2.set_activity(true);
+            self.awake = true;
+        }
+
+        let inactivity = self.threshold(REG_THRESH_INACT_X_H);
+        let inactivity_counts = u32::from(u16::from_be_bytes([
+            self.registers[usize::from(REG_TIME_INACT_H)],
+            self.registers[usize::from(REG_TIME_INACT_L)],
+        ]));
+        let inactivity_ms = inactivity_time_ms(inactivity_counts, odr);
+        if self
+            .inactivity
+            .update(&inactivity, sample, at_ns, inactivity_ms, Comparison::Below)
+        {
+            self.status2.set_inactivity(true);
+            self.awake = false;
+        }
+
+        // Activity2 has no timer and compares against absolute thresholds only.
+        let activity2 = self.threshold(REG_THRESH_ACT2_X_H);
+        if Comparison::Above.holds(&activity2, sample, &[0; 3]) {
+            self.status2.set_activity2(true);
+        }
+    }
+
+    fn threshold(&self, first: u8) -> MotionThreshold {
+        let start = usize::from(first);
+        let mut raw = [0u8; THRESHOLD_BYTES];
+        raw.copy_from_slice(&self.registers[start..start + THRESHOLD_BYTES]);
+        MotionThreshold::from_registers(raw)
+    }
+
+    // ==================================================================
+    // == Self-Test =====================================================
+    // ==================================================================
+    fn write_self_test(&mut self, value: SelfTest) {
+        let address = usize::from(REG_SELF_TEST);
+        let current = SelfTest::from(self.registers[address]);
+
+        let next = if value.st() && !current.st() && self.power_mode() != PowerMode::Standby {
+            self.self_test_started_ns = Some(self.clock.now_ns());
+            SelfTest::new().with_st(true)
+        } else if !value.st() {
+            self.self_test_started_ns = None;
+            current.with_st(false)
+        } else {
+            current
+        };
+        self.registers[address] = u8::from(next);
+    }
+
+    fn update_self_test(&mut self, now_ns: u64) {
+        let Some(started) = self.self_test_started_ns else {
+            return;
+        };
+        if now_ns.saturating_sub(started) >= duration_ns(SELF_TEST_DURATION) {
+            self.self_test_started_ns = None;
+            self.registers[usize::from(REG_SELF_TEST)] = u8::from(
+                SelfTest::new()
+                    .with_st(false)
+                    .with_st_done(true)
+                    .with_user_st(true),
+            );
+        }
+    }
+
+    fn self_test_excited(&self, at_ns: u64) -> bool {
+        self.self_test_started_ns
+            .is_some_and(|started| at_ns.saturating_sub(started) >= SELF_TEST_SETTLE_NS)
+    }
+}
+
+impl Adxl372Interface for Adxl372Sim {
+    type Error = Infallible;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_many(self, register, core::slice::from_ref(&value))
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let mut value = [0u8; 1];
+        Adxl372Interface::read_many(self, register, &mut value)?;
+        Ok(value[0])
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        self.catch_up();
+        let mut address = register;
+        for slot in buf.iter_mut() {
+            *slot = self.read_byte(address);
+            if address != REG_FIFO_DATA {
+                address = address.wrapping_add(1);
+            }
+        }
+        Ok(())
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        self.catch_up();
+        for (address, value) in (register..=u8::MAX).zip(data) {
+            self.write_byte(address, *value);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl Adxl372InterfaceAsync for Adxl372Sim {
+    type Error = Infallible;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_register(self, register, value)
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        Adxl372Interface::read_register(self, register)
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::read_many(self, register, buf)
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_many(self, register, data)
+    }
+}
+
+/// Direction a detector compares samples against its threshold.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Comparison {
+    /// Any enabled axis exceeds the threshold (activity).
+    Above,
+    /// Every enabled axis stays at or below the threshold (inactivity).
+    Below,
+}
+
+impl Comparison {
+    fn holds(self, threshold: &MotionThreshold, sample: &[i16; 3], reference: &[i16; 3]) -> bool {
+        let axes = [threshold.axes.x, threshold.axes.y, threshold.axes.z];
+        if !threshold.axes.any() {
+            return false;
+        }
+
+        let mut exceeded = axes
+            .iter()
+            .zip(sample.iter().zip(reference))
+            .filter(|(enabled, _)| **enabled)
+            .map(|(_, (value, reference))| {
+                let delta_mg = raw_to_mg(*value) - raw_to_mg(*reference);
+                delta_mg.unsigned_abs() > threshold.threshold_mg
+            });
+
+        match self {
+            Self::Above => exceeded.any(|over| over),
+            Self::Below => exceeded.all(|over| !over),
+        }
+    }
+}
+
+/// Timer and reference state of the activity or inactivity detector.
+#[derive(Debug, Clone, Copy, Default)]
+struct Detector {
+    // Time the detector condition started to hold.
+    since_ns: Option<u64>,
+    // Reference sample for referenced mode, captured on the first sample and after events.
+    reference: Option<[i16; 3]>,
+}
+
+impl Detector {
+    // Returns `true` when the condition held for `duration_ms` and the event fires.
+    fn update(
+        &mut self,
+        threshold: &MotionThreshold,
+        sample: &[i16; 3],
+        at_ns: u64,
+        duration_ms: u32,
+        comparison: Comparison,
+    ) -> bool {
+        let reference = match threshold.mode {
+            ThresholdMode::Absolute => [0; 3],
+            ThresholdMode::Referenced => *self.reference.get_or_insert(*sample),
+        };
+
+        if !comparison.holds(threshold, sample, &reference) {
+            self.since_ns = None;
+            return false;
+        }
+
+        let since = *self.since_ns.get_or_insert(at_ns);
+        if at_ns - since < u64::from(duration_ms) * 1_000_000 {
+            return false;
+        }
+
+        self.since_ns = None;
+        if threshold.mode == ThresholdMode::Referenced {
+            self.reference = Some(*sample);
+        }
+        true
+    }
+}
+
+// Returns the byte at `offset` of the `X_H, X_L, Y_H, Y_L, Z_H, Z_L` encoding of `axes`.
+fn axis_byte(axes: &[i16; 3], offset: u8) -> u8 {
+    let bytes = (axes[usize::from(offset / 2)] << 4).to_be_bytes();
+    bytes[usize::from(offset % 2)]
+}
+
+fn duration_ns(duration: Duration) -> u64 {
+    u64::try_from(duration.as_nanos()).unwrap_or(u64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::stimulus::{Stimulus, Waveform};
+    use super::{Adxl372Sim, SELF_TEST_DURATION};
+    use crate::activity::{AxisMask, MotionDetector, MotionThreshold, ThresholdMode};
+    use crate::config::Config;
+    use crate::device::Adxl372;
+    use crate::error::Error;
+    use crate::fifo::Sample;
+    use crate::interface::Adxl372Interface;
+    use crate::params::{
+        Bandwidth, FifoFormat, FifoMode, HighPassCorner, HpfDisable, OutputDataRate, PowerMode,
+    };
+    use crate::registers::{
+        REG_FIFO_DATA, REG_POWER_CTL, REG_RESET, REG_SELF_TEST, REG_STATUS, REG_TIMING,
+        RESET_COMMAND,
+    };
+    use core::time::Duration;
+
+    /// Checks reset values, read-only semantics and the soft reset command.
+    #[test]
+    fn registers_follow_reset_and_access_rules() {
+        let mut device = Adxl372::new(Adxl372Sim::new(), Config::default());
+        assert_eq!(device.check_ids().unwrap(), 0x02);
+
+        let mut sim = Adxl372Sim::new();
+        assert_eq!(sim.read_register(REG_STATUS).unwrap(), 0xA0);
+        assert_eq!(sim.read_register(REG_STATUS).unwrap(), 0x00);
+        sim.write_register(REG_STATUS, 0xFF).unwrap();
+        assert_eq!(sim.read_register(REG_STATUS).unwrap(), 0x00);
+
+        sim.write_register(REG_TIMING, 0x60).unwrap();
+        assert_eq!(sim.read_register(REG_RESET).unwrap(), 0x00);
+        sim.write_register(REG_RESET, 0x01).unwrap();
+        assert_eq!(sim.read_register(REG_TIMING).unwrap(), 0x60);
+        sim.write_register(REG_RESET, RESET_COMMAND).unwrap();
+        assert_eq!(sim.read_register(REG_TIMING).unwrap(), 0x00);
+        assert_eq!(sim.read_register(REG_FIFO_DATA).unwrap(), 0x00);
+    }
+
+    /// Runs the real `init` sequence, including the self-test, against the model.
+    #[test]
+    fn driver_init_runs_self_test() {
+        let sim = Adxl372Sim::new();
+        let mut clock = sim.clock();
+        let config = Config::new().power_mode(PowerMode::Measure).build();
+        let mut device = Adxl372::new(sim, config);
+
+        let report = device.run_self_test(&mut clock).unwrap();
+        assert!(report.passed);
+        assert!(report.delta_z_lsb >= 5);
+        assert!(clock.now() >= SELF_TEST_DURATION);
+
+        device.init(&mut clock).unwrap();
+        let (mut sim, _) = device.release();
+        assert_eq!(sim.power_mode(), PowerMode::Measure);
+        assert_eq!(sim.peek(REG_SELF_TEST), 0x00);
+
+        sim.set_self_test_response(0);
+        let mut device = Adxl372::new(sim, config);
+        assert_eq!(device.init(&mut clock), Err(Error::SelfTestFailed));
+    }
+
+    /// Ensures the FIFO fills at the configured ODR and drains through the driver.
+    #[test]
+    fn fifo_fills_at_odr() {
+        let mut sim = Adxl372Sim::new();
+        sim.set_acceleration_mg([1_000, -2_000, 300]);
+        let clock = sim.clock();
+        let config = Config::new()
+            .odr(OutputDataRate::Od800Hz)
+            .fifo_mode(FifoMode::Stream)
+            .fifo_format(FifoFormat::XYZ)
+            .fifo_watermark(12)
+            .hpf_disable(HpfDisable::Disabled)
+            .power_mode(PowerMode::Measure)
+            .build();
+        let mut device = Adxl372::new(sim, Config::default());
+        device.configure_burst(config, &mut clock.clone()).unwrap();
+        assert_eq!(device.read_fifo_level().unwrap(), 0);
+
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(device.read_fifo_level().unwrap(), 12);
+        assert!(device.read_status().unwrap().fifo_rdy);
+
+        let mut samples = [Sample::default(); 4];
+        assert_eq!(device.read_fifo_samples(&mut samples).unwrap(), 4);
+        assert_eq!(samples[3].x, Some(10));
+        assert_eq!(samples[3].y, Some(-20));
+        assert_eq!(samples[3].z, Some(3));
+        assert_eq!(device.read_fifo_level().unwrap(), 0);
+
+        device
+            .configure_power_ctl(None, None, None, None, None, Some(PowerMode::Standby))
+            .unwrap();
+        clock.advance(Duration::from_millis(5));
+        assert_eq!(device.read_fifo_level().unwrap(), 0);
+        let (mut sim, _) = device.release();
+        assert_eq!(sim.peek(REG_POWER_CTL) & 0x03, 0x00);
+    }
+
+    /// Verifies activity latches in STATUS2 and clears once read.
+    #[test]
+    fn activity_latches_until_read() {
+        let sim = Adxl372Sim::new();
+        let clock = sim.clock();
+        let threshold = MotionThreshold::new(1_000, AxisMask::ALL, ThresholdMode::Absolute);
+        let config = Config::new()
+            .activity(MotionDetector::new(threshold, 0))
+            .hpf_disable(HpfDisable::Disabled)
+            .power_mode(PowerMode::Measure)
+            .build();
+        let mut device = Adxl372::new(sim, config);
+        device.configure(config, &mut clock.clone()).unwrap();
+
+        clock.advance(Duration::from_millis(10));
+        assert!(!device.read_status().unwrap().activity);
+
+        device.interface_mut().set_acceleration_mg([0, 0, 5_000]);
+        clock.advance(Duration::from_millis(10));
+        let status = device.read_status().unwrap();
+        assert!(status.activity && status.awake && status.data_rdy);
+
+        let status = device.read_status().unwrap();
+        assert!(!status.activity && !status.data_rdy && status.awake);
+        assert_eq!(device.read_peak_xyz_mg().unwrap().as_mg(), [0, 0, 5_000]);
+        assert_eq!(device.read_peak_xyz_mg().unwrap().as_mg(), [0, 0, 0]);
+    }
+
+    /// Drives a half-sine shock through the filters into the data, peak and FIFO paths.
+    #[test]
+    fn shock_reaches_peak_and_fifo() {
+        let mut sim = Adxl372Sim::new();
+        let clock = sim.clock();
+        let config = Config::new()
+            .odr(OutputDataRate::Od6400Hz)
+            .bandwidth(Bandwidth::Bw3200Hz)
+            .hpf_corner(HighPassCorner::Corner3)
+            .fifo_mode(FifoMode::Stream)
+            .fifo_format(FifoFormat::Z)
+            .power_mode(PowerMode::Measure)
+            .build();
+        let mut device = Adxl372::new(sim.clone(), Config::default());
+        device.configure_burst(config, &mut clock.clone()).unwrap();
+
+        // The high-pass filter removes gravity once the device is sampling.
+        device.interface_mut().set_stimulus(Stimulus::at_rest());
+        clock.advance(Duration::from_millis(500));
+        assert_eq!(device.read_xyz_raw().unwrap(), [0, 0, 0]);
+
+        let shock = Waveform::HalfSine {
+            start: Duration::from_millis(1),
+            duration: Duration::from_millis(5),
+            peak_mg: 300_000.0,
+        };
+        device.interface_mut().set_stimulus(
+            Stimulus::at_rest().z(Waveform::Sum(std::vec![Waveform::gravity(), shock])),
+        );
+        device.read_peak_xyz_mg().unwrap();
+        clock.advance(Duration::from_millis(10));
+
+        // Clipped at 200 g, then slightly eroded by the high-pass filter.
+        let peak = device.read_peak_xyz_mg().unwrap().as_mg();
+        assert!((180_000..200_000).contains(&peak[2]), "{peak:?}");
+        // The FIFO holds the last 80 ms of Z samples, shock included.
+        let mut samples = [Sample::default(); 512];
+        let read = device.read_fifo_samples(&mut samples).unwrap();
+        assert_eq!(read, 512);
+        assert!(
+            samples
+                .iter()
+                .any(|sample| sample.z.is_some_and(|z| z > 1_900))
+        );
+
+        sim = device.release().0;
+        assert_eq!(sim.acceleration_mg(), [0, 0, 1_000]);
+    }
+}
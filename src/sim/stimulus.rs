@@ -0,0 +1,513 @@
+//! Acceleration waveforms driving the simulated sensor.
+//!
+//! A [`Stimulus`] holds one [`Waveform`] per axis, evaluated in milli-g at every simulated
+//! sample. Waveform time starts when the stimulus is applied with
+//! [`Adxl372Sim::set_stimulus`](super::Adxl372Sim::set_stimulus), so a shock scheduled at
+//! 10 ms happens 10 ms after that call regardless of how long `init` took.
+//!
+//! Before reaching the data registers every sample is clipped to the ±200 g range and passed
+//! through the low-pass (`MEASURE.BANDWIDTH`) and high-pass (`HPF.HPF_CORNER`) filters unless
+//! `POWER_CTL` disables them. Both filters are modelled as single-pole IIR sections.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use core::time::Duration;
+//! use adxl372::sim::stimulus::{Stimulus, Waveform};
+//!
+//! // Resting on a table, then a 50 g, 2 ms half-sine shock on Z after 100 ms.
+//! let shock = Waveform::HalfSine {
+//!     start: Duration::from_millis(100),
+//!     duration: Duration::from_millis(2),
+//!     peak_mg: 50_000.0,
+//! };
+//! let stimulus = Stimulus::at_rest().z(Waveform::Sum(vec![Waveform::gravity(), shock]));
+//!
+//! assert_eq!(stimulus.sample_mg(Duration::ZERO), [0.0, 0.0, 1_000.0]);
+//! assert_eq!(stimulus.sample_mg(Duration::from_millis(101))[2], 51_000.0);
+//! ```
+
+use core::f64::consts::PI;
+use core::fmt;
+use core::time::Duration;
+use std::vec::Vec;
+
+/// Full-scale range of the ADXL372 in milli-g; inputs beyond it are clipped.
+pub const FULL_SCALE_MG: f64 = 200_000.0;
+/// Standard gravity in milli-g.
+pub const GRAVITY_MG: f64 = 1_000.0;
+
+/// Acceleration applied to one axis as a function of time, in milli-g.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Waveform {
+    /// Constant acceleration, e.g. gravity.
+    Constant {
+        /// Acceleration in milli-g.
+        mg: f64,
+    },
+    /// Half-sine shock pulse, `peak_mg · sin(π·t / duration)`, zero outside the pulse.
+    HalfSine {
+        /// Pulse start.
+        start: Duration,
+        /// Pulse length.
+        duration: Duration,
+        /// Pulse amplitude in milli-g.
+        peak_mg: f64,
+    },
+    /// Haversine shock pulse, `peak_mg · sin²(π·t / duration)`, zero outside the pulse.
+    Haversine {
+        /// Pulse start.
+        start: Duration,
+        /// Pulse length.
+        duration: Duration,
+        /// Pulse amplitude in milli-g.
+        peak_mg: f64,
+    },
+    /// Sine whose frequency moves linearly from `start_hz` to `end_hz`, zero outside the sweep.
+    SineSweep {
+        /// Sweep start.
+        start: Duration,
+        /// Sweep length.
+        duration: Duration,
+        /// Amplitude in milli-g.
+        amplitude_mg: f64,
+        /// Frequency at the start of the sweep in hertz.
+        start_hz: f64,
+        /// Frequency at the end of the sweep in hertz.
+        end_hz: f64,
+    },
+    /// Gaussian white noise with the given RMS level.
+    ///
+    /// The value at a given time only depends on `seed`, so runs are reproducible.
+    RandomVibration {
+        /// RMS level in milli-g.
+        rms_mg: f64,
+        /// Noise seed.
+        seed: u64,
+    },
+    /// Recorded `(time, milli-g)` points, interpolated linearly and held after the last one.
+    Samples(Vec<(Duration, f64)>),
+    /// Sum of several waveforms.
+    Sum(Vec<Waveform>),
+}
+
+impl Waveform {
+    /// Returns an axis with no acceleration.
+    pub const fn zero() -> Self {
+        Self::Constant { mg: 0.0 }
+    }
+
+    /// Returns a constant 1 g, for the axis aligned with gravity.
+    pub const fn gravity() -> Self {
+        Self::Constant { mg: GRAVITY_MG }
+    }
+
+    /// Evaluates the waveform `t` after the stimulus was applied.
+    pub fn value_mg(&self, t: Duration) -> f64 {
+        match self {
+            Self::Constant { mg } => *mg,
+            Self::HalfSine {
+                start,
+                duration,
+                peak_mg,
+            } => {
+                pulse_phase(t, *start, *duration).map_or(0.0, |phase| peak_mg * (PI * phase).sin())
+            }
+            Self::Haversine {
+                start,
+                duration,
+                peak_mg,
+            } => pulse_phase(t, *start, *duration)
+                .map_or(0.0, |phase| peak_mg * (PI * phase).sin().powi(2)),
+            Self::SineSweep {
+                start,
+                duration,
+                amplitude_mg,
+                start_hz,
+                end_hz,
+            } => pulse_phase(t, *start, *duration).map_or(0.0, |phase| {
+                let length = duration.as_secs_f64();
+                let elapsed = phase * length;
+                let cycles = start_hz * elapsed + (end_hz - start_hz) * elapsed * phase / 2.0;
+                amplitude_mg * (2.0 * PI * cycles).sin()
+            }),
+            Self::RandomVibration { rms_mg, seed } => rms_mg * gaussian(*seed, t),
+            Self::Samples(points) => interpolate(points, t),
+            Self::Sum(parts) => parts.iter().map(|part| part.value_mg(t)).sum(),
+        }
+    }
+}
+
+impl Default for Waveform {
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+/// Per-axis acceleration input for the simulator.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Stimulus {
+    axes: [Waveform; 3],
+}
+
+impl Stimulus {
+    /// Creates a stimulus from X, Y and Z waveforms.
+    pub fn new(x: Waveform, y: Waveform, z: Waveform) -> Self {
+        Self { axes: [x, y, z] }
+    }
+
+    /// Returns a stimulus holding each axis at a constant acceleration in milli-g.
+    pub fn constant_mg(mg: [i32; 3]) -> Self {
+        Self::new(
+            Waveform::Constant {
+                mg: f64::from(mg[0]),
+            },
+            Waveform::Constant {
+                mg: f64::from(mg[1]),
+            },
+            Waveform::Constant {
+                mg: f64::from(mg[2]),
+            },
+        )
+    }
+
+    /// Returns a sensor lying flat: 1 g on Z and nothing on X and Y.
+    pub fn at_rest() -> Self {
+        Self::new(Waveform::zero(), Waveform::zero(), Waveform::gravity())
+    }
+
+    /// Replaces the X-axis waveform.
+    pub fn x(mut self, waveform: Waveform) -> Self {
+        self.axes[0] = waveform;
+        self
+    }
+
+    /// Replaces the Y-axis waveform.
+    pub fn y(mut self, waveform: Waveform) -> Self {
+        self.axes[1] = waveform;
+        self
+    }
+
+    /// Replaces the Z-axis waveform.
+    pub fn z(mut self, waveform: Waveform) -> Self {
+        self.axes[2] = waveform;
+        self
+    }
+
+    /// Returns the X, Y and Z waveforms.
+    pub fn axes(&self) -> &[Waveform; 3] {
+        &self.axes
+    }
+
+    /// Evaluates every axis `t` after the stimulus was applied, in milli-g.
+    pub fn sample_mg(&self, t: Duration) -> [f64; 3] {
+        [
+            self.axes[0].value_mg(t),
+            self.axes[1].value_mg(t),
+            self.axes[2].value_mg(t),
+        ]
+    }
+
+    /// Parses a recording with one `time_s,x_g,y_g,z_g` row per line.
+    ///
+    /// Times are in seconds and accelerations in g. Blank lines, lines starting with `#` and a
+    /// leading header row are skipped; a first row only counts as a header when one of its
+    /// columns is not a number. Times must not decrease or exceed the [`Duration`] range.
+    pub fn from_csv(text: &str) -> Result<Self, CsvError> {
+        let mut axes: [Vec<(Duration, f64)>; 3] = Default::default();
+        let mut header_allowed = true;
+
+        for (index, line) in text.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields = match parse_row(line) {
+                Ok(fields) => fields,
+                Err(CsvError::InvalidNumber { .. }) if header_allowed => {
+                    header_allowed = false;
+                    continue;
+                }
+                Err(err) => return Err(err.at(number)),
+            };
+            header_allowed = false;
+
+            let time = Duration::try_from_secs_f64(fields[0])
+                .map_err(|_| CsvError::InvalidTime { line: number })?;
+            if axes[0].last().is_some_and(|(last, _)| time < *last) {
+                return Err(CsvError::InvalidTime { line: number });
+            }
+
+            for (points, value) in axes.iter_mut().zip(&fields[1..]) {
+                points.push((time, value * GRAVITY_MG));
+            }
+        }
+
+        if axes[0].is_empty() {
+            return Err(CsvError::Empty);
+        }
+
+        let [x, y, z] = axes;
+        Ok(Self::new(
+            Waveform::Samples(x),
+            Waveform::Samples(y),
+            Waveform::Samples(z),
+        ))
+    }
+}
+
+/// Errors reported by [`Stimulus::from_csv`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CsvError {
+    /// The recording has no data rows.
+    Empty,
+    /// A row does not have exactly four columns.
+    ColumnCount {
+        /// One-based line number.
+        line: usize,
+    },
+    /// A column is not a number.
+    InvalidNumber {
+        /// One-based line number.
+        line: usize,
+    },
+    /// A timestamp is negative, not finite or earlier than the previous row.
+    InvalidTime {
+        /// One-based line number.
+        line: usize,
+    },
+}
+
+impl CsvError {
+    fn at(self, line: usize) -> Self {
+        match self {
+            Self::ColumnCount { .. } => Self::ColumnCount { line },
+            Self::InvalidNumber { .. } => Self::InvalidNumber { line },
+            Self::InvalidTime { .. } => Self::InvalidTime { line },
+            Self::Empty => Self::Empty,
+        }
+    }
+}
+
+impl fmt::Display for CsvError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "recording has no data rows"),
+            Self::ColumnCount { line } => write!(f, "line {line}: expected 4 columns"),
+            Self::InvalidNumber { line } => write!(f, "line {line}: invalid number"),
+            Self::InvalidTime { line } => write!(f, "line {line}: invalid or decreasing time"),
+        }
+    }
+}
+
+impl std::error::Error for CsvError {}
+
+/// Clipping and filter state applied between the stimulus and the data registers.
+#[derive(Debug, Clone, Copy, Default)]
+pub(super) struct SignalChain {
+    // Low-pass output and high-pass input/output of the previous sample, per axis.
+    state: Option<[(f64, f64, f64); 3]>,
+}
+
+impl SignalChain {
+    /// Forgets the filter history, e.g. when the device leaves standby.
+    pub(super) fn reset(&mut self) {
+        self.state = None;
+    }
+
+    /// Clips and filters one sample taken at `rate_hz`.
+    ///
+    /// `low_pass_hz` and `high_pass_hz` are the filter corners, or `None` when a filter is
+    /// disabled. The filters start settled on the first sample after a reset.
+    pub(super) fn process(
+        &mut self,
+        input_mg: [f64; 3],
+        rate_hz: f64,
+        low_pass_hz: Option<f64>,
+        high_pass_hz: Option<f64>,
+    ) -> [f64; 3] {
+        let clipped = input_mg.map(|mg| mg.clamp(-FULL_SCALE_MG, FULL_SCALE_MG));
+        let dt = 1.0 / rate_hz;
+        let lp_alpha = low_pass_hz.map(|hz| 1.0 - (-2.0 * PI * hz * dt).exp());
+        let hp_alpha = high_pass_hz.map(|hz| 1.0 / (1.0 + 2.0 * PI * hz * dt));
+
+        let state = self.state.get_or_insert(clipped.map(|mg| (mg, mg, 0.0)));
+
+        let mut output = [0.0; 3];
+        for ((value, (low, hp_input, hp_output)), out) in
+            clipped.into_iter().zip(state.iter_mut()).zip(&mut output)
+        {
+            let low_passed = match lp_alpha {
+                Some(alpha) => *low + alpha * (value - *low),
+                None => value,
+            };
+            *out = match hp_alpha {
+                Some(alpha) => alpha * (*hp_output + low_passed - *hp_input),
+                None => low_passed,
+            };
+            *low = low_passed;
+            *hp_input = low_passed;
+            *hp_output = *out;
+        }
+        output
+    }
+}
+
+// Returns the position within a `start..start + duration` window as a fraction of it.
+fn pulse_phase(t: Duration, start: Duration, duration: Duration) -> Option<f64> {
+    let elapsed = t.checked_sub(start)?;
+    (elapsed <= duration && !duration.is_zero())
+        .then(|| elapsed.as_secs_f64() / duration.as_secs_f64())
+}
+
+fn interpolate(points: &[(Duration, f64)], t: Duration) -> f64 {
+    let next = points.partition_point(|(time, _)| *time <= t);
+    match (
+        next.checked_sub(1).map(|index| points[index]),
+        points.get(next),
+    ) {
+        (Some((t0, v0)), Some((t1, v1))) => {
+            let span = (*t1 - t0).as_secs_f64();
+            if span == 0.0 {
+                *v1
+            } else {
+                v0 + (v1 - v0) * (t - t0).as_secs_f64() / span
+            }
+        }
+        (Some((_, value)), None) => value,
+        (None, Some((_, value))) => *value,
+        (None, None) => 0.0,
+    }
+}
+
+// Standard normal value derived from `seed` and `t` with a Box-Muller transform.
+fn gaussian(seed: u64, t: Duration) -> f64 {
+    let key = seed ^ u64::try_from(t.as_nanos()).unwrap_or(u64::MAX);
+    let u1 = unit(splitmix64(key));
+    let u2 = unit(splitmix64(key ^ 0x9E37_79B9_7F4A_7C15));
+    (-2.0 * (1.0 - u1).ln()).sqrt() * (2.0 * PI * u2).cos()
+}
+
+fn splitmix64(value: u64) -> u64 {
+    let mut z = value.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+// Maps 53 random bits onto `[0, 1)`.
+fn unit(bits: u64) -> f64 {
+    (bits >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn parse_row(line: &str) -> Result<[f64; 4], CsvError> {
+    let mut fields = [0.0; 4];
+    let mut columns = line.split(',');
+    for field in fields.iter_mut() {
+        let column = columns.next().ok_or(CsvError::ColumnCount { line: 0 })?;
+        *field = column
+            .trim()
+            .parse()
+            .map_err(|_| CsvError::InvalidNumber { line: 0 })?;
+    }
+    if columns.next().is_some() {
+        return Err(CsvError::ColumnCount { line: 0 });
+    }
+    Ok(fields)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{CsvError, SignalChain, Stimulus, Waveform};
+    use core::time::Duration;
+
+    const MS: Duration = Duration::from_millis(1);
+
+    /// Checks the pulse shapes at their start, centre and end.
+    #[test]
+    fn shock_pulses_follow_their_shape() {
+        let half_sine = Waveform::HalfSine {
+            start: 10 * MS,
+            duration: 4 * MS,
+            peak_mg: 100_000.0,
+        };
+        assert_eq!(half_sine.value_mg(9 * MS), 0.0);
+        assert!((half_sine.value_mg(12 * MS) - 100_000.0).abs() < 1e-6);
+        assert!(half_sine.value_mg(14 * MS).abs() < 1e-6);
+        assert_eq!(half_sine.value_mg(15 * MS), 0.0);
+
+        let haversine = Waveform::Haversine {
+            start: Duration::ZERO,
+            duration: 4 * MS,
+            peak_mg: 10_000.0,
+        };
+        assert!((haversine.value_mg(MS) - 5_000.0).abs() < 1e-6);
+
+        let noise = Waveform::RandomVibration {
+            rms_mg: 500.0,
+            seed: 7,
+        };
+        assert_eq!(noise.value_mg(3 * MS), noise.value_mg(3 * MS));
+        let rms = ((0..4_000)
+            .map(|i| noise.value_mg(Duration::from_micros(i * 250)).powi(2))
+            .sum::<f64>()
+            / 4_000.0)
+            .sqrt();
+        assert!((rms - 500.0).abs() < 50.0);
+    }
+
+    /// Verifies CSV recordings are interpolated and malformed input is rejected.
+    #[test]
+    fn csv_replay_interpolates_rows() {
+        let csv = "time_s,x_g,y_g,z_g\n0.000,0,0,1\n# impact\n0.010,2,0,1\n";
+        let stimulus = Stimulus::from_csv(csv).unwrap();
+        assert_eq!(stimulus.sample_mg(5 * MS), [1_000.0, 0.0, 1_000.0]);
+        assert_eq!(stimulus.sample_mg(20 * MS), [2_000.0, 0.0, 1_000.0]);
+
+        assert_eq!(Stimulus::from_csv("t,x,y,z\n"), Err(CsvError::Empty));
+        assert_eq!(
+            Stimulus::from_csv("0,0,0,1\n0.1,0,0\n"),
+            Err(CsvError::ColumnCount { line: 2 })
+        );
+        assert_eq!(
+            Stimulus::from_csv("0.2,0,0,1\n0.1,0,0,1\n"),
+            Err(CsvError::InvalidTime { line: 2 })
+        );
+    }
+
+    /// Ensures a short first data row is reported and huge times do not panic.
+    #[test]
+    fn csv_rejects_bad_first_row_and_overflowing_time() {
+        assert_eq!(
+            Stimulus::from_csv("0,0,1\n0.1,0,0,1\n"),
+            Err(CsvError::ColumnCount { line: 1 })
+        );
+        assert_eq!(
+            Stimulus::from_csv("1e30,0,0,1\n"),
+            Err(CsvError::InvalidTime { line: 1 })
+        );
+        assert_eq!(
+            Stimulus::from_csv("t,x,y,z\n-1,0,0,1\n"),
+            Err(CsvError::InvalidTime { line: 2 })
+        );
+    }
+
+    /// Ensures the chain clips at full scale and the high-pass removes a constant offset.
+    #[test]
+    fn signal_chain_clips_and_filters() {
+        let mut chain = SignalChain::default();
+        let out = chain.process([300_000.0, -1_000.0, 0.0], 400.0, None, None);
+        assert_eq!(out, [200_000.0, -1_000.0, 0.0]);
+
+        let mut chain = SignalChain::default();
+        let gravity = [0.0, 0.0, 1_000.0];
+        assert_eq!(
+            chain.process(gravity, 400.0, Some(200.0), Some(1.9)),
+            [0.0; 3]
+        );
+        let step = chain.process([0.0, 0.0, 6_000.0], 400.0, Some(200.0), Some(1.9));
+        assert!(step[2] > 4_500.0 && step[2] < 5_000.0);
+    }
+}
@@ -0,0 +1,382 @@
+//! Test support for crates built on this driver.
+//!
+//! [`MockInterface`] is a scripted [`Adxl372Interface`]: every access must match the next
+//! [`Expectation`], reads are answered from it, and a mismatch panics with the offending
+//! access. Writing those scripts by hand ties application tests to the driver's internal
+//! register sequencing, so [`sequence::Sequence`] generates them for `init`, `configure`,
+//! `run_self_test` and `reset` from a model of the register file instead.
+//!
+//! Tests that only care about the end result can check register state with
+//! [`assert_register`] and [`assert_config`], which work on any [`RegisterState`] source:
+//! the mock itself, or the simulator when the `sim` feature is enabled.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::params::{OutputDataRate, PowerMode};
+//! use adxl372::testing::sequence::{Sequence, SelfTestResponse};
+//! use adxl372::testing::{assert_config, assert_register};
+//! use adxl372::registers::Timing;
+//! use embedded_hal_mock::eh1::delay::NoopDelay;
+//!
+//! let config = Config::new()
+//!     .odr(OutputDataRate::Od800Hz)
+//!     .power_mode(PowerMode::Measure)
+//!     .build();
+//! let mock = Sequence::new()
+//!     .init(&config, SelfTestResponse::passing())
+//!     .into_mock();
+//!
+//! let mut device = Adxl372::new(mock, config);
+//! device.init(&mut NoopDelay::new()).unwrap();
+//!
+//! let (mut mock, _) = device.release();
+//! assert_config(&mut mock, &config);
+//! assert_register(&mut mock, Timing::from(0x20));
+//! ```
+
+pub mod sequence;
+
+use core::fmt::Debug;
+use std::collections::VecDeque;
+use std::vec::Vec;
+
+use crate::config::Config;
+use crate::dump::reset_values;
+use crate::interface::Adxl372Interface;
+#[cfg(feature = "async")]
+use crate::interface::Adxl372InterfaceAsync;
+use crate::interface::trace::Operation;
+use crate::registers::{REG_FIFO_DATA, REG_OFFSET_X, REG_RESET, RESET_COMMAND, Register};
+
+// Number of addresses tracked for register state, `DEVID_AD` through `FIFO_DATA`.
+const REGISTER_COUNT: usize = REG_FIFO_DATA as usize + 1;
+
+/// One bus access expected by [`MockInterface`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expectation {
+    /// `read_register(register)`, answered with `value`.
+    ReadRegister {
+        /// Register address.
+        register: u8,
+        /// Value returned to the driver.
+        value: u8,
+    },
+    /// `write_register(register, value)`.
+    WriteRegister {
+        /// Register address.
+        register: u8,
+        /// Expected value.
+        value: u8,
+    },
+    /// `read_many(register, ..)` of `data.len()` bytes, answered with `data`.
+    ReadMany {
+        /// First register address.
+        register: u8,
+        /// Bytes returned to the driver.
+        data: Vec<u8>,
+    },
+    /// `write_many(register, data)`.
+    WriteMany {
+        /// First register address.
+        register: u8,
+        /// Expected bytes.
+        data: Vec<u8>,
+    },
+    /// Any access of kind `operation` at `register`, failing with [`MockError`].
+    Fail {
+        /// Kind of access.
+        operation: Operation,
+        /// Register address.
+        register: u8,
+    },
+}
+
+impl Expectation {
+    fn operation(&self) -> Operation {
+        match self {
+            Self::ReadRegister { .. } => Operation::ReadRegister,
+            Self::WriteRegister { .. } => Operation::WriteRegister,
+            Self::ReadMany { .. } => Operation::ReadMany,
+            Self::WriteMany { .. } => Operation::WriteMany,
+            Self::Fail { operation, .. } => *operation,
+        }
+    }
+
+    fn register(&self) -> u8 {
+        match self {
+            Self::ReadRegister { register, .. }
+            | Self::WriteRegister { register, .. }
+            | Self::ReadMany { register, .. }
+            | Self::WriteMany { register, .. }
+            | Self::Fail { register, .. } => *register,
+        }
+    }
+}
+
+/// Error returned for [`Expectation::Fail`].
+///
+/// Reports [`ErrorKind::Other`](embedded_hal::spi::ErrorKind::Other) on both buses, so the
+/// default retry classifiers treat it as transient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "defmt", derive(defmt::Format))]
+pub struct MockError;
+
+impl embedded_hal::spi::Error for MockError {
+    fn kind(&self) -> embedded_hal::spi::ErrorKind {
+        embedded_hal::spi::ErrorKind::Other
+    }
+}
+
+impl embedded_hal::i2c::Error for MockError {
+    fn kind(&self) -> embedded_hal::i2c::ErrorKind {
+        embedded_hal::i2c::ErrorKind::Other
+    }
+}
+
+/// Scripted bus interface.
+///
+/// Panics on the first access that does not match the next expectation, and on drop when
+/// expectations are left over.
+#[derive(Debug)]
+pub struct MockInterface {
+    expectations: VecDeque<Expectation>,
+    consumed: usize,
+    registers: [Option<u8>; REGISTER_COUNT],
+}
+
+impl MockInterface {
+    /// Creates a mock expecting `expectations` in order.
+    pub fn new<I: IntoIterator<Item = Expectation>>(expectations: I) -> Self {
+        Self {
+            expectations: expectations.into_iter().collect(),
+            consumed: 0,
+            registers: [None; REGISTER_COUNT],
+        }
+    }
+
+    /// Appends `expectations` to the script.
+    pub fn expect<I: IntoIterator<Item = Expectation>>(&mut self, expectations: I) {
+        self.expectations.extend(expectations);
+    }
+
+    /// Returns the number of accesses matched so far.
+    pub fn consumed(&self) -> usize {
+        self.consumed
+    }
+
+    /// Returns the expectations not yet matched.
+    pub fn remaining(&self) -> impl Iterator<Item = &Expectation> {
+        self.expectations.iter()
+    }
+
+    /// Panics unless every expectation has been matched.
+    pub fn done(&self) {
+        assert!(
+            self.expectations.is_empty(),
+            "{} interface expectations not consumed, next: {:?}",
+            self.expectations.len(),
+            self.expectations.front()
+        );
+    }
+
+    // Pops the next expectation after checking it matches the access.
+    fn next(&mut self, operation: Operation, register: u8) -> Result<Expectation, MockError> {
+        let Some(expected) = self.expectations.pop_front() else {
+            panic!(
+                "unexpected {operation:?} at {register:#04x} after {} accesses",
+                self.consumed
+            );
+        };
+        assert!(
+            expected.operation() == operation && expected.register() == register,
+            "access {}: expected {expected:?}, got {operation:?} at {register:#04x}",
+            self.consumed
+        );
+
+        self.consumed += 1;
+        match expected {
+            Expectation::Fail { .. } => Err(MockError),
+            expected => Ok(expected),
+        }
+    }
+
+    // Records the values an access revealed; a soft reset restores the reset values.
+    fn track(&mut self, register: u8, data: &[u8]) {
+        if register == REG_RESET && data.first() == Some(&RESET_COMMAND) {
+            self.registers = [None; REGISTER_COUNT];
+            for (address, value) in reset_values() {
+                self.registers[usize::from(address)] = Some(value);
+            }
+            return;
+        }
+        for (address, value) in (register..=u8::MAX).zip(data) {
+            if let Some(slot) = self.registers.get_mut(usize::from(address)) {
+                *slot = Some(*value);
+            }
+        }
+    }
+}
+
+impl Default for MockInterface {
+    fn default() -> Self {
+        Self::new([])
+    }
+}
+
+impl Drop for MockInterface {
+    fn drop(&mut self) {
+        if !std::thread::panicking() {
+            self.done();
+        }
+    }
+}
+
+impl Adxl372Interface for MockInterface {
+    type Error = MockError;
+
+    fn write_register(&mut self, register: u8, value: u8) -> core::result::Result<(), Self::Error> {
+        if let Expectation::WriteRegister {
+            value: expected, ..
+        } = self.next(Operation::WriteRegister, register)?
+        {
+            assert_eq!(value, expected, "write value mismatch at {register:#04x}");
+        }
+        self.track(register, &[value]);
+        Ok(())
+    }
+
+    fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        let Expectation::ReadRegister { value, .. } =
+            self.next(Operation::ReadRegister, register)?
+        else {
+            unreachable!("operation checked by `next`");
+        };
+        self.track(register, &[value]);
+        Ok(value)
+    }
+
+    fn read_many(&mut self, register: u8, buf: &mut [u8]) -> core::result::Result<(), Self::Error> {
+        let Expectation::ReadMany { data, .. } = self.next(Operation::ReadMany, register)? else {
+            unreachable!("operation checked by `next`");
+        };
+        assert_eq!(
+            buf.len(),
+            data.len(),
+            "read length mismatch at {register:#04x}"
+        );
+        buf.copy_from_slice(&data);
+        if register != REG_FIFO_DATA {
+            self.track(register, &data);
+        }
+        Ok(())
+    }
+
+    fn write_many(&mut self, register: u8, data: &[u8]) -> core::result::Result<(), Self::Error> {
+        if let Expectation::WriteMany { data: expected, .. } =
+            self.next(Operation::WriteMany, register)?
+        {
+            assert_eq!(data, expected, "write data mismatch at {register:#04x}");
+        }
+        self.track(register, data);
+        Ok(())
+    }
+}
+
+#[cfg(feature = "async")]
+impl Adxl372InterfaceAsync for MockInterface {
+    type Error = MockError;
+
+    async fn write_register(
+        &mut self,
+        register: u8,
+        value: u8,
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_register(self, register, value)
+    }
+
+    async fn read_register(&mut self, register: u8) -> core::result::Result<u8, Self::Error> {
+        Adxl372Interface::read_register(self, register)
+    }
+
+    async fn read_many(
+        &mut self,
+        register: u8,
+        buf: &mut [u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::read_many(self, register, buf)
+    }
+
+    async fn write_many(
+        &mut self,
+        register: u8,
+        data: &[u8],
+    ) -> core::result::Result<(), Self::Error> {
+        Adxl372Interface::write_many(self, register, data)
+    }
+}
+
+/// Source of register values for the assertion helpers.
+pub trait RegisterState {
+    /// Returns the value held at `address`, or `None` when it is not known.
+    fn register_value(&mut self, address: u8) -> Option<u8>;
+}
+
+impl RegisterState for MockInterface {
+    /// Returns the last value written to or read from `address` through the mock.
+    fn register_value(&mut self, address: u8) -> Option<u8> {
+        self.registers.get(usize::from(address)).copied().flatten()
+    }
+}
+
+#[cfg(feature = "sim")]
+impl RegisterState for crate::sim::Adxl372Sim {
+    fn register_value(&mut self, address: u8) -> Option<u8> {
+        Some(self.peek(address))
+    }
+}
+
+/// Asserts that register `R` holds `expected`.
+#[track_caller]
+pub fn assert_register<R>(state: &mut impl RegisterState, expected: R)
+where
+    R: Register<Raw = u8> + Into<u8> + From<u8> + Copy + Debug,
+{
+    let raw = state
+        .register_value(R::ADDRESS)
+        .unwrap_or_else(|| panic!("register {:#04x} was never accessed", R::ADDRESS));
+    assert_eq!(
+        raw,
+        expected.into(),
+        "register {:#04x}: expected {expected:?}, found {:?}",
+        R::ADDRESS,
+        R::from(raw)
+    );
+}
+
+/// Asserts that the bits of `address` selected by `mask` equal `expected`.
+#[track_caller]
+pub fn assert_register_bits(state: &mut impl RegisterState, address: u8, mask: u8, expected: u8) {
+    let raw = state
+        .register_value(address)
+        .unwrap_or_else(|| panic!("register {address:#04x} was never accessed"));
+    assert_eq!(
+        raw & mask,
+        expected & mask,
+        "register {address:#04x} = {raw:#04x}, expected {expected:#04x} under mask {mask:#04x}"
+    );
+}
+
+/// Asserts that the `OFFSET_X..=POWER_CTL` block holds `config`.
+///
+/// Compares at register resolution, with reserved bits read as zero, so only what the device
+/// would actually run with is checked.
+#[track_caller]
+pub fn assert_config(state: &mut impl RegisterState, config: &Config) {
+    let expected = config.register_block();
+    for (address, expected) in (REG_OFFSET_X..).zip(expected) {
+        assert_register_bits(state, address, 0xFF, expected);
+    }
+}
@@ -0,0 +1,460 @@
+//! Expectation sequences for common driver operations.
+//!
+//! [`Sequence`] keeps a model of the register file, starting from the datasheet reset
+//! values, and appends the accesses the driver makes for each operation. Read-modify-write
+//! steps read back whatever the model holds, and writes the driver would skip because the
+//! value is unchanged are left out, exactly as the driver does. Operations can be chained to
+//! script a whole test:
+//!
+//! ```rust
+//! use adxl372::config::Config;
+//! use adxl372::device::Adxl372;
+//! use adxl372::params::PowerMode;
+//! use adxl372::testing::sequence::Sequence;
+//! use embedded_hal_mock::eh1::delay::NoopDelay;
+//!
+//! let standby = Config::default();
+//! let measure = Config::new().power_mode(PowerMode::Measure).build();
+//! let mock = Sequence::new()
+//!     .configure(&standby)
+//!     .configure(&measure)
+//!     .reset()
+//!     .into_mock();
+//!
+//! let mut device = Adxl372::new(mock, standby);
+//! let mut delay = NoopDelay::new();
+//! device.configure(standby, &mut delay).unwrap();
+//! device.configure(measure, &mut delay).unwrap();
+//! device.reset().unwrap();
+//! ```
+
+use std::vec::Vec;
+
+use super::{Expectation, MockInterface, REGISTER_COUNT};
+use crate::config::{CONFIGURE_SEQUENCE, Config};
+use crate::device::{VerifiedWrite, WriteVerify};
+use crate::dump::reset_values;
+use crate::params::PowerMode;
+use crate::registers::{
+    PowerControl, REG_RESET, REG_ZDATA_H, RESET_COMMAND, ReadableRegister, Register, SelfTest,
+    WritableRegister,
+};
+use crate::self_test::{
+    SELF_TEST_MAX_SAMPLES, SELF_TEST_SEQUENCE, SelfTestReport, SelfTestStep, evaluate_samples,
+    prepare_power_control,
+};
+
+/// Z-axis behaviour scripted for a self-test run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SelfTestResponse {
+    /// Z reading, in LSB, during the first half of the samples.
+    pub baseline_z: i16,
+    /// Z reading, in LSB, during the second half of the samples.
+    pub stimulated_z: i16,
+    /// Number of Z samples read before `ST_DONE` is reported.
+    ///
+    /// At or beyond the driver's 500 ms poll budget the run times out instead.
+    pub samples: u16,
+    /// Value of the `USER_ST` flag once the test completes.
+    pub user_st: bool,
+}
+
+impl SelfTestResponse {
+    /// A run the driver accepts: a 10 LSB deflection over 40 samples.
+    pub const fn passing() -> Self {
+        Self {
+            baseline_z: 0,
+            stimulated_z: 10,
+            samples: 40,
+            user_st: true,
+        }
+    }
+
+    /// A run that completes without any deflection, so the driver rejects it.
+    pub const fn failing() -> Self {
+        Self {
+            stimulated_z: 0,
+            ..Self::passing()
+        }
+    }
+
+    /// Returns the report the driver produces for this response.
+    pub fn report(&self) -> SelfTestReport {
+        let timed_out = self.timed_out();
+        evaluate_samples(
+            (0..self.sample_count()).map(|index| self.sample(index)),
+            self.final_register(),
+            timed_out,
+        )
+    }
+
+    fn timed_out(&self) -> bool {
+        usize::from(self.samples) >= SELF_TEST_MAX_SAMPLES
+    }
+
+    fn sample_count(&self) -> usize {
+        usize::from(self.samples).min(SELF_TEST_MAX_SAMPLES)
+    }
+
+    fn sample(&self, index: usize) -> i16 {
+        if index < usize::from(self.samples) / 2 {
+            self.baseline_z
+        } else {
+            self.stimulated_z
+        }
+    }
+
+    // `SELF_TEST` as the driver last polls it.
+    fn final_register(&self) -> SelfTest {
+        if self.timed_out() {
+            SelfTest::new().with_st(true)
+        } else {
+            SelfTest::new()
+                .with_st_done(true)
+                .with_user_st(self.user_st)
+        }
+    }
+}
+
+impl Default for SelfTestResponse {
+    fn default() -> Self {
+        Self::passing()
+    }
+}
+
+/// Builder for the accesses the driver makes, driven by a register-file model.
+///
+/// The sequences assume the device was created with write verification disabled unless
+/// [`write_verify`](Self::write_verify) says otherwise.
+#[derive(Debug, Clone)]
+pub struct Sequence {
+    registers: [u8; REGISTER_COUNT],
+    verify: WriteVerify,
+    expectations: Vec<Expectation>,
+}
+
+impl Default for Sequence {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sequence {
+    /// Starts an empty sequence against a device in its power-on state.
+    pub fn new() -> Self {
+        let mut sequence = Self {
+            registers: [0; REGISTER_COUNT],
+            verify: WriteVerify::Disabled,
+            expectations: Vec::new(),
+        };
+        sequence.load_reset_values();
+        sequence
+    }
+
+    /// Includes the read-backs made under `policy` after every subsequent write.
+    ///
+    /// The model reads back what was written, so no retries are scripted.
+    pub fn write_verify(mut self, policy: WriteVerify) -> Self {
+        self.verify = policy;
+        self
+    }
+
+    /// Appends the accesses of [`Adxl372::reset`](crate::device::Adxl372::reset).
+    pub fn reset(mut self) -> Self {
+        self.push_reset();
+        self
+    }
+
+    /// Appends the accesses of [`Adxl372::configure`](crate::device::Adxl372::configure).
+    ///
+    /// An invalid `config` is rejected by the driver before it touches the bus, so nothing is
+    /// appended for it.
+    pub fn configure(mut self, config: &Config) -> Self {
+        if config.validate().is_ok() {
+            self.push_configure(config);
+        }
+        self
+    }
+
+    /// Appends the accesses of [`run_self_test`](crate::self_test::run_self_test).
+    pub fn self_test(mut self, response: SelfTestResponse) -> Self {
+        self.push_self_test(response);
+        self
+    }
+
+    /// Appends the accesses of [`Adxl372::init`](crate::device::Adxl372::init) for a device
+    /// created with `config`.
+    ///
+    /// When `response` fails the self-test, the sequence ends where `init` gives up.
+    pub fn init(mut self, config: &Config, response: SelfTestResponse) -> Self {
+        if config.validate().is_err() {
+            return self;
+        }
+
+        self.push_self_test(response);
+        if !response.report().passed {
+            return self;
+        }
+
+        self.modify(|power: &mut PowerControl| power.set_mode(PowerMode::Standby));
+        self.push_reset();
+        self.push_configure(config);
+        self
+    }
+
+    /// Appends a custom expectation, e.g. for an operation without a helper.
+    ///
+    /// The register model is not updated.
+    pub fn then(mut self, expectation: Expectation) -> Self {
+        self.expectations.push(expectation);
+        self
+    }
+
+    /// Returns the accesses generated so far.
+    pub fn expectations(&self) -> &[Expectation] {
+        &self.expectations
+    }
+
+    /// Returns the value the model holds at `address`.
+    pub fn register(&self, address: u8) -> u8 {
+        self.registers
+            .get(usize::from(address))
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// Consumes the builder and returns the generated accesses.
+    pub fn into_expectations(self) -> Vec<Expectation> {
+        self.expectations
+    }
+
+    /// Consumes the builder and returns a mock expecting the generated accesses.
+    pub fn into_mock(self) -> MockInterface {
+        MockInterface::new(self.expectations)
+    }
+
+    // ==================================================================
+    // == Operations ====================================================
+    // ==================================================================
+    fn push_reset(&mut self) {
+        self.expectations.push(Expectation::WriteRegister {
+            register: REG_RESET,
+            value: RESET_COMMAND,
+        });
+        self.load_reset_values();
+    }
+
+    // Walks `CONFIGURE_SEQUENCE` the way `Adxl372::configure` does.
+    fn push_configure(&mut self, config: &Config) {
+        for step in CONFIGURE_SEQUENCE {
+            let register = step.register();
+            if let Some(block) = step.block(config) {
+                self.write_block(register, block.as_slice());
+            } else {
+                let current = self.read_address(register);
+                if let Some(updated) = step.update(config, current) {
+                    self.write_block(register, &[updated]);
+                }
+            }
+        }
+    }
+
+    // Walks `SELF_TEST_SEQUENCE`, then the concluding reset of `run_self_test`.
+    fn push_self_test(&mut self, response: SelfTestResponse) {
+        for step in SELF_TEST_SEQUENCE {
+            match step {
+                SelfTestStep::Reset => self.push_reset(),
+                SelfTestStep::PreparePowerControl => self.modify(prepare_power_control),
+                SelfTestStep::DelayMs(_) => {}
+                SelfTestStep::SetTrigger(st) => self.modify(|reg: &mut SelfTest| reg.set_st(st)),
+                SelfTestStep::Collect => self.push_collect(response),
+            }
+        }
+        self.push_reset();
+    }
+
+    fn push_collect(&mut self, response: SelfTestResponse) {
+        let running = self.read_register::<SelfTest>();
+        for index in 0..response.sample_count() {
+            self.expectations.push(Expectation::ReadRegister {
+                register: SelfTest::ADDRESS,
+                value: u8::from(running),
+            });
+            let z = response.sample(index) << 4;
+            self.expectations.push(Expectation::ReadMany {
+                register: REG_ZDATA_H,
+                data: z.to_be_bytes().to_vec(),
+            });
+        }
+
+        let done = response.final_register();
+        self.set_register(SelfTest::ADDRESS, u8::from(done));
+        if !response.timed_out() {
+            self.read_register::<SelfTest>();
+        }
+    }
+
+    // ==================================================================
+    // == Register Model ================================================
+    // ==================================================================
+    fn load_reset_values(&mut self) {
+        self.registers = [0; REGISTER_COUNT];
+        for (address, value) in reset_values() {
+            self.set_register(address, value);
+        }
+    }
+
+    fn set_register(&mut self, address: u8, value: u8) {
+        if let Some(slot) = self.registers.get_mut(usize::from(address)) {
+            *slot = value;
+        }
+    }
+
+    fn read_register<R: ReadableRegister>(&mut self) -> R {
+        R::from(self.read_address(R::ADDRESS))
+    }
+
+    fn read_address(&mut self, register: u8) -> u8 {
+        let value = self.register(register);
+        self.expectations
+            .push(Expectation::ReadRegister { register, value });
+        value
+    }
+
+    // Mirrors `Adxl372::modify_reg`: read, mutate and write back only when the value changed.
+    fn modify<R, F>(&mut self, mutate: F)
+    where
+        R: ReadableRegister + WritableRegister,
+        F: FnOnce(&mut R),
+    {
+        let current = self.register(R::ADDRESS);
+        let mut reg = self.read_register::<R>();
+        mutate(&mut reg);
+
+        let updated: u8 = reg.into();
+        if updated != current {
+            self.write_block(R::ADDRESS, &[updated]);
+        }
+    }
+
+    // Mirrors the driver's write path, including the optional read-back.
+    fn write_block(&mut self, register: u8, data: &[u8]) {
+        self.expectations.push(match data {
+            [value] => Expectation::WriteRegister {
+                register,
+                value: *value,
+            },
+            _ => Expectation::WriteMany {
+                register,
+                data: data.to_vec(),
+            },
+        });
+        for (address, value) in (register..).zip(data) {
+            self.set_register(address, *value);
+        }
+
+        let Some(mut verify) = VerifiedWrite::new(self.verify, register, data) else {
+            return;
+        };
+        while let Some((address, read)) = verify.next_read() {
+            for (slot, offset) in read.iter_mut().zip(0u8..) {
+                *slot = self.register(address + offset);
+            }
+            self.expectations.push(Expectation::ReadMany {
+                register: address,
+                data: read.to_vec(),
+            });
+            if !matches!(verify.check::<()>(), Ok(false)) {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SelfTestResponse, Sequence};
+    use crate::activity::{AxisMask, MotionDetector, MotionThreshold, ThresholdMode};
+    use crate::config::Config;
+    use crate::device::{Adxl372, WriteVerify};
+    use crate::error::Error;
+    use crate::params::{FifoFormat, FifoMode, OutputDataRate, PowerMode};
+    use crate::registers::{PowerControl, Register, SelfTest, Timing};
+    use crate::testing::{assert_config, assert_register, assert_register_bits};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+
+    fn measuring_config() -> Config {
+        let threshold = MotionThreshold::new(2_000, AxisMask::ALL, ThresholdMode::Referenced);
+        Config::new()
+            .odr(OutputDataRate::Od1600Hz)
+            .activity(MotionDetector::new(threshold, 3))
+            .fifo_mode(FifoMode::Stream)
+            .fifo_format(FifoFormat::XYZ)
+            .fifo_watermark(96)
+            .power_mode(PowerMode::Measure)
+            .build()
+    }
+
+    /// Runs `init` and a reconfiguration against generated sequences and checks the result.
+    #[test]
+    fn generated_sequences_match_the_driver() {
+        let config = measuring_config();
+        let standby = Config::default();
+        let mock = Sequence::new()
+            .init(&config, SelfTestResponse::passing())
+            .configure(&standby)
+            .into_mock();
+
+        let mut device = Adxl372::new(mock, config);
+        let mut delay = NoopDelay::new();
+        device.init(&mut delay).unwrap();
+        assert_config(device.interface_mut(), &config);
+
+        device.configure(standby, &mut delay).unwrap();
+        let (mut mock, _) = device.release();
+        assert_config(&mut mock, &standby);
+        assert_register(&mut mock, Timing::from(0x00));
+        assert_register_bits(&mut mock, PowerControl::ADDRESS, 0x03, 0x00);
+        mock.done();
+    }
+
+    /// Checks failing and timed-out self-tests end where the driver stops.
+    #[test]
+    fn self_test_responses_drive_the_report() {
+        let config = measuring_config();
+        let mock = Sequence::new()
+            .init(&config, SelfTestResponse::failing())
+            .into_mock();
+        let mut device = Adxl372::new(mock, config);
+        assert_eq!(
+            device.init(&mut NoopDelay::new()),
+            Err(Error::SelfTestFailed)
+        );
+
+        let timeout = SelfTestResponse {
+            samples: u16::MAX,
+            ..SelfTestResponse::passing()
+        };
+        let mock = Sequence::new().self_test(timeout).into_mock();
+        let mut device = Adxl372::new(mock, config);
+        let report = device.run_self_test(&mut NoopDelay::new()).unwrap();
+        assert_eq!(report, timeout.report());
+        assert!(report.timed_out && !report.passed);
+        assert_register(device.interface_mut(), SelfTest::new());
+    }
+
+    /// Ensures write verification read-backs are scripted after every verifiable write.
+    #[test]
+    fn write_verify_adds_read_backs() {
+        let config = measuring_config();
+        let policy = WriteVerify::ReadBack { retries: 1 };
+        let mock = Sequence::new()
+            .write_verify(policy)
+            .configure(&config)
+            .into_mock();
+
+        let mut device = Adxl372::new(mock, Config::default());
+        device.set_write_verify(policy);
+        device.configure(config, &mut NoopDelay::new()).unwrap();
+    }
+}
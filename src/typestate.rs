@@ -0,0 +1,682 @@
+//! Typestate layer over [`device::Adxl372`] that tracks init state and power mode.
+//!
+//! The dynamic driver accepts any call at any time, so nothing stops reading samples before
+//! `init`, running the self-test after `configure` (its reset wipes the configuration), or
+//! draining the FIFO in standby. [`Adxl372`] encodes the state in its type instead:
+//!
+//! - [`Uninit`]: freshly created; only [`init`](Adxl372::init) is available.
+//! - [`Standby`]: initialised and idle; configuration changes and the self-test live here.
+//! - [`Measuring`], [`WakeUp`] and [`InstantOn`]: sampling states with the data and FIFO
+//!   methods.
+//!
+//! Transitions consume the device and return it in the new state. When a transition fails
+//! the device comes back inside a [`TransitionError`]: unchanged if the configuration was
+//! rejected before anything was written, otherwise as [`Uninit`], since the sensor may be left
+//! anywhere between the two states. The power mode is owned by the state, so the
+//! `power_mode` field of any [`Config`] passed in is ignored.
+//!
+//! [`into_inner`](Adxl372::into_inner) hands over the dynamic driver for operations this layer
+//! does not cover.
+//!
+//! # Examples
+//!
+//! ```rust,no_run
+//! # use embedded_hal::delay::DelayNs;
+//! # use embedded_hal::spi::SpiDevice;
+//! use adxl372::config::Config;
+//! use adxl372::interface::spi::SpiInterface;
+//! use adxl372::typestate::Adxl372;
+//!
+//! # fn example<SPI: SpiDevice, D: DelayNs>(spi: SPI, mut delay: D) {
+//! let device = Adxl372::new(SpiInterface::new(spi), Config::default());
+//! let standby = device.init(&mut delay).unwrap();
+//! let mut measuring = standby.start_measuring(&mut delay).unwrap();
+//! let [x, y, z] = measuring.read_xyz_raw().unwrap();
+//! # let _ = (x, y, z);
+//! let standby = measuring.standby().unwrap();
+//! # let _ = standby;
+//! # }
+//! ```
+//!
+//! Sample reads do not exist outside the sampling states:
+//!
+//! ```rust,compile_fail
+//! # use adxl372::interface::Adxl372Interface;
+//! # use adxl372::typestate::{Adxl372, Standby};
+//! # fn example<I: Adxl372Interface>(device: &mut Adxl372<I, Standby>) {
+//! device.read_xyz_raw();
+//! # }
+//! ```
+
+use core::fmt;
+use core::marker::PhantomData;
+
+use embedded_hal::delay::DelayNs;
+
+use crate::activity::{MotionDetector, MotionThreshold};
+use crate::calibration::OffsetTrim;
+use crate::config::{Config, ConfigDiff};
+use crate::device::{self, StatusSnapshot};
+use crate::dump::RegisterDump;
+use crate::error::{Error, Result};
+use crate::fifo::{FifoDecoder, Sample};
+use crate::interface::Adxl372Interface;
+use crate::interrupt::InterruptMap;
+use crate::params::{FifoFormat, FifoMode, HighPassCorner, PowerMode};
+use crate::self_test::SelfTestReport;
+use crate::units::Acceleration;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Marker implemented by every typestate.
+pub trait State: sealed::Sealed {}
+
+/// Marker for the states in which the device has been initialised.
+pub trait Initialized: State {}
+
+/// Marker for the states in which the device produces samples.
+pub trait Sampling: Initialized {
+    /// Power mode selected in `POWER_CTL` for this state.
+    const MODE: PowerMode;
+}
+
+/// Created but not yet initialised.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Uninit;
+
+/// Initialised and in standby.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Standby;
+
+/// Full measurement mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Measuring;
+
+/// Wake-up mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WakeUp;
+
+/// Instant-on mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InstantOn;
+
+impl sealed::Sealed for Uninit {}
+impl sealed::Sealed for Standby {}
+impl sealed::Sealed for Measuring {}
+impl sealed::Sealed for WakeUp {}
+impl sealed::Sealed for InstantOn {}
+
+impl State for Uninit {}
+impl State for Standby {}
+impl State for Measuring {}
+impl State for WakeUp {}
+impl State for InstantOn {}
+
+impl Initialized for Standby {}
+impl Initialized for Measuring {}
+impl Initialized for WakeUp {}
+impl Initialized for InstantOn {}
+
+impl Sampling for Measuring {
+    const MODE: PowerMode = PowerMode::Measure;
+}
+
+impl Sampling for WakeUp {
+    const MODE: PowerMode = PowerMode::WakeUp;
+}
+
+impl Sampling for InstantOn {
+    const MODE: PowerMode = PowerMode::InstantOn;
+}
+
+/// Failed transition, carrying the device back in a state it can be recovered from.
+pub enum TransitionError<IFACE, E, S = Uninit> {
+    /// The configuration was rejected before anything was written; the device is still in
+    /// its previous state `S`.
+    Rejected {
+        /// Error reported by the driver.
+        error: Error<E>,
+        /// The device, unchanged.
+        device: Adxl372<IFACE, S>,
+    },
+    /// The transition failed after touching the device.
+    Failed {
+        /// Error reported by the driver.
+        error: Error<E>,
+        /// The device, to be initialised again.
+        device: Adxl372<IFACE, Uninit>,
+    },
+}
+
+impl<IFACE, E, S> TransitionError<IFACE, E, S> {
+    /// Returns the driver error.
+    pub fn error(&self) -> &Error<E> {
+        match self {
+            Self::Rejected { error, .. } | Self::Failed { error, .. } => error,
+        }
+    }
+
+    /// Returns the driver error, dropping the device.
+    pub fn into_error(self) -> Error<E> {
+        match self {
+            Self::Rejected { error, .. } | Self::Failed { error, .. } => error,
+        }
+    }
+}
+
+impl<IFACE, E: fmt::Debug, S> fmt::Debug for TransitionError<IFACE, E, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Self::Rejected { .. } => "Rejected",
+            Self::Failed { .. } => "Failed",
+        };
+        f.debug_struct(name)
+            .field("error", self.error())
+            .finish_non_exhaustive()
+    }
+}
+
+/// Result of a consuming transition out of state `S`.
+pub type TransitionResult<T, IFACE, E, S = Uninit> =
+    core::result::Result<T, TransitionError<IFACE, E, S>>;
+
+/// ADXL372 driver whose type records the init state and power mode.
+pub struct Adxl372<IFACE, S = Uninit> {
+    inner: device::Adxl372<IFACE>,
+    state: PhantomData<S>,
+}
+
+impl<IFACE, S: State> Adxl372<IFACE, S> {
+    // ==================================================================
+    // == Ownership =====================================================
+    // ==================================================================
+    /// Returns the dynamic driver, giving up the state tracking.
+    pub fn into_inner(self) -> device::Adxl372<IFACE> {
+        self.inner
+    }
+
+    /// Consumes the driver and returns the owned interface and configuration.
+    pub fn release(self) -> (IFACE, Config) {
+        self.inner.release()
+    }
+
+    /// Provides mutable access to the underlying interface.
+    pub fn interface_mut(&mut self) -> &mut IFACE {
+        self.inner.interface_mut()
+    }
+
+    fn into_state<T: State>(self) -> Adxl372<IFACE, T> {
+        Adxl372 {
+            inner: self.inner,
+            state: PhantomData,
+        }
+    }
+
+    fn fail<E, T>(self, error: Error<E>) -> TransitionError<IFACE, E, T> {
+        TransitionError::Failed {
+            error,
+            device: self.into_state(),
+        }
+    }
+}
+
+impl<IFACE> Adxl372<IFACE, Uninit> {
+    /// Creates a driver in the [`Uninit`] state.
+    pub fn new(interface: IFACE, config: Config) -> Self {
+        Self::from_device(device::Adxl372::new(interface, config))
+    }
+
+    /// Wraps a dynamic driver; its state is unknown, so it starts as [`Uninit`].
+    pub fn from_device(device: device::Adxl372<IFACE>) -> Self {
+        Self {
+            inner: device,
+            state: PhantomData,
+        }
+    }
+}
+
+impl<IFACE, CommE> Adxl372<IFACE, Uninit>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+{
+    /// Runs [`device::Adxl372::init`] and leaves the device in standby.
+    ///
+    /// The self-test runs first, as in the dynamic driver, so the configuration is applied
+    /// only after its reset.
+    pub fn init(
+        mut self,
+        delay: &mut impl DelayNs,
+    ) -> TransitionResult<Adxl372<IFACE, Standby>, IFACE, CommE> {
+        let config = *self.inner.config();
+        if config.power_mode != PowerMode::Standby {
+            let verify = self.inner.write_verify();
+            let (interface, _) = self.inner.release();
+            self.inner = device::Adxl372::new(interface, standby_config(config));
+            self.inner.set_write_verify(verify);
+        }
+
+        match self.inner.init(delay) {
+            Ok(()) => Ok(self.into_state()),
+            Err(error) => Err(self.fail(error)),
+        }
+    }
+}
+
+impl<IFACE, CommE> Adxl372<IFACE, Standby>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+{
+    // ==================================================================
+    // == Transitions ===================================================
+    // ==================================================================
+    /// Enters measurement mode and waits for the filters to settle.
+    pub fn start_measuring(
+        self,
+        delay: &mut impl DelayNs,
+    ) -> TransitionResult<Adxl372<IFACE, Measuring>, IFACE, CommE, Standby> {
+        let device = self.enter::<Measuring>()?;
+        device.inner.wait_filter_settle(delay);
+        Ok(device)
+    }
+
+    /// Enters wake-up mode.
+    ///
+    /// The configuration must satisfy the wake-up rules checked by [`Config::validate`];
+    /// otherwise the device comes back in standby as [`TransitionError::Rejected`].
+    pub fn start_wake_up(self) -> TransitionResult<Adxl372<IFACE, WakeUp>, IFACE, CommE, Standby> {
+        self.enter()
+    }
+
+    /// Enters instant-on mode.
+    pub fn start_instant_on(
+        self,
+    ) -> TransitionResult<Adxl372<IFACE, InstantOn>, IFACE, CommE, Standby> {
+        self.enter()
+    }
+
+    /// Runs the self-test, then restores the configuration its resets cleared.
+    pub fn self_test(
+        mut self,
+        delay: &mut impl DelayNs,
+    ) -> TransitionResult<(Self, SelfTestReport), IFACE, CommE> {
+        let config = *self.inner.config();
+        let result = self.inner.run_self_test(delay).and_then(|report| {
+            self.inner.configure(config, delay)?;
+            Ok(report)
+        });
+
+        match result {
+            Ok(report) => Ok((self, report)),
+            Err(error) => Err(self.fail(error)),
+        }
+    }
+
+    // ==================================================================
+    // == Configuration =================================================
+    // ==================================================================
+    /// Applies `config` with [`device::Adxl372::configure`], staying in standby.
+    pub fn configure(&mut self, config: Config, delay: &mut impl DelayNs) -> Result<(), CommE> {
+        self.inner.configure(standby_config(config), delay)
+    }
+
+    /// Applies `config` with [`device::Adxl372::configure_burst`], staying in standby.
+    pub fn configure_burst(
+        &mut self,
+        config: Config,
+        delay: &mut impl DelayNs,
+    ) -> Result<(), CommE> {
+        self.inner.configure_burst(standby_config(config), delay)
+    }
+
+    // ==================================================================
+    // == Partial Configuration =========================================
+    // ==================================================================
+    /// Selects the high-pass filter corner; see [`device::Adxl372::configure_hpf_corner`].
+    pub fn configure_hpf_corner(&mut self, corner: HighPassCorner) -> Result<(), CommE> {
+        self.inner.configure_hpf_corner(corner)
+    }
+
+    /// Programs the per-axis user offset trim.
+    pub fn configure_offsets(&mut self, offsets: OffsetTrim) -> Result<(), CommE> {
+        self.inner.configure_offsets(offsets)
+    }
+
+    /// Updates FIFO format, mode, or watermark; see [`device::Adxl372::configure_fifo`].
+    pub fn configure_fifo(
+        &mut self,
+        format: Option<FifoFormat>,
+        mode: Option<FifoMode>,
+        watermark: Option<u16>,
+    ) -> Result<(), CommE> {
+        self.inner.configure_fifo(format, mode, watermark)
+    }
+
+    /// Programs the activity detector.
+    pub fn configure_activity(&mut self, detector: MotionDetector) -> Result<(), CommE> {
+        self.inner.configure_activity(detector)
+    }
+
+    /// Programs the inactivity detector.
+    pub fn configure_inactivity(&mut self, detector: MotionDetector) -> Result<(), CommE> {
+        self.inner.configure_inactivity(detector)
+    }
+
+    /// Programs the Activity2 (motion warning) detector.
+    pub fn configure_activity2(&mut self, threshold: MotionThreshold) -> Result<(), CommE> {
+        self.inner.configure_activity2(threshold)
+    }
+
+    /// Updates the INT1 and/or INT2 pin mapping.
+    pub fn configure_interrupts(
+        &mut self,
+        int1: Option<InterruptMap>,
+        int2: Option<InterruptMap>,
+    ) -> Result<(), CommE> {
+        self.inner.configure_interrupts(int1, int2)
+    }
+
+    fn enter<S: Sampling>(self) -> TransitionResult<Adxl372<IFACE, S>, IFACE, CommE, Standby> {
+        self.switch_mode(S::MODE)
+    }
+}
+
+impl<IFACE, CommE, S> Adxl372<IFACE, S>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+    S: Initialized,
+{
+    // ==================================================================
+    // == Identification & Status =======================================
+    // ==================================================================
+    /// Returns a shared reference to the active configuration.
+    pub fn config(&self) -> &Config {
+        self.inner.config()
+    }
+
+    /// Verifies the identification registers; see [`device::Adxl372::check_ids`].
+    pub fn check_ids(&mut self) -> Result<u8, CommE> {
+        self.inner.check_ids()
+    }
+
+    /// Returns a snapshot of the `STATUS` and `STATUS2` registers.
+    pub fn read_status(&mut self) -> Result<StatusSnapshot, CommE> {
+        self.inner.read_status()
+    }
+
+    /// Reads the live register contents back into a [`Config`].
+    pub fn read_config(&mut self) -> Result<Config, CommE> {
+        self.inner.read_config()
+    }
+
+    /// Compares the live register contents against the active configuration.
+    pub fn verify_config(&mut self) -> Result<ConfigDiff, CommE> {
+        self.inner.verify_config()
+    }
+
+    /// Captures every readable register except `FIFO_DATA`; see
+    /// [`device::Adxl372::dump_registers`].
+    pub fn dump_registers(&mut self) -> Result<RegisterDump, CommE> {
+        self.inner.dump_registers()
+    }
+
+    fn switch_mode<T: State>(
+        mut self,
+        mode: PowerMode,
+    ) -> TransitionResult<Adxl372<IFACE, T>, IFACE, CommE, S> {
+        match set_power_mode(&mut self.inner, mode) {
+            Ok(()) => Ok(self.into_state()),
+            // `POWER_CTL` is validated before it is written, so the device is untouched.
+            Err(error @ Error::InvalidConfig(_)) => Err(TransitionError::Rejected {
+                error,
+                device: self,
+            }),
+            Err(error) => Err(self.fail(error)),
+        }
+    }
+}
+
+impl<IFACE, CommE, S> Adxl372<IFACE, S>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+    S: Sampling,
+{
+    // ==================================================================
+    // == Transitions ===================================================
+    // ==================================================================
+    /// Returns to standby.
+    pub fn standby(self) -> TransitionResult<Adxl372<IFACE, Standby>, IFACE, CommE, S> {
+        self.switch_mode(PowerMode::Standby)
+    }
+
+    // ==================================================================
+    // == Data Acquisition ==============================================
+    // ==================================================================
+    /// Reads a raw acceleration triplet.
+    pub fn read_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
+        self.inner.read_xyz_raw()
+    }
+
+    /// Reads the raw X-axis acceleration sample.
+    pub fn read_x_raw(&mut self) -> Result<i16, CommE> {
+        self.inner.read_x_raw()
+    }
+
+    /// Reads the raw Y-axis acceleration sample.
+    pub fn read_y_raw(&mut self) -> Result<i16, CommE> {
+        self.inner.read_y_raw()
+    }
+
+    /// Reads the raw Z-axis acceleration sample.
+    pub fn read_z_raw(&mut self) -> Result<i16, CommE> {
+        self.inner.read_z_raw()
+    }
+
+    /// Returns acceleration scaled in milli-g.
+    pub fn read_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.inner.read_xyz_mg()
+    }
+
+    /// Reads and clears the latched per-axis peak; see [`device::Adxl372::read_peak_xyz_raw`].
+    pub fn read_peak_xyz_raw(&mut self) -> Result<[i16; 3], CommE> {
+        self.inner.read_peak_xyz_raw()
+    }
+
+    /// Reads the latched peak acceleration scaled in milli-g, clearing the latch.
+    pub fn read_peak_xyz_mg(&mut self) -> Result<Acceleration, CommE> {
+        self.inner.read_peak_xyz_mg()
+    }
+
+    /// Discards the latched peak so tracking restarts from the next sample.
+    pub fn clear_peak(&mut self) -> Result<(), CommE> {
+        self.inner.clear_peak()
+    }
+
+    // ==================================================================
+    // == FIFO Streaming ================================================
+    // ==================================================================
+    /// Returns the number of FIFO entries currently buffered.
+    pub fn read_fifo_level(&mut self) -> Result<u16, CommE> {
+        self.inner.read_fifo_level()
+    }
+
+    /// Reads raw FIFO bytes; see [`device::Adxl372::read_fifo_raw`].
+    pub fn read_fifo_raw(&mut self, buf: &mut [u8]) -> Result<usize, CommE> {
+        self.inner.read_fifo_raw(buf)
+    }
+
+    /// Decodes FIFO samples into the caller-provided slice.
+    pub fn read_fifo_samples(&mut self, samples: &mut [Sample]) -> Result<usize, CommE> {
+        self.inner.read_fifo_samples(samples)
+    }
+
+    /// Creates a streaming [`FifoDecoder`] for the configured FIFO format.
+    pub fn fifo_decoder(&self) -> FifoDecoder {
+        self.inner.fifo_decoder()
+    }
+
+    /// Discards the FIFO contents.
+    pub fn flush_fifo(&mut self) -> Result<(), CommE> {
+        self.inner.flush_fifo()
+    }
+}
+
+fn standby_config(mut config: Config) -> Config {
+    config.power_mode = PowerMode::Standby;
+    config
+}
+
+fn set_power_mode<IFACE, CommE>(
+    device: &mut device::Adxl372<IFACE>,
+    mode: PowerMode,
+) -> Result<(), CommE>
+where
+    IFACE: Adxl372Interface<Error = CommE>,
+{
+    device.configure_power_ctl(None, None, None, None, None, Some(mode))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Adxl372, Measuring, Standby, TransitionError};
+    use crate::config::{Config, ConfigError};
+    use crate::error::Error;
+    use crate::params::PowerMode;
+    use crate::registers::{REG_POWER_CTL, REG_XDATA_H};
+    use crate::testing::sequence::{SelfTestResponse, Sequence};
+    use crate::testing::{Expectation, MockInterface, assert_config};
+    use embedded_hal_mock::eh1::delay::NoopDelay;
+
+    // Read-modify-write of `POWER_CTL` from `current` into `mode`.
+    fn power_mode_change(current: u8, mode: PowerMode) -> [Expectation; 2] {
+        [
+            Expectation::ReadRegister {
+                register: REG_POWER_CTL,
+                value: current,
+            },
+            Expectation::WriteRegister {
+                register: REG_POWER_CTL,
+                value: (current & !0x03) | mode as u8,
+            },
+        ]
+    }
+
+    fn initialized(config: &Config) -> (Sequence, Adxl372<MockInterface, Standby>) {
+        let sequence = Sequence::new().init(config, SelfTestResponse::passing());
+        let mock = MockInterface::new(sequence.expectations().iter().cloned());
+        let device = Adxl372::new(mock, *config)
+            .init(&mut NoopDelay::new())
+            .unwrap();
+        (sequence, device)
+    }
+
+    /// Walks a device through init, measurement and back to standby.
+    #[test]
+    fn measuring_round_trip() {
+        let config = Config::default();
+        let (sequence, mut device) = initialized(&config);
+        assert_config(device.interface_mut(), &config);
+
+        let standby = sequence.register(REG_POWER_CTL);
+        let measure = (standby & !0x03) | PowerMode::Measure as u8;
+        device
+            .interface_mut()
+            .expect(power_mode_change(standby, PowerMode::Measure));
+        device.interface_mut().expect([Expectation::ReadMany {
+            register: REG_XDATA_H,
+            data: [0x01, 0x00, 0xFF, 0xF0, 0x00, 0x20].to_vec(),
+        }]);
+        device
+            .interface_mut()
+            .expect(power_mode_change(measure, PowerMode::Standby));
+
+        let mut device: Adxl372<_, Measuring> =
+            device.start_measuring(&mut NoopDelay::new()).unwrap();
+        assert_eq!(device.config().power_mode, PowerMode::Measure);
+        assert_eq!(device.read_xyz_raw().unwrap(), [16, -1, 2]);
+
+        let device = device.standby().unwrap();
+        assert_eq!(device.config().power_mode, PowerMode::Standby);
+    }
+
+    /// Requesting a measuring power mode in the config still leaves init in standby.
+    #[test]
+    fn init_ignores_configured_power_mode() {
+        let config = Config::new().power_mode(PowerMode::Measure).build();
+        let standby = Config::default();
+        let mock = Sequence::new()
+            .init(&standby, SelfTestResponse::passing())
+            .into_mock();
+
+        let mut device = Adxl372::new(mock, config)
+            .init(&mut NoopDelay::new())
+            .unwrap();
+        assert_eq!(device.config().power_mode, PowerMode::Standby);
+        assert_config(device.interface_mut(), &standby);
+    }
+
+    /// A failed init hands the device back so init can be retried.
+    #[test]
+    fn failed_transition_returns_uninit_device() {
+        let config = Config::default();
+        let mock = Sequence::new()
+            .init(&config, SelfTestResponse::failing())
+            .into_mock();
+
+        let Err(TransitionError::Failed { error, mut device }) =
+            Adxl372::new(mock, config).init(&mut NoopDelay::new())
+        else {
+            panic!("init succeeded despite a failing self-test");
+        };
+        assert_eq!(error, Error::SelfTestFailed);
+
+        device.interface_mut().expect(
+            Sequence::new()
+                .init(&config, SelfTestResponse::passing())
+                .into_expectations(),
+        );
+        device.init(&mut NoopDelay::new()).unwrap();
+    }
+
+    /// A mode the configuration does not allow is rejected without leaving standby.
+    #[test]
+    fn rejected_transition_keeps_standby_device() {
+        let config = Config::default();
+        let (sequence, mut device) = initialized(&config);
+        let standby = sequence.register(REG_POWER_CTL);
+        device.interface_mut().expect([Expectation::ReadRegister {
+            register: REG_POWER_CTL,
+            value: standby,
+        }]);
+
+        let Err(TransitionError::Rejected { error, mut device }) = device.start_wake_up() else {
+            panic!("wake-up accepted without an activity detector");
+        };
+        assert_eq!(
+            error,
+            Error::InvalidConfig(ConfigError::WakeUpRequiresActivity)
+        );
+        assert_eq!(device.config().power_mode, PowerMode::Standby);
+        assert_config(device.interface_mut(), &config);
+    }
+
+    /// The standby self-test restores the configuration its resets cleared.
+    #[test]
+    fn self_test_restores_config() {
+        let config = Config::new().fifo_watermark(96).build();
+        let (sequence, mut device) = initialized(&config);
+        let consumed = device.interface_mut().consumed();
+        let expectations = sequence
+            .self_test(SelfTestResponse::passing())
+            .configure(&config)
+            .into_expectations();
+        device
+            .interface_mut()
+            .expect(expectations.into_iter().skip(consumed));
+
+        let (mut device, report) = device.self_test(&mut NoopDelay::new()).unwrap();
+        assert_eq!(report, SelfTestResponse::passing().report());
+        assert_config(device.interface_mut(), &config);
+    }
+}
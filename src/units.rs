@@ -0,0 +1,125 @@
+//! Physical-unit conversions for ADXL372 acceleration data.
+//!
+//! The ADXL372 has a fixed ±200 g range with a scale factor of 100 mg/LSB, so every raw
+//! 12-bit reading (`XDATA`, FIFO entries, `MAXPEAK`) converts to milli-g the same way. Floating
+//! point conversions to g and m/s² are available with the `float` feature.
+
+use crate::fifo::Sample;
+
+/// Acceleration scale factor in milli-g per LSB.
+pub const MG_PER_LSB: i32 = 100;
+
+/// Standard gravity in m/s², used by the m/s² conversions.
+#[cfg(feature = "float")]
+pub const STANDARD_GRAVITY: f32 = 9.806_65;
+
+/// Converts a raw 12-bit acceleration reading into milli-g.
+#[inline]
+pub const fn raw_to_mg(raw: i16) -> i32 {
+    raw as i32 * MG_PER_LSB
+}
+
+/// Three-axis acceleration expressed in milli-g.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Acceleration {
+    /// X-axis acceleration in milli-g.
+    pub x_mg: i32,
+    /// Y-axis acceleration in milli-g.
+    pub y_mg: i32,
+    /// Z-axis acceleration in milli-g.
+    pub z_mg: i32,
+}
+
+impl Acceleration {
+    /// Creates an acceleration from per-axis milli-g values.
+    pub const fn new(x_mg: i32, y_mg: i32, z_mg: i32) -> Self {
+        Self { x_mg, y_mg, z_mg }
+    }
+
+    /// Scales a raw `[x, y, z]` triplet, as returned by `read_xyz_raw` or `read_peak_xyz_raw`.
+    pub const fn from_raw(raw: [i16; 3]) -> Self {
+        Self::new(raw_to_mg(raw[0]), raw_to_mg(raw[1]), raw_to_mg(raw[2]))
+    }
+
+    /// Scales a FIFO sample, returning `None` unless all three axes are present.
+    pub const fn from_sample(sample: &Sample) -> Option<Self> {
+        match (sample.x, sample.y, sample.z) {
+            (Some(x), Some(y), Some(z)) => Some(Self::from_raw([x, y, z])),
+            _ => None,
+        }
+    }
+
+    /// Returns the per-axis values as an `[x, y, z]` array in milli-g.
+    pub const fn as_mg(&self) -> [i32; 3] {
+        [self.x_mg, self.y_mg, self.z_mg]
+    }
+
+    /// Returns the per-axis values in g.
+    #[cfg(feature = "float")]
+    pub fn as_g(&self) -> [f32; 3] {
+        self.as_mg().map(|mg| mg as f32 / 1_000.0)
+    }
+
+    /// Returns the per-axis values in m/s².
+    #[cfg(feature = "float")]
+    pub fn as_m_s2(&self) -> [f32; 3] {
+        self.as_g().map(|g| g * STANDARD_GRAVITY)
+    }
+}
+
+impl From<[i16; 3]> for Acceleration {
+    fn from(raw: [i16; 3]) -> Self {
+        Self::from_raw(raw)
+    }
+}
+
+impl Sample {
+    /// Scales this sample into milli-g, returning `None` unless all three axes are present.
+    pub const fn acceleration(&self) -> Option<Acceleration> {
+        Acceleration::from_sample(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Acceleration, raw_to_mg};
+    use crate::fifo::Sample;
+
+    /// Ensures raw readings scale by 100 mg/LSB across the signed 12-bit range.
+    #[test]
+    fn raw_readings_scale_to_mg() {
+        assert_eq!(raw_to_mg(-2048), -204_800);
+        assert_eq!(
+            Acceleration::from_raw([1, -1, 2047]).as_mg(),
+            [100, -100, 204_700]
+        );
+    }
+
+    /// Verifies partial FIFO samples do not produce a three-axis acceleration.
+    #[test]
+    fn samples_require_all_axes() {
+        let full = Sample {
+            x: Some(10),
+            y: Some(0),
+            z: Some(-10),
+            is_peak: false,
+        };
+        let partial = Sample { z: None, ..full };
+
+        assert_eq!(
+            full.acceleration(),
+            Some(Acceleration::new(1_000, 0, -1_000))
+        );
+        assert_eq!(partial.acceleration(), None);
+    }
+
+    /// Checks the float conversions against standard gravity.
+    #[cfg(feature = "float")]
+    #[test]
+    fn float_conversions_use_standard_gravity() {
+        let accel = Acceleration::new(1_000, -500, 0);
+
+        assert_eq!(accel.as_g(), [1.0, -0.5, 0.0]);
+        assert_eq!(accel.as_m_s2(), [9.806_65, -4.903_325, 0.0]);
+    }
+}